@@ -0,0 +1,59 @@
+use crate::{client::Client, docker::DockerNegotiateVersion, models::Capabilities};
+
+impl<D: DockerNegotiateVersion> Client<D> {
+    /// Reports the optional features this crate was compiled with and the Docker API version in use.
+    ///
+    /// Call [`Client::negotiate_api_version`] first to report the version agreed on with the daemon.
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::new(self.api_version())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::DockerError, models::ApiVersion};
+    use bollard::models::SystemVersion;
+    use mockall::mock;
+
+    mock! {
+        Docker {}
+
+        impl DockerNegotiateVersion for Docker {
+            async fn version(&self) -> Result<SystemVersion, DockerError>;
+            fn api_version(&self) -> ApiVersion;
+            async fn negotiate_version(&self) -> Result<(), DockerError>;
+        }
+    }
+
+    #[test]
+    fn test_capabilities() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_api_version()
+            .times(1)
+            .returning(|| ApiVersion::new(1, 43));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let capabilities = client.capabilities();
+
+        // Assert
+        assert_eq!(
+            capabilities,
+            Capabilities {
+                serde: cfg!(feature = "serde"),
+                bollard: cfg!(feature = "bollard"),
+                tracing: cfg!(feature = "tracing"),
+                mongodb: cfg!(feature = "mongodb"),
+                api_version: ApiVersion::new(1, 43),
+            }
+        );
+        #[cfg(feature = "mongodb")]
+        assert!(capabilities.mongodb);
+        #[cfg(not(feature = "mongodb"))]
+        assert!(!capabilities.mongodb);
+    }
+}
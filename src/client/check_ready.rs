@@ -0,0 +1,294 @@
+use crate::{
+    Client,
+    client::{get_deployment::GetDeploymentError, mongosh_command::PING_SCRIPT},
+    docker::{DockerInspectContainer, RunCommandInContainer},
+    models::{CheckStatus, ContainerHealthStatus, Deployment, ReadinessReport, State},
+};
+
+/// Prints `true` when a member of the replica set is primary.
+const REPLICA_SET_PRIMARY_SCRIPT: &str =
+    "print(rs.status().members.some(m => m.stateStr === 'PRIMARY'))";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CheckReadyError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+}
+
+impl<D: DockerInspectContainer + RunCommandInContainer> Client<D> {
+    /// Checks whether a local Atlas deployment is usable.
+    ///
+    /// The report holds the status of each check: the container health, the presence of a replica set
+    /// primary and a ping of mongod. The last two are run with mongosh inside the container and are
+    /// skipped when the container is not running. A failing check does not fail the call, use
+    /// [`ReadinessReport::is_ready`] for the verdict.
+    pub async fn check_ready(
+        &self,
+        cluster_id_or_name: &str,
+    ) -> Result<ReadinessReport, CheckReadyError> {
+        let deployment = self.get_deployment(cluster_id_or_name).await?;
+
+        let container_health = container_health_status(&deployment);
+        if deployment.state != State::Running {
+            return Ok(ReadinessReport {
+                container_health,
+                replica_set_primary: CheckStatus::Skipped,
+                ping: CheckStatus::Skipped,
+            });
+        }
+
+        let ping = self.run_check(&deployment, PING_SCRIPT, "1").await;
+        let replica_set_primary = self
+            .run_check(&deployment, REPLICA_SET_PRIMARY_SCRIPT, "true")
+            .await;
+
+        Ok(ReadinessReport {
+            container_health,
+            replica_set_primary,
+            ping,
+        })
+    }
+
+    /// Runs a mongosh script in the container, the check passes when it prints `expected_output`.
+    async fn run_check(
+        &self,
+        deployment: &Deployment,
+        script: &str,
+        expected_output: &str,
+    ) -> CheckStatus {
+        let command_output = match self.run_mongosh(deployment, script).await {
+            Ok(command_output) => command_output,
+            Err(err) => {
+                return CheckStatus::Failed {
+                    reason: err.to_string(),
+                };
+            }
+        };
+
+        let stdout = command_output.stdout.join("\n");
+        if stdout.trim() == expected_output {
+            return CheckStatus::Passed;
+        }
+
+        // mongosh reports errors on stderr
+        let reason = if stdout.trim().is_empty() {
+            command_output.stderr.join("\n")
+        } else {
+            stdout
+        };
+        CheckStatus::Failed { reason }
+    }
+}
+
+fn container_health_status(deployment: &Deployment) -> CheckStatus {
+    if deployment.state != State::Running {
+        return CheckStatus::Failed {
+            reason: format!("container is {}", deployment.state),
+        };
+    }
+
+    match deployment.health {
+        Some(ContainerHealthStatus::Unhealthy) => CheckStatus::Failed {
+            reason: "health check reports the container as unhealthy".to_string(),
+        },
+        Some(ContainerHealthStatus::Starting) => CheckStatus::Failed {
+            reason: "health check has not passed yet".to_string(),
+        },
+        _ => CheckStatus::Passed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        docker::{CommandOutput, DockerError, RunCommandInContainerError},
+        test_utils::create_container_inspect_response_with_auth,
+    };
+    use bollard::{
+        models::{ContainerInspectResponse, ContainerStateStatusEnum, HealthStatusEnum},
+        query_parameters::InspectContainerOptions,
+    };
+    use mockall::mock;
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    fn create_container_inspect_response(
+        status: ContainerStateStatusEnum,
+        health: HealthStatusEnum,
+    ) -> ContainerInspectResponse {
+        let mut container_inspect_response = create_container_inspect_response_with_auth(27017);
+        if let Some(state) = container_inspect_response.state.as_mut() {
+            state.status = Some(status);
+            state.health = Some(bollard::models::Health {
+                status: Some(health),
+                ..Default::default()
+            });
+        }
+        container_inspect_response
+    }
+
+    fn command_output(stdout: &str, stderr: &str) -> CommandOutput {
+        CommandOutput {
+            stdout: stdout.lines().map(str::to_string).collect(),
+            stderr: stderr.lines().map(str::to_string).collect(),
+            exit_code: Some(0),
+        }
+    }
+
+    /// Answers the ping script with `ping` and the replica set script with `primary` (stdout) and `primary_error` (stderr).
+    fn expect_checks(
+        mock_docker: &mut MockDocker,
+        ping: &'static str,
+        primary: &'static str,
+        primary_error: &'static str,
+    ) {
+        mock_docker
+            .expect_run_command_in_container()
+            .withf(|_, command| command.iter().any(|arg| arg == PING_SCRIPT))
+            .times(1)
+            .returning(move |_, _| Ok(command_output(ping, "")));
+        mock_docker
+            .expect_run_command_in_container()
+            .withf(|_, command| command.iter().any(|arg| arg == REPLICA_SET_PRIMARY_SCRIPT))
+            .times(1)
+            .returning(move |_, _| Ok(command_output(primary, primary_error)));
+    }
+
+    #[tokio::test]
+    async fn test_check_ready() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                    HealthStatusEnum::HEALTHY,
+                ))
+            });
+        expect_checks(&mut mock_docker, "1", "true", "");
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let report = client.check_ready("test-deployment").await.unwrap();
+
+        // Assert
+        assert!(report.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_check_ready_no_primary() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                    HealthStatusEnum::STARTING,
+                ))
+            });
+        expect_checks(
+            &mut mock_docker,
+            "1",
+            "",
+            "MongoServerError: no replset config has been received",
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let report = client.check_ready("test-deployment").await.unwrap();
+
+        // Assert
+        assert!(!report.is_ready());
+        assert_eq!(
+            report,
+            ReadinessReport {
+                container_health: CheckStatus::Failed {
+                    reason: "health check has not passed yet".to_string(),
+                },
+                replica_set_primary: CheckStatus::Failed {
+                    reason: "MongoServerError: no replset config has been received".to_string(),
+                },
+                ping: CheckStatus::Passed,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_ready_container_not_running() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_container_inspect_response(
+                    ContainerStateStatusEnum::EXITED,
+                    HealthStatusEnum::UNHEALTHY,
+                ))
+            });
+        mock_docker.expect_run_command_in_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let report = client.check_ready("test-deployment").await.unwrap();
+
+        // Assert
+        assert_eq!(
+            report,
+            ReadinessReport {
+                container_health: CheckStatus::Failed {
+                    reason: "container is exited".to_string(),
+                },
+                replica_set_primary: CheckStatus::Skipped,
+                ping: CheckStatus::Skipped,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_ready_get_deployment_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.check_ready("nonexistent-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CheckReadyError::GetDeployment(_)
+        ));
+    }
+}
@@ -0,0 +1,643 @@
+use bollard::models::ContainerInspectResponse;
+
+use crate::{
+    client::{
+        Client, CreateDeploymentError, GetDeploymentError, MongoshError,
+        mongosh_command::{LOCAL_MONGODB_URI, RootCredentials},
+        pull_image::split_image_reference,
+    },
+    docker::{
+        DockerCreateContainer, DockerCreateVolume, DockerInspectContainer, DockerPullImage,
+        DockerRemoveContainer, DockerStartContainer, RunCommandInContainer,
+        RunCommandInContainerError,
+    },
+    models::{CreateDeploymentOptions, Deployment, ImageTag, MongoDBPortBinding, Telemetry},
+};
+
+/// Path of the archive the data is dumped to inside the clone.
+const CLONE_ARCHIVE_PATH: &str = "/tmp/atlas-local-clone.archive";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CloneDeploymentError {
+    #[error("Failed to get source deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Source deployment {0} has no image")]
+    MissingImage(String),
+    #[error(transparent)]
+    CreateDeployment(#[from] CreateDeploymentError),
+    #[error("Source deployment {0} has no network address to copy the data from")]
+    MissingSourceAddress(String),
+    #[error(transparent)]
+    RootCredentials(#[from] MongoshError),
+    #[error("Failed to run {command}: {source}")]
+    RunCommand {
+        command: &'static str,
+        source: RunCommandInContainerError,
+    },
+    #[error("{command} failed with exit code {exit_code:?}: {stderr}")]
+    CommandFailed {
+        command: &'static str,
+        exit_code: Option<i64>,
+        stderr: String,
+    },
+}
+
+impl<
+    D: DockerPullImage
+        + DockerCreateVolume
+        + DockerCreateContainer
+        + DockerStartContainer
+        + DockerInspectContainer
+        + DockerRemoveContainer
+        + RunCommandInContainer
+        + Send
+        + Sync
+        + 'static,
+> Client<D>
+{
+    /// Creates a new deployment named `new_name` with the same configuration as `source`.
+    ///
+    /// The image, version, network, seed mount, initial database configuration and telemetry settings
+    /// of the source are used for every option that is not set in `options`. The port binding is not
+    /// copied, so the clone does not conflict with the source. The image is not pulled again unless
+    /// `skip_pull_image` is set to `false`. The data is not copied, see [`Client::clone_deployment_with_data`].
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The ID or name of the deployment to clone.
+    /// * `new_name` - The name of the new deployment.
+    /// * `options` - Options overriding the configuration of the source.
+    pub async fn clone_deployment(
+        &self,
+        source: &str,
+        new_name: &str,
+        options: CreateDeploymentOptions,
+    ) -> Result<Deployment, CloneDeploymentError> {
+        let container_inspect_response = self.inspect_deployment_container(source).await?;
+        self.create_clone(source, new_name, options, &container_inspect_response)
+            .await
+    }
+
+    /// Clones a deployment like [`Client::clone_deployment`] and copies its data into the clone.
+    ///
+    /// The data is dumped from the source with `mongodump` and restored with `mongorestore`, both run
+    /// inside the clone, which reaches the source over their Docker network. The dump is not a
+    /// consistent snapshot: writes to the source while it is copied may or may not end up in the clone,
+    /// stop writing to the source (e.g. pause the application using it) for an exact copy.
+    /// The source must therefore be running and share a network with the clone.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The ID or name of the deployment to clone.
+    /// * `new_name` - The name of the new deployment.
+    /// * `options` - Options overriding the configuration of the source.
+    pub async fn clone_deployment_with_data(
+        &self,
+        source: &str,
+        new_name: &str,
+        options: CreateDeploymentOptions,
+    ) -> Result<Deployment, CloneDeploymentError> {
+        let container_inspect_response = self.inspect_deployment_container(source).await?;
+        let source_deployment = Deployment::try_from(container_inspect_response.clone())
+            .map_err(GetDeploymentError::from)?;
+        let source_address = source_address(&container_inspect_response)
+            .ok_or_else(|| CloneDeploymentError::MissingSourceAddress(source.to_string()))?;
+
+        let clone = self
+            .create_clone(source, new_name, options, &container_inspect_response)
+            .await?;
+
+        // Dump the source from inside the clone, the archive never leaves the clone
+        let source_credentials = self.root_credentials(&source_deployment).await?;
+        let mut mongodump = vec![
+            "mongodump".to_string(),
+            format!("--host={source_address}"),
+            format!("--archive={CLONE_ARCHIVE_PATH}"),
+        ];
+        mongodump.extend(credential_args(source_credentials));
+        self.run_clone_command(&clone, "mongodump", mongodump)
+            .await?;
+
+        // Restore the dump into the clone
+        let clone_credentials = self.root_credentials(&clone).await?;
+        let mut mongorestore = vec![
+            "mongorestore".to_string(),
+            format!("--uri={LOCAL_MONGODB_URI}"),
+            format!("--archive={CLONE_ARCHIVE_PATH}"),
+            "--drop".to_string(),
+        ];
+        mongorestore.extend(credential_args(clone_credentials));
+        self.run_clone_command(&clone, "mongorestore", mongorestore)
+            .await?;
+
+        self.run_clone_command(
+            &clone,
+            "rm",
+            vec![
+                "rm".to_string(),
+                "-f".to_string(),
+                CLONE_ARCHIVE_PATH.to_string(),
+            ],
+        )
+        .await?;
+
+        Ok(clone)
+    }
+
+    async fn create_clone(
+        &self,
+        source: &str,
+        new_name: &str,
+        mut options: CreateDeploymentOptions,
+        container_inspect_response: &ContainerInspectResponse,
+    ) -> Result<Deployment, CloneDeploymentError> {
+        let source_deployment = Deployment::try_from(container_inspect_response.clone())
+            .map_err(GetDeploymentError::from)?;
+
+        // Use the image of the source, it is already present so it is not pulled by default
+        let reference = container_inspect_response
+            .config
+            .as_ref()
+            .and_then(|config| config.image.as_deref())
+            .ok_or_else(|| CloneDeploymentError::MissingImage(source.to_string()))?;
+        let (image, tag) = split_image_reference(reference);
+        if options.image.is_none() && options.atlas_local_image.is_none() {
+            options.image = Some(image.to_string());
+            options.image_tag.get_or_insert_with(|| {
+                ImageTag::try_from(tag).unwrap_or_else(|_| ImageTag::Custom(tag.to_string()))
+            });
+        }
+        options.skip_pull_image.get_or_insert(true);
+
+        options.name = Some(new_name.to_string());
+        apply_source_configuration(&mut options, source_deployment);
+
+        Ok(self.create_deployment(options).await?)
+    }
+
+    /// Runs a command inside the clone, failing when it does not exit successfully.
+    async fn run_clone_command(
+        &self,
+        clone: &Deployment,
+        command: &'static str,
+        args: Vec<String>,
+    ) -> Result<(), CloneDeploymentError> {
+        let command_output = self
+            .docker
+            .run_command_in_container(&clone.container_id, args)
+            .await
+            .map_err(|source| CloneDeploymentError::RunCommand { command, source })?;
+
+        if command_output.exit_code != Some(0) {
+            return Err(CloneDeploymentError::CommandFailed {
+                command,
+                exit_code: command_output.exit_code,
+                stderr: command_output.stderr.join("\n"),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the options that are not set yet to the configuration of the source deployment.
+#[allow(deprecated)]
+fn apply_source_configuration(options: &mut CreateDeploymentOptions, source: Deployment) {
+    options.local_seed_location = options
+        .local_seed_location
+        .take()
+        .or(source.local_seed_location);
+    options.mongodb_initdb_database = options
+        .mongodb_initdb_database
+        .take()
+        .or(source.mongodb_initdb_database);
+    options.mongodb_initdb_root_roles = options
+        .mongodb_initdb_root_roles
+        .take()
+        .or(source.mongodb_initdb_root_roles);
+    options.load_sample_data = options.load_sample_data.or(source.mongodb_load_sample_data);
+    options.voyage_api_key = options.voyage_api_key.take().or(source.voyage_api_key);
+    options.replica_set_name = options.replica_set_name.take().or(source.replica_set_name);
+    options.mongot_log_file = options.mongot_log_file.take().or(source.mongot_log_file);
+    options.runner_log_file = options.runner_log_file.take().or(source.runner_log_file);
+
+    // Attach the clone to the network of the source so the two can reach each other
+    if options.network.is_none() && options.network_mode.is_none() {
+        options.network = source.network_mode;
+    }
+
+    // The username and password can each be set as a value or as a file, only copy them when neither is set
+    if options.mongodb_initdb_root_username.is_none()
+        && options.mongodb_initdb_root_username_file.is_none()
+    {
+        options.mongodb_initdb_root_username = source.mongodb_initdb_root_username;
+        options.mongodb_initdb_root_username_file = source.mongodb_initdb_root_username_file;
+    }
+    if options.mongodb_initdb_root_password.is_none()
+        && options.mongodb_initdb_root_password_file.is_none()
+    {
+        options.mongodb_initdb_root_password = source.mongodb_initdb_root_password;
+        options.mongodb_initdb_root_password_file = source.mongodb_initdb_root_password_file;
+    }
+
+    if options.telemetry.is_none()
+        && options.do_not_track.is_none()
+        && options.telemetry_base_url.is_none()
+    {
+        options.telemetry = Some(if source.do_not_track {
+            Telemetry::Disabled
+        } else {
+            Telemetry::Enabled {
+                base_url: source.telemetry_base_url,
+            }
+        });
+    }
+}
+
+/// Returns the address mongod of the source listens on in its Docker network, e.g. `172.17.0.2:27017`.
+fn source_address(container_inspect_response: &ContainerInspectResponse) -> Option<String> {
+    let ip_address = container_inspect_response
+        .network_settings
+        .as_ref()?
+        .networks
+        .as_ref()?
+        .values()
+        .filter_map(|network| network.ip_address.as_deref())
+        .find(|ip_address| !ip_address.is_empty())?;
+    let port = MongoDBPortBinding::container_port_of(container_inspect_response);
+
+    Some(format!("{ip_address}:{port}"))
+}
+
+/// Builds the arguments passing the root credentials to the MongoDB database tools.
+fn credential_args(credentials: RootCredentials) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(username) = credentials.username {
+        args.push(format!("--username={username}"));
+    }
+    if let Some(password) = credentials.password {
+        args.push(format!("--password={password}"));
+    }
+    if !args.is_empty() {
+        args.push("--authenticationDatabase=admin".to_string());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        docker::{CommandOutput, DockerError, RunCommandInContainerError},
+        test_utils::create_container_inspect_response_with_auth,
+    };
+    use bollard::{
+        models::{
+            ContainerCreateBody, ContainerCreateResponse, ContainerInspectResponse, ContainerState,
+            CreateImageInfo, EndpointSettings, Health, HealthStatusEnum, Volume,
+            VolumeCreateRequest,
+        },
+        query_parameters::{
+            CreateContainerOptions, InspectContainerOptions, RemoveContainerOptions,
+            StartContainerOptions,
+        },
+    };
+    use futures_util::Stream;
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerPullImage for Docker {
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerCreateVolume for Docker {
+            async fn create_volume(
+                &self,
+                config: VolumeCreateRequest,
+            ) -> Result<Volume, DockerError>;
+        }
+
+        impl DockerCreateContainer for Docker {
+            async fn create_container(
+                &self,
+                options: Option<CreateContainerOptions>,
+                config: ContainerCreateBody,
+            ) -> Result<ContainerCreateResponse, DockerError>;
+        }
+
+        impl DockerStartContainer for Docker {
+            async fn start_container(
+                &self,
+                container_id: &str,
+                options: Option<StartContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerRemoveContainer for Docker {
+            async fn remove_container(
+                &self,
+                container_id: &str,
+                options: Option<RemoveContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    fn source_inspect_response() -> ContainerInspectResponse {
+        let mut container_inspect_response = create_container_inspect_response_with_auth(27017);
+        if let Some(config) = container_inspect_response.config.as_mut() {
+            config.image = Some("quay.io/mongodb/mongodb-atlas-local:8.0.4".to_string());
+            if let Some(env) = config.env.as_mut() {
+                env.push("MONGODB_INITDB_DATABASE=testdb".to_string());
+            }
+        }
+        if let Some(network_settings) = container_inspect_response.network_settings.as_mut() {
+            network_settings.networks = Some(hashmap! {
+                "bridge".to_string() => EndpointSettings {
+                    ip_address: Some("172.17.0.2".to_string()),
+                    ..Default::default()
+                },
+            });
+        }
+        container_inspect_response
+    }
+
+    fn clone_inspect_response() -> ContainerInspectResponse {
+        let mut container_inspect_response = create_container_inspect_response_with_auth(27018);
+        container_inspect_response.id = Some("clone_container_id".to_string());
+        container_inspect_response.name = Some("/clone".to_string());
+        container_inspect_response.state = Some(ContainerState {
+            status: Some(bollard::models::ContainerStateStatusEnum::RUNNING),
+            health: Some(Health {
+                status: Some(HealthStatusEnum::HEALTHY),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        container_inspect_response
+    }
+
+    fn expect_create_clone(mock_docker: &mut MockDocker) {
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("source"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(source_inspect_response()));
+        mock_docker.expect_pull_image().never();
+        mock_docker
+            .expect_create_container()
+            .withf(|options, config| {
+                let env = config.env.clone().unwrap_or_default();
+                options.as_ref().map(|options| options.name.as_deref()) == Some(Some("clone"))
+                    && config.image.as_deref() == Some("quay.io/mongodb/mongodb-atlas-local:8.0.4")
+                    && env.contains(&"MONGODB_INITDB_ROOT_USERNAME=testuser".to_string())
+                    && env.contains(&"MONGODB_INITDB_ROOT_PASSWORD=testpass".to_string())
+                    && env.contains(&"MONGODB_INITDB_DATABASE=testdb".to_string())
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "clone_container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+        mock_docker
+            .expect_start_container()
+            .with(eq("clone"), eq(None::<StartContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("clone"), eq(None::<InspectContainerOptions>))
+            .times(2)
+            .returning(|_, _| Ok(clone_inspect_response()));
+    }
+
+    fn command_output(exit_code: i64) -> CommandOutput {
+        CommandOutput {
+            stdout: vec![],
+            stderr: if exit_code == 0 {
+                vec![]
+            } else {
+                vec!["Failed: connection refused".to_string()]
+            },
+            exit_code: Some(exit_code),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_create_clone(&mut mock_docker);
+        mock_docker.expect_run_command_in_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .clone_deployment("source", "clone", CreateDeploymentOptions::default())
+            .await;
+
+        // Assert
+        let deployment = result.unwrap();
+        assert_eq!(deployment.container_id, "clone_container_id");
+        assert_eq!(deployment.name.as_deref(), Some("clone"));
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment_options_override_source() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("source"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(source_inspect_response()));
+        mock_docker
+            .expect_create_container()
+            .withf(|_, config| {
+                let env = config.env.clone().unwrap_or_default();
+                env.contains(&"MONGODB_INITDB_DATABASE=otherdb".to_string())
+                    && !env.contains(&"MONGODB_INITDB_DATABASE=testdb".to_string())
+            })
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker);
+        let options = CreateDeploymentOptions {
+            mongodb_initdb_database: Some("otherdb".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let result = client.clone_deployment("source", "clone", options).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CloneDeploymentError::CreateDeployment(CreateDeploymentError::CreateContainer(
+                DockerError::ServerError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment_source_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_create_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .clone_deployment("source", "clone", CreateDeploymentOptions::default())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CloneDeploymentError::GetDeployment(GetDeploymentError::NotFound { name })
+                if name == "source"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment_with_data() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_create_clone(&mut mock_docker);
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("clone_container_id"),
+                eq(vec![
+                    "mongodump".to_string(),
+                    "--host=172.17.0.2:27017".to_string(),
+                    "--archive=/tmp/atlas-local-clone.archive".to_string(),
+                    "--username=testuser".to_string(),
+                    "--password=testpass".to_string(),
+                    "--authenticationDatabase=admin".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(command_output(0)));
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("clone_container_id"),
+                eq(vec![
+                    "mongorestore".to_string(),
+                    "--uri=mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                    "--archive=/tmp/atlas-local-clone.archive".to_string(),
+                    "--drop".to_string(),
+                    "--username=testuser".to_string(),
+                    "--password=testpass".to_string(),
+                    "--authenticationDatabase=admin".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(command_output(0)));
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("clone_container_id"),
+                eq(vec![
+                    "rm".to_string(),
+                    "-f".to_string(),
+                    "/tmp/atlas-local-clone.archive".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| Ok(command_output(0)));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .clone_deployment_with_data("source", "clone", CreateDeploymentOptions::default())
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap().container_id, "clone_container_id");
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment_with_data_dump_failed() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_create_clone(&mut mock_docker);
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| Ok(command_output(1)));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .clone_deployment_with_data("source", "clone", CreateDeploymentOptions::default())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CloneDeploymentError::CommandFailed {
+                command: "mongodump",
+                exit_code: Some(1),
+                stderr,
+            } if stderr == "Failed: connection refused"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clone_deployment_with_data_missing_source_address() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker.expect_create_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .clone_deployment_with_data("source", "clone", CreateDeploymentOptions::default())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CloneDeploymentError::MissingSourceAddress(source) if source == "source"
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{client::Client, docker::DockerInspectContainer, models::DeploymentDiff};
+
+use super::GetDeploymentError;
+
+impl<D: DockerInspectContainer> Client<D> {
+    /// Compares two local Atlas deployments field by field.
+    ///
+    /// See [`crate::models::Deployment::diff`] for the fields that are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The ID or name of the first deployment.
+    /// * `right` - The ID or name of the second deployment.
+    pub async fn compare_deployments(
+        &self,
+        left: &str,
+        right: &str,
+    ) -> Result<DeploymentDiff, GetDeploymentError> {
+        let left = self.get_deployment(left).await?;
+        let right = self.get_deployment(right).await?;
+
+        Ok(left.diff(&right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::DockerError, models::FieldDiff};
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+        },
+        query_parameters::InspectContainerOptions,
+    };
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+    }
+
+    fn create_test_container_inspect_response(
+        id: &str,
+        version: &str,
+        env: Vec<String>,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some(id.to_string()),
+            name: Some(format!("/{id}")),
+            config: Some(ContainerConfig {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => version.to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                env: Some(env),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compare_deployments_identical() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("deployment1"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    "deployment1",
+                    "8.0.0",
+                    vec!["TOOL=ATLASCLI".to_string()],
+                ))
+            });
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("deployment2"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    "deployment2",
+                    "8.0.0",
+                    vec!["TOOL=ATLASCLI".to_string()],
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .compare_deployments("deployment1", "deployment2")
+            .await;
+
+        // Assert
+        assert!(result.unwrap().is_identical());
+    }
+
+    #[tokio::test]
+    async fn test_compare_deployments_different() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("deployment1"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    "deployment1",
+                    "8.0.0",
+                    vec!["TOOL=ATLASCLI".to_string()],
+                ))
+            });
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("deployment2"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    "deployment2",
+                    "7.0.12",
+                    vec!["TOOL=CONTAINER".to_string()],
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .compare_deployments("deployment1", "deployment2")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap().differences,
+            vec![
+                FieldDiff {
+                    field: "mongodb_version".to_string(),
+                    left: Some("8.0.0".to_string()),
+                    right: Some("7.0.12".to_string()),
+                },
+                FieldDiff {
+                    field: "env.TOOL".to_string(),
+                    left: Some("ATLASCLI".to_string()),
+                    right: Some("CONTAINER".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compare_deployments_get_deployment_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("deployment1"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    "deployment1",
+                    "8.0.0",
+                    vec![],
+                ))
+            });
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("missing"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.compare_deployments("deployment1", "missing").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::NotFound { name } if name == "missing"
+        ));
+    }
+}
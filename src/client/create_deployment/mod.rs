@@ -1,30 +1,45 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    pin::pin,
+    time::Duration,
+};
+
 use bollard::{
     models::ContainerCreateBody,
-    query_parameters::{CreateContainerOptions, StartContainerOptions},
+    query_parameters::{CreateContainerOptions, RemoveContainerOptions, StartContainerOptions},
 };
-use tokio::sync::oneshot;
+use futures::future::{self, Either};
+use tokio::{sync::oneshot, time::Instant};
 
 use crate::{
     GetDeploymentError,
     client::Client,
     docker::{
-        DockerCreateContainer, DockerError, DockerInspectContainer, DockerPullImage,
-        DockerStartContainer,
+        DockerCreateContainer, DockerCreateVolume, DockerError, DockerInspectContainer,
+        DockerPullImage, DockerRemoveContainer, DockerStartContainer,
     },
-    models::{ATLAS_LOCAL_IMAGE, CreateDeploymentOptions, Deployment, WatchOptions},
+    models::{BindingType, CreateDeploymentOptions, Deployment, WaitStrategy, WatchOptions},
 };
 
-use super::{PullImageError, WatchDeploymentError};
+use super::{PullImageError, WatchDeploymentError, watch_deployment::wait_for_tcp_connect};
 
 mod progress;
 
-pub use progress::{CreateDeploymentProgress, CreateDeploymentStepOutcome};
+/// How often a started deployment is inspected before giving up on its published port being reported.
+const PORT_BINDING_ATTEMPTS: u32 = 5;
+/// Time between two inspections of a started deployment whose published port is not reported yet.
+const PORT_BINDING_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+pub use progress::{CreateDeploymentProgress, CreateDeploymentStep, CreateDeploymentStepOutcome};
 use progress::{CreateDeploymentProgressSender, create_progress_pairs};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CreateDeploymentError {
     #[error("Failed to create container: {0}")]
     CreateContainer(DockerError),
+    #[error("Failed to create data volume: {0}")]
+    CreateVolume(DockerError),
     #[error(transparent)]
     PullImage(#[from] PullImageError),
     #[error("Container already exists: {0}")]
@@ -43,42 +58,101 @@ pub enum CreateDeploymentError {
         "Image must not include a tag. Use the `image_tag` field to specify a tag. Got: \"{0}\""
     )]
     InvalidImage(String),
+    #[error("Deployment {0} has no host port binding to connect to")]
+    MissingPortBinding(String),
+    #[error("Config file does not exist: {}", .0.display())]
+    ConfigFileNotFound(PathBuf),
+    #[error("Deployment creation was cancelled")]
+    Cancelled,
 }
 
 impl<
     D: DockerPullImage
+        + DockerCreateVolume
         + DockerCreateContainer
         + DockerStartContainer
         + DockerInspectContainer
+        + DockerRemoveContainer
         + Send
         + Sync
         + 'static,
 > Client<D>
 {
     /// Creates a local Atlas deployment.
+    ///
+    /// The deployment is created in a background task, use [`CreateDeploymentProgress::cancel`] to stop it.
     pub fn create_deployment(
         &self,
-        deployment_options: CreateDeploymentOptions,
+        mut deployment_options: CreateDeploymentOptions,
     ) -> CreateDeploymentProgress {
         let (sender, receiver) = create_progress_pairs();
-        let client = self.clone();
+
+        // Settle the container name now, a random name would otherwise only be picked inside the task
+        let container_name = CreateContainerOptions::from(&deployment_options)
+            .name
+            .unwrap_or_default();
+        deployment_options.name = Some(container_name.clone());
+
+        // The task tracker only cleans up a container this creation made, never one that already had the name
+        let created_container_id = sender.created_container_id.clone();
+
+        // The task gets its own task tracker, sharing ours would keep the tasks alive after the last client is dropped
+        let client = Client {
+            docker: self.docker.clone(),
+            tasks: Default::default(),
+            default_watch_timeout: self.default_watch_timeout,
+            retry_policy: self.retry_policy,
+        };
 
         // Spawn the deployment creation in a background task.
         // Errors from `create_deployment_inner` are forwarded to the receiver via the progress channel.
         // This code cannot panic: the crate denies unwrap/expect/panic usage (see lib.rs),
         // and any errors from `create_deployment_inner` are captured in the `Result` and sent
         // to the receiver through `progress.finalize_deployment()`.
-        tokio::spawn(async move {
+        let task = async move {
             let mut progress: CreateDeploymentProgressSender = sender;
+            // Without a cancel receiver the sender of the stand-in is dropped, which never cancels
+            let cancelled = progress
+                .cancelled
+                .take()
+                .unwrap_or_else(|| oneshot::channel().1);
+
+            let result = {
+                let creation =
+                    pin!(client.create_deployment_inner(deployment_options, &mut progress));
+                match future::select(creation, cancelled).await {
+                    Either::Left((result, _)) => result,
+                    Either::Right((Ok(()), _)) => Err(CreateDeploymentError::Cancelled),
+                    // The progress was dropped without cancelling, finish the creation
+                    Either::Right((Err(_), creation)) => creation.await,
+                }
+            };
 
-            let result = client
-                .create_deployment_inner(deployment_options, &mut progress)
-                .await;
+            // Remove the container this creation made on a best-effort basis, never one that already had the name
+            if matches!(result, Err(CreateDeploymentError::Cancelled))
+                && let Some(container_id) = progress.created_container_id.get()
+            {
+                let remove_container_options = RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                };
+                _ = client
+                    .docker
+                    .remove_container(container_id, Some(remove_container_options))
+                    .await;
+            }
 
             // Forward the result (success or error) to the receiver via the channel.
             // The caller can await the returned `CreateDeploymentProgress` to receive this result.
             progress.finalize_deployment(result).await;
-        });
+        };
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(
+            task,
+            tracing::debug_span!("create_deployment", deployment = %container_name),
+        );
+        let handle = tokio::spawn(task);
+        self.tasks.track(created_container_id, handle);
 
         receiver
     }
@@ -94,25 +168,30 @@ impl<
             return Err(CreateDeploymentError::InvalidImage(image.clone()));
         }
 
+        // Docker would create a directory in place of a missing config file, fail early instead
+        if let Some(config_file) = &deployment_options.config_file
+            && !config_file.is_file()
+        {
+            return Err(CreateDeploymentError::ConfigFileNotFound(
+                config_file.clone(),
+            ));
+        }
+
         // Pull the image for Atlas Local if requested
         let will_pull_image = !deployment_options.skip_pull_image.unwrap_or(false);
         if will_pull_image {
-            let tag = deployment_options
-                .image_tag
-                .as_ref()
-                .map(ToString::to_string)
-                .unwrap_or_else(|| "latest".to_string());
-
-            self.pull_image(
-                deployment_options
-                    .image
-                    .as_ref()
-                    .unwrap_or(&ATLAS_LOCAL_IMAGE.to_string()),
-                tag.as_str(),
-            )
-            .await?;
+            // A pinned image is pulled by its digest
+            let image = deployment_options.effective_image();
+            self.pull_image(&image.repository, &image.pull_tag())
+                .await?;
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            step = "pull_image",
+            skipped = !will_pull_image,
+            "step finished"
+        );
         progress
             .set_pull_image_finished(if will_pull_image {
                 CreateDeploymentStepOutcome::Success
@@ -121,9 +200,20 @@ impl<
             })
             .await;
 
+        // Create the data volume with the local deployment label, Docker returns an existing volume unchanged
+        if let Some(volume_create_request) = deployment_options.data_volume_create_request() {
+            self.with_retries(|| self.docker.create_volume(volume_create_request.clone()))
+                .await
+                .map_err(CreateDeploymentError::CreateVolume)?;
+        }
+
         // Create the container with the correct configuration
         let create_container_options: CreateContainerOptions = (&deployment_options).into();
         let create_container_config: ContainerCreateBody = (&deployment_options).into();
+        let publishes_port = create_container_config
+            .host_config
+            .as_ref()
+            .is_some_and(|host_config| host_config.port_bindings.is_some());
 
         // Get the cluster name
         // It is safe to unwrap because CreateContainerOptions::from will generate a random name if none is provided
@@ -133,8 +223,13 @@ impl<
             .clone()
             .expect("Container name to be set by CreateContainerOptions::from");
 
-        self.docker
-            .create_container(Some(create_container_options), create_container_config)
+        let create_container_response = self
+            .with_retries(|| {
+                self.docker.create_container(
+                    Some(create_container_options.clone()),
+                    create_container_config.clone(),
+                )
+            })
             .await
             .map_err(|err| match err {
                 DockerError::Conflict => {
@@ -142,45 +237,127 @@ impl<
                 }
                 _ => CreateDeploymentError::CreateContainer(err),
             })?;
-
+        progress.set_created_container_id(create_container_response.id.clone());
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            step = "create_container",
+            container_id = %create_container_response.id,
+            "step finished"
+        );
         progress
             .set_create_container_finished(CreateDeploymentStepOutcome::Success)
             .await;
+        progress
+            .set_create_warnings(create_container_response.warnings)
+            .await;
 
-        // Start the Atlas Local container
-        self.docker
-            .start_container(&cluster_name.to_string(), None::<StartContainerOptions>)
-            .await
-            .map_err(CreateDeploymentError::CreateContainer)?;
-
+        // Start the Atlas Local container, time to healthy is measured from here
+        let started_at = Instant::now();
+        self.with_retries(|| {
+            self.docker
+                .start_container(&cluster_name, None::<StartContainerOptions>)
+        })
+        .await
+        .map_err(CreateDeploymentError::CreateContainer)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(step = "start_container", "step finished");
         progress
             .set_start_container_finished(CreateDeploymentStepOutcome::Success)
             .await;
 
         // Default to waiting for the deployment to be healthy
-        let will_wait_for_healthy = deployment_options.wait_until_healthy.unwrap_or(true);
-        if will_wait_for_healthy {
-            let watch_options = WatchOptions {
-                timeout_duration: deployment_options.wait_until_healthy_timeout,
-                allow_unhealthy_initial_state: false,
-            };
-            self.wait_for_healthy_deployment(&cluster_name, watch_options)
-                .await?;
+        let wait_strategy = deployment_options.effective_wait_strategy();
+        match wait_strategy {
+            WaitStrategy::Healthy => {
+                let watch_options = WatchOptions {
+                    timeout_duration: deployment_options.wait_until_healthy_timeout,
+                    deadline: None,
+                    poll_interval: None,
+                    max_polls: None,
+                    allow_unhealthy_initial_state: false,
+                };
+                self.wait_for_healthy_deployment(&cluster_name, watch_options)
+                    .await?;
+            }
+            WaitStrategy::TcpConnect => {
+                let deployment = self
+                    .with_retries(|| self.get_deployment(&cluster_name))
+                    .await
+                    .map_err(CreateDeploymentError::GetDeploymentError)?;
+                let address = host_address(&deployment).ok_or_else(|| {
+                    CreateDeploymentError::MissingPortBinding(cluster_name.clone())
+                })?;
+                let timeout_duration =
+                    self.watch_timeout(deployment_options.wait_until_healthy_timeout);
+                wait_for_tcp_connect(address, timeout_duration).await?;
+            }
+            WaitStrategy::None => {}
+        }
+
+        if wait_strategy != WaitStrategy::None {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                step = "wait_for_healthy_deployment",
+                elapsed = ?started_at.elapsed(),
+                "step finished"
+            );
+            progress.set_time_to_healthy(started_at.elapsed()).await;
         }
 
         progress
-            .set_wait_for_healthy_deployment_finished(if will_wait_for_healthy {
-                CreateDeploymentStepOutcome::Success
-            } else {
+            .set_wait_for_healthy_deployment_finished(if wait_strategy == WaitStrategy::None {
                 CreateDeploymentStepOutcome::Skipped
+            } else {
+                CreateDeploymentStepOutcome::Success
             })
             .await;
 
-        // Return the deployment details
-        self.get_deployment(&cluster_name)
+        // Return the deployment details, including the host port Docker picked when none was requested
+        self.get_started_deployment(&cluster_name, publishes_port)
             .await
             .map_err(CreateDeploymentError::GetDeploymentError)
     }
+
+    /// Gets a deployment that was just started.
+    ///
+    /// Docker reports the published host port once the container is started, the deployment is
+    /// inspected again a few times when the port is not reported yet.
+    async fn get_started_deployment(
+        &self,
+        cluster_name: &str,
+        publishes_port: bool,
+    ) -> Result<Deployment, GetDeploymentError> {
+        let mut attempts = 1;
+        loop {
+            let deployment = self
+                .with_retries(|| self.get_deployment(cluster_name))
+                .await?;
+            let port_bound = deployment
+                .port_bindings
+                .as_ref()
+                .is_some_and(|port_binding| port_binding.port.is_some());
+            if port_bound || !publishes_port || attempts >= PORT_BINDING_ATTEMPTS {
+                return Ok(deployment);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(attempts, "published port not reported yet, retrying");
+            attempts += 1;
+            tokio::time::sleep(PORT_BINDING_RETRY_INTERVAL).await;
+        }
+    }
+}
+
+/// Returns the host address the MongoDB port of the deployment is reachable on.
+fn host_address(deployment: &Deployment) -> Option<SocketAddr> {
+    let port_binding = deployment.port_bindings.as_ref()?;
+    let ip = match &port_binding.binding_type {
+        BindingType::Loopback | BindingType::AnyInterface => IpAddr::V4(Ipv4Addr::LOCALHOST),
+        BindingType::Specific { ip } => *ip,
+    };
+    Some(SocketAddr::new(ip, port_binding.port?))
 }
 
 #[cfg(test)]
@@ -188,24 +365,44 @@ mod tests {
     use super::*;
     use crate::client::WatchDeploymentError;
     use crate::docker::DockerError;
-    use crate::models::{ContainerHealthStatus, ImageTag};
+    use crate::models::{
+        ATLAS_LOCAL_IMAGE, AtlasLocalImage, ContainerHealthStatus, ImageTag, MongoDBPortBinding,
+        RetryPolicy,
+    };
     use bollard::{
         models::{
             ContainerConfig, ContainerCreateResponse, ContainerInspectResponse, ContainerState,
-            ContainerStateStatusEnum, HealthStatusEnum,
+            ContainerStateStatusEnum, CreateImageInfo, HealthStatusEnum, NetworkSettings,
+            PortBinding, Volume, VolumeCreateRequest,
         },
         query_parameters::InspectContainerOptions,
     };
+    use futures_util::{Stream, stream};
     use maplit::hashmap;
     use mockall::mock;
     use pretty_assertions::assert_eq;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
     use tokio::time;
 
     mock! {
         Docker {}
 
         impl DockerPullImage for Docker {
-            async fn pull_image(&self, image: &str, tag: &str) -> Result<(), DockerError>;
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerCreateVolume for Docker {
+            async fn create_volume(
+                &self,
+                config: VolumeCreateRequest,
+            ) -> Result<Volume, DockerError>;
         }
 
         impl DockerCreateContainer for Docker {
@@ -231,6 +428,13 @@ mod tests {
                 options: Option<InspectContainerOptions>,
             ) -> Result<ContainerInspectResponse, DockerError>;
         }
+        impl DockerRemoveContainer for Docker {
+            async fn remove_container(
+                &self,
+                container_id: &str,
+                options: Option<RemoveContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
     }
 
     fn create_test_container_inspect_response() -> ContainerInspectResponse {
@@ -254,6 +458,15 @@ mod tests {
                 }),
                 ..Default::default()
             }),
+            network_settings: Some(NetworkSettings {
+                ports: Some(hashmap! {
+                    "27017/tcp".to_string() => Some(vec![PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: Some("49152".to_string()),
+                    }]),
+                }),
+                ..Default::default()
+            }),
             ..Default::default()
         }
     }
@@ -386,7 +599,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -425,6 +638,121 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_create_deployment_random_port() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            mongodb_port_binding: Some(MongoDBPortBinding::new(None, BindingType::Loopback)),
+            ..Default::default()
+        };
+
+        // Docker is asked for a random host port
+        mock_docker
+            .expect_create_container()
+            .withf(|_, config| {
+                config
+                    .host_config
+                    .as_ref()
+                    .and_then(|host_config| host_config.port_bindings.as_ref())
+                    .and_then(|port_bindings| port_bindings.get("27017/tcp").cloned().flatten())
+                    == Some(vec![PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: None,
+                    }])
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap().port_bindings,
+            Some(MongoDBPortBinding::new(Some(49152), BindingType::Loopback))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_waits_for_published_port() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = mockall::Sequence::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            wait_strategy: Some(WaitStrategy::None),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // The published port is not reported right after the container is started
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(ContainerInspectResponse {
+                    network_settings: None,
+                    ..create_test_container_inspect_response()
+                })
+            });
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert_eq!(
+            result
+                .unwrap()
+                .port_bindings
+                .and_then(|port_binding| port_binding.port),
+            Some(49152)
+        );
+    }
+
     #[tokio::test]
     async fn test_create_deployment_pulls_preview_tag_when_image_tag_preview() {
         // Arrange
@@ -443,7 +771,7 @@ mod tests {
                 mockall::predicate::eq("preview"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -482,6 +810,64 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_create_deployment_pulls_pinned_image_by_digest() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let digest = "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            atlas_local_image: Some(
+                AtlasLocalImage::default()
+                    .with_tag(ImageTag::try_from("8.0.4").unwrap())
+                    .with_digest(digest)
+                    .unwrap(),
+            ),
+            wait_until_healthy: Some(false),
+            ..Default::default()
+        };
+
+        // Set up expectations - the pinned image is pulled and created by its digest
+        mock_docker
+            .expect_pull_image()
+            .with(
+                mockall::predicate::eq(ATLAS_LOCAL_IMAGE),
+                mockall::predicate::eq(digest),
+            )
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        mock_docker
+            .expect_create_container()
+            .withf(move |_, config| {
+                config.image == Some(format!("{ATLAS_LOCAL_IMAGE}:8.0.4@{digest}"))
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_create_deployment_pull_image_error() {
         // Arrange
@@ -495,7 +881,7 @@ mod tests {
         mock_docker
             .expect_pull_image()
             .times(1)
-            .returning(|_, _| Err(DockerError::ServerError));
+            .returning(|_, _| Box::pin(stream::iter(vec![Err(DockerError::ServerError)])));
 
         let client = Client::new(mock_docker);
 
@@ -510,6 +896,140 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_create_deployment_cancel_during_pull() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+
+        // The image pull never completes
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::pending()));
+        mock_docker.expect_create_container().never();
+        mock_docker.expect_remove_container().never();
+
+        let client = Client::new(mock_docker);
+        let progress = client.create_deployment(options);
+
+        // Let the background task start pulling the image
+        tokio::task::yield_now().await;
+
+        // Act
+        let result = progress.cancel().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::Cancelled
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_deployment_cancel_removes_container() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // The deployment never becomes healthy
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                mockall::predicate::eq("container_id"),
+                mockall::predicate::eq(Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+        let mut progress = client.create_deployment(options);
+        progress.wait_for_start_container_outcome().await.unwrap();
+
+        // Act
+        let result = progress.cancel().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::Cancelled
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_cancel_keeps_existing_container() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+        let create_attempts = Arc::new(AtomicU32::new(0));
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        // A container named `test-deployment` already exists, the retry of the creation would conflict with it
+        let attempts = create_attempts.clone();
+        mock_docker
+            .expect_create_container()
+            .returning(move |_, _| {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(DockerError::ServerError)
+                } else {
+                    Err(DockerError::Conflict)
+                }
+            });
+        mock_docker.expect_remove_container().never();
+
+        let client = Client::new(mock_docker).with_retry_policy(retry_policy());
+        let mut progress = client.create_deployment(options);
+        progress.wait_for_pull_image_outcome().await.unwrap();
+
+        // Act
+        // The cancel arrives while the creation waits to retry
+        let result = progress.cancel().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::Cancelled
+        ));
+        assert_eq!(create_attempts.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_create_deployment_container_already_exists() {
         // Arrange
@@ -523,26 +1043,156 @@ mod tests {
         mock_docker
             .expect_pull_image()
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::Conflict));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            CreateDeploymentError::ContainerAlreadyExists(name) => {
+                assert_eq!(name, "test-deployment");
+            }
+            _ => panic!("Expected ContainerAlreadyExists error"),
+        }
+    }
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            jitter: false,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_retries_transient_errors() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+        let create_attempts = Arc::new(AtomicU32::new(0));
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        // Fail twice with a server error, then succeed
+        let attempts = create_attempts.clone();
+        mock_docker
+            .expect_create_container()
+            .returning(move |_, _| {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(DockerError::ServerError)
+                } else {
+                    Ok(ContainerCreateResponse {
+                        id: "container_id".to_string(),
+                        warnings: vec![],
+                    })
+                }
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker).with_retry_policy(retry_policy());
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(create_attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_retry_gives_up() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(3)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker).with_retry_policy(retry_policy());
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::CreateContainer(DockerError::ServerError)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_retry_fails_fast_on_conflict() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
             .times(1)
             .returning(|_, _| Err(DockerError::Conflict));
 
-        let client = Client::new(mock_docker);
+        let client = Client::new(mock_docker).with_retry_policy(retry_policy());
 
         // Act
         let result = client.create_deployment(options).await;
 
         // Assert
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            CreateDeploymentError::ContainerAlreadyExists(name) => {
-                assert_eq!(name, "test-deployment");
-            }
-            _ => panic!("Expected ContainerAlreadyExists error"),
-        }
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::ContainerAlreadyExists(name) if name == "test-deployment"
+        ));
     }
 
     #[tokio::test]
@@ -558,7 +1208,7 @@ mod tests {
         mock_docker
             .expect_pull_image()
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -591,7 +1241,7 @@ mod tests {
         mock_docker
             .expect_pull_image()
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -638,7 +1288,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -700,7 +1350,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -748,6 +1398,103 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_create_deployment_time_to_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            ..Default::default()
+        };
+        let mut sequence = mockall::Sequence::new();
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Two polls report starting, each is followed by a one second sleep
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let mut progress = client.create_deployment(options);
+        let time_to_healthy = progress.wait_for_time_to_healthy().await;
+        let result = progress.await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(time_to_healthy, Some(time::Duration::from_secs(2)));
+    }
+
+    #[tokio::test]
+    async fn test_create_deployment_create_warnings() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![
+                        "Published ports are discarded when using host network mode".to_string(),
+                    ],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let mut progress = client.create_deployment(options);
+        let warnings = progress.wait_for_create_warnings().await;
+        let result = progress.await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            warnings.unwrap(),
+            vec!["Published ports are discarded when using host network mode".to_string()]
+        );
+    }
+
     #[tokio::test]
     async fn test_wait_for_healthy_deployment_disabled() {
         // Arrange
@@ -766,7 +1513,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -799,10 +1546,122 @@ mod tests {
         let client = Client::new(mock_docker);
 
         // Act
-        let result = client.create_deployment(options).await;
+        let mut progress = client.create_deployment(options);
+        let time_to_healthy = progress.wait_for_time_to_healthy().await;
+        let result = progress.await;
 
         // Assert
         assert!(result.is_ok());
+        assert_eq!(time_to_healthy, None);
+    }
+
+    #[tokio::test]
+    async fn test_create_deployment_wait_strategy_tcp_connect() {
+        // Arrange
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            wait_strategy: Some(WaitStrategy::TcpConnect),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Once to find the bound port, once to return the deployment
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("test-deployment"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(2)
+            .returning(move |_, _| {
+                Ok(crate::test_utils::create_container_inspect_response_no_auth(port))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let mut progress = client.create_deployment(options);
+
+        // Assert
+        assert_eq!(
+            progress
+                .wait_for_wait_for_healthy_deployment_outcome()
+                .await
+                .unwrap(),
+            CreateDeploymentStepOutcome::Success
+        );
+        assert!(progress.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_deployment_wait_strategy_tcp_connect_timeout() {
+        // Arrange
+        let port = {
+            // Release the port again so nothing is listening on it
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+
+        let mut mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            wait_strategy: Some(WaitStrategy::TcpConnect),
+            wait_until_healthy_timeout: Some(time::Duration::from_millis(300)),
+            ..Default::default()
+        };
+
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(crate::test_utils::create_container_inspect_response_no_auth(port))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::WatchDeployment(WatchDeploymentError::TcpConnectTimeout { .. })
+        ));
     }
 
     #[tokio::test]
@@ -824,7 +1683,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -888,7 +1747,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -929,6 +1788,7 @@ mod tests {
             CreateDeploymentError::WatchDeployment(WatchDeploymentError::UnhealthyDeployment {
                 deployment_name,
                 status,
+                ..
             }) => {
                 assert_eq!(deployment_name, "test-deployment");
                 assert_eq!(status, ContainerHealthStatus::None);
@@ -954,7 +1814,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -995,6 +1855,7 @@ mod tests {
             CreateDeploymentError::WatchDeployment(WatchDeploymentError::UnhealthyDeployment {
                 deployment_name,
                 status,
+                ..
             }) => {
                 assert_eq!(deployment_name, "test-deployment");
                 assert_eq!(status, ContainerHealthStatus::None);
@@ -1037,6 +1898,72 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_create_deployment_rejects_missing_config_file() {
+        let mock_docker = MockDocker::new();
+        let options = CreateDeploymentOptions {
+            config_file: Some(PathBuf::from("/nonexistent/mongod.conf")),
+            ..Default::default()
+        };
+
+        let client = Client::new(mock_docker);
+        let result = client.create_deployment(options).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CreateDeploymentError::ConfigFileNotFound(path) if path.as_os_str() == "/nonexistent/mongod.conf"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_deployment_mounts_config_file() {
+        let mut mock_docker = MockDocker::new();
+        let config_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            skip_pull_image: Some(true),
+            config_file: Some(config_file.clone()),
+            ..Default::default()
+        };
+
+        mock_docker.expect_pull_image().never();
+
+        mock_docker
+            .expect_create_container()
+            .withf(move |_, config| {
+                let binds = config
+                    .host_config
+                    .as_ref()
+                    .and_then(|host_config| host_config.binds.clone())
+                    .unwrap_or_default();
+                binds == vec![format!("{}:/etc/mongod.conf:ro", config_file.display())]
+                    && config.cmd
+                        == Some(vec!["--config".to_string(), "/etc/mongod.conf".to_string()])
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+        let result = client.create_deployment(options).await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_create_deployment_accepts_image_without_tag() {
         let mut mock_docker = MockDocker::new();
@@ -1053,7 +1980,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -1106,7 +2033,7 @@ mod tests {
                 mockall::predicate::eq("latest"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         mock_docker
             .expect_create_container()
@@ -1147,6 +2074,7 @@ mod tests {
             CreateDeploymentError::WatchDeployment(WatchDeploymentError::UnhealthyDeployment {
                 deployment_name,
                 status,
+                ..
             }) => {
                 assert_eq!(deployment_name, "test-deployment");
                 assert_eq!(status, ContainerHealthStatus::None);
@@ -1154,4 +2082,62 @@ mod tests {
             _ => panic!("Expected WatchDeployment error"),
         }
     }
+
+    /// Records the names of the spans created while it is the default subscriber.
+    #[cfg(feature = "tracing")]
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        span_names: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+    }
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut span_names = self.span_names.lock().unwrap();
+            span_names.push(span.metadata().name());
+            tracing::span::Id::from_u64(span_names.len() as u64)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event<'_>) {}
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_create_deployment_emits_span() {
+        // Arrange
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+        let options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            image: Some("mongo:6.0".to_string()),
+            ..Default::default()
+        };
+
+        let client = Client::new(MockDocker::new());
+
+        // Act
+        let result = client.create_deployment(options).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(
+            recorder
+                .span_names
+                .lock()
+                .unwrap()
+                .contains(&"create_deployment")
+        );
+    }
 }
@@ -1,22 +1,47 @@
 use std::{
     pin::Pin,
+    sync::{Arc, OnceLock},
     task::{Context, Poll},
+    time::Duration,
 };
 
 use futures::future::Fuse;
-use futures_util::FutureExt;
+use futures_util::{FutureExt, Stream, stream};
 use tokio::sync::oneshot::{self, Receiver, Sender, error::RecvError};
 
 use crate::models::Deployment;
 
 use super::CreateDeploymentError;
 
+/// Names of the creation steps in order of execution, as reported by [`CreateDeploymentProgress::into_stream`].
+const STEP_NAMES: [&str; 4] = [
+    "pull_image",
+    "create_container",
+    "start_container",
+    "wait_for_healthy_deployment",
+];
+
+/// An event yielded by [`CreateDeploymentProgress::into_stream`].
+#[derive(Debug)]
+pub enum CreateDeploymentStep {
+    /// A creation step finished, `name` is e.g. `pull_image` or `start_container`.
+    Step {
+        name: &'static str,
+        outcome: CreateDeploymentStepOutcome,
+    },
+    /// The creation finished, always the last event. Boxed to keep the step events small.
+    Finished(Box<Result<Deployment, CreateDeploymentError>>),
+}
+
 pub struct CreateDeploymentProgress {
     pub pull_image_finished: Fuse<Receiver<CreateDeploymentStepOutcome>>,
     pub create_container_finished: Fuse<Receiver<CreateDeploymentStepOutcome>>,
     pub start_container_finished: Fuse<Receiver<CreateDeploymentStepOutcome>>,
     pub wait_for_healthy_deployment_finished: Fuse<Receiver<CreateDeploymentStepOutcome>>,
+    pub time_to_healthy: Fuse<Receiver<Duration>>,
+    pub create_warnings: Fuse<Receiver<Vec<String>>>,
     pub deployment: Fuse<Receiver<Result<Deployment, CreateDeploymentError>>>,
+    cancel: Option<Sender<()>>,
 }
 
 impl CreateDeploymentProgress {
@@ -51,12 +76,76 @@ impl CreateDeploymentProgress {
         Self::await_receiver(&mut self.wait_for_healthy_deployment_finished).await
     }
 
+    /// Waits for the deployment to become ready and returns the time it took from starting the container.
+    ///
+    /// Returns `None` when the deployment was not waited for or did not become ready.
+    pub async fn wait_for_time_to_healthy(&mut self) -> Option<Duration> {
+        Self::await_receiver(&mut self.time_to_healthy).await.ok()
+    }
+
+    /// Waits for the container to be created and returns the warnings Docker reported, e.g. about deprecated options.
+    ///
+    /// Returns an error when the container was not created.
+    pub async fn wait_for_create_warnings(&mut self) -> Result<Vec<String>, RecvError> {
+        Self::await_receiver(&mut self.create_warnings).await
+    }
+
     pub async fn wait_for_deployment_outcome(
         &mut self,
     ) -> Result<Deployment, CreateDeploymentError> {
         // We use the Future implementation to wait for the deployment outcome
         self.await
     }
+
+    /// Cancels the creation and waits for the background task to stop.
+    ///
+    /// A container that may already have been created is force removed on a best-effort basis.
+    /// Dropping the progress without cancelling lets the creation finish in the background.
+    ///
+    /// # Returns
+    ///
+    /// [`CreateDeploymentError::Cancelled`] when the creation was cancelled, or the outcome of the
+    /// creation when it finished before it could be cancelled.
+    pub async fn cancel(mut self) -> Result<Deployment, CreateDeploymentError> {
+        if let Some(cancel) = self.cancel.take() {
+            // An error occurs when the task is already done, the outcome is then waiting in the channel
+            _ = cancel.send(());
+        }
+
+        self.await
+    }
+
+    /// Converts the progress into a stream of events, so it can be rendered generically.
+    ///
+    /// The outcome of every step is yielded in order of execution, followed by
+    /// [`CreateDeploymentStep::Finished`] with the created deployment or the error.
+    pub fn into_stream(self) -> impl Stream<Item = CreateDeploymentStep> + Send {
+        stream::unfold(Some((self, 0)), |state| async move {
+            let (mut progress, mut next_step) = state?;
+
+            while let Some(&name) = STEP_NAMES.get(next_step) {
+                let receiver = match next_step {
+                    0 => &mut progress.pull_image_finished,
+                    1 => &mut progress.create_container_finished,
+                    2 => &mut progress.start_container_finished,
+                    _ => &mut progress.wait_for_healthy_deployment_finished,
+                };
+                let outcome = Self::await_receiver(receiver).await;
+                next_step += 1;
+
+                // A step without an outcome is skipped, the final result reports what went wrong
+                if let Ok(outcome) = outcome {
+                    let step = CreateDeploymentStep::Step { name, outcome };
+                    return Some((step, Some((progress, next_step))));
+                }
+            }
+
+            Some((
+                CreateDeploymentStep::Finished(Box::new(progress.await)),
+                None,
+            ))
+        })
+    }
 }
 
 impl std::future::Future for CreateDeploymentProgress {
@@ -78,7 +167,12 @@ pub struct CreateDeploymentProgressSender {
     pub create_container_finished: Option<Sender<CreateDeploymentStepOutcome>>,
     pub start_container_finished: Option<Sender<CreateDeploymentStepOutcome>>,
     pub wait_for_healthy_deployment_finished: Option<Sender<CreateDeploymentStepOutcome>>,
+    pub time_to_healthy: Option<Sender<Duration>>,
+    pub create_warnings: Option<Sender<Vec<String>>>,
     pub deployment: Sender<Result<Deployment, CreateDeploymentError>>,
+    pub cancelled: Option<Receiver<()>>,
+    /// ID of the container once Docker created it, shared with the task tracker to clean up aborted creations.
+    pub created_container_id: Arc<OnceLock<String>>,
 }
 
 impl CreateDeploymentProgressSender {
@@ -98,6 +192,12 @@ impl CreateDeploymentProgressSender {
         false
     }
 
+    /// Records the ID of the container created for the deployment.
+    pub fn set_created_container_id(&self, container_id: String) {
+        // The container is only created once, a second ID cannot occur
+        _ = self.created_container_id.set(container_id);
+    }
+
     pub async fn set_pull_image_finished(&mut self, outcome: CreateDeploymentStepOutcome) {
         Self::send_outcome(&mut self.pull_image_finished, outcome).await;
     }
@@ -117,6 +217,20 @@ impl CreateDeploymentProgressSender {
         Self::send_outcome(&mut self.wait_for_healthy_deployment_finished, outcome).await;
     }
 
+    pub async fn set_time_to_healthy(&mut self, elapsed: Duration) {
+        if let Some(sender) = self.time_to_healthy.take() {
+            // An error occurs when there is not receiver, this is expected behavior that is safe to ignore
+            _ = sender.send(elapsed);
+        }
+    }
+
+    pub async fn set_create_warnings(&mut self, warnings: Vec<String>) {
+        if let Some(sender) = self.create_warnings.take() {
+            // An error occurs when there is not receiver, this is expected behavior that is safe to ignore
+            _ = sender.send(warnings);
+        }
+    }
+
     /// Finalizes the deployment process by marking all remaining steps and sending the final result.
     ///
     /// This method completes the deployment workflow by:
@@ -168,7 +282,10 @@ pub fn create_progress_pairs() -> (CreateDeploymentProgressSender, CreateDeploym
     let (start_container_finished, start_container_finished_receiver) = oneshot::channel();
     let (wait_for_healthy_deployment_finished, wait_for_healthy_deployment_finished_receiver) =
         oneshot::channel();
+    let (time_to_healthy, time_to_healthy_receiver) = oneshot::channel();
+    let (create_warnings, create_warnings_receiver) = oneshot::channel();
     let (deployment, deployment_receiver) = oneshot::channel();
+    let (cancel, cancelled) = oneshot::channel();
 
     (
         CreateDeploymentProgressSender {
@@ -176,7 +293,11 @@ pub fn create_progress_pairs() -> (CreateDeploymentProgressSender, CreateDeploym
             create_container_finished: Some(create_container_finished),
             start_container_finished: Some(start_container_finished),
             wait_for_healthy_deployment_finished: Some(wait_for_healthy_deployment_finished),
+            time_to_healthy: Some(time_to_healthy),
+            create_warnings: Some(create_warnings),
             deployment,
+            cancelled: Some(cancelled),
+            created_container_id: Default::default(),
         },
         CreateDeploymentProgress {
             pull_image_finished: pull_image_finished_receiver.fuse(),
@@ -184,7 +305,10 @@ pub fn create_progress_pairs() -> (CreateDeploymentProgressSender, CreateDeploym
             start_container_finished: start_container_finished_receiver.fuse(),
             wait_for_healthy_deployment_finished: wait_for_healthy_deployment_finished_receiver
                 .fuse(),
+            time_to_healthy: time_to_healthy_receiver.fuse(),
+            create_warnings: create_warnings_receiver.fuse(),
             deployment: deployment_receiver.fuse(),
+            cancel: Some(cancel),
         },
     )
 }
@@ -193,6 +317,7 @@ pub fn create_progress_pairs() -> (CreateDeploymentProgressSender, CreateDeploym
 mod tests {
     use super::*;
     use crate::models::{MongodbType, State};
+    use futures_util::StreamExt;
     use semver::Version;
 
     fn create_test_deployment() -> Deployment {
@@ -200,16 +325,22 @@ mod tests {
             container_id: "test_container_id".to_string(),
             name: Some("test-deployment".to_string()),
             state: State::Running,
+            health: None,
             port_bindings: None,
+            unix_socket_location: None,
+            network_mode: None,
             mongodb_type: MongodbType::Community,
             mongodb_version: Version::new(8, 0, 0),
+            replica_set_name: None,
             creation_source: None,
+            created_by_version: None,
             local_seed_location: None,
             mongodb_initdb_database: None,
             mongodb_initdb_root_password_file: None,
             mongodb_initdb_root_password: None,
             mongodb_initdb_root_username_file: None,
             mongodb_initdb_root_username: None,
+            mongodb_initdb_root_roles: None,
             mongodb_load_sample_data: None,
             voyage_api_key: None,
             mongot_log_file: None,
@@ -349,6 +480,98 @@ mod tests {
         }
     }
 
+    fn step(name: &'static str, outcome: CreateDeploymentStepOutcome) -> (String, String) {
+        (name.to_string(), format!("{outcome:?}"))
+    }
+
+    /// Flattens the events so they can be compared, the final event is reported as `finished`.
+    async fn collect_events(progress: CreateDeploymentProgress) -> Vec<(String, String)> {
+        progress
+            .into_stream()
+            .map(|event| match event {
+                CreateDeploymentStep::Step { name, outcome } => step(name, outcome),
+                CreateDeploymentStep::Finished(result) => (
+                    "finished".to_string(),
+                    if result.is_ok() { "Ok" } else { "Err" }.to_string(),
+                ),
+            })
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_success() {
+        let (mut sender, progress) = create_progress_pairs();
+
+        sender
+            .set_pull_image_finished(CreateDeploymentStepOutcome::Skipped)
+            .await;
+        sender
+            .set_create_container_finished(CreateDeploymentStepOutcome::Success)
+            .await;
+        sender
+            .set_start_container_finished(CreateDeploymentStepOutcome::Success)
+            .await;
+        sender
+            .set_wait_for_healthy_deployment_finished(CreateDeploymentStepOutcome::Success)
+            .await;
+        sender
+            .finalize_deployment(Ok(create_test_deployment()))
+            .await;
+
+        assert_eq!(
+            collect_events(progress).await,
+            vec![
+                step("pull_image", CreateDeploymentStepOutcome::Skipped),
+                step("create_container", CreateDeploymentStepOutcome::Success),
+                step("start_container", CreateDeploymentStepOutcome::Success),
+                step(
+                    "wait_for_healthy_deployment",
+                    CreateDeploymentStepOutcome::Success
+                ),
+                ("finished".to_string(), "Ok".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_failure() {
+        let (mut sender, progress) = create_progress_pairs();
+
+        sender
+            .set_pull_image_finished(CreateDeploymentStepOutcome::Success)
+            .await;
+        sender
+            .finalize_deployment(Err(create_test_error().await))
+            .await;
+
+        assert_eq!(
+            collect_events(progress).await,
+            vec![
+                step("pull_image", CreateDeploymentStepOutcome::Success),
+                step("create_container", CreateDeploymentStepOutcome::Failure),
+                step("start_container", CreateDeploymentStepOutcome::Skipped),
+                step(
+                    "wait_for_healthy_deployment",
+                    CreateDeploymentStepOutcome::Skipped
+                ),
+                ("finished".to_string(), "Err".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_sender_dropped() {
+        let (sender, progress) = create_progress_pairs();
+
+        drop(sender);
+
+        assert_eq!(
+            collect_events(progress).await,
+            vec![("finished".to_string(), "Err".to_string())]
+        );
+    }
+
     #[tokio::test]
     async fn test_future_implementation_success() {
         let (sender, progress) = create_progress_pairs();
@@ -2,7 +2,11 @@ use bollard::query_parameters::{RemoveContainerOptions, StopContainerOptions};
 
 use crate::{
     client::Client,
-    docker::{DockerError, DockerInspectContainer, DockerRemoveContainer, DockerStopContainer},
+    docker::{
+        DockerError, DockerInspectContainer, DockerListContainers, DockerRemoveContainer,
+        DockerStopContainer,
+    },
+    models::DeleteDeploymentOptions,
 };
 
 use super::GetDeploymentError;
@@ -19,34 +23,113 @@ pub enum DeleteDeploymentError {
 
 impl<D: DockerStopContainer + DockerRemoveContainer + DockerInspectContainer> Client<D> {
     /// Deletes a local Atlas deployment.
+    ///
+    /// Fails with [`GetDeploymentError::NotFound`] (wrapped in [`DeleteDeploymentError::GetDeployment`])
+    /// when the deployment does not exist.
     pub async fn delete_deployment(&self, name: &str) -> Result<(), DeleteDeploymentError> {
+        self.delete_deployment_with_options(name, DeleteDeploymentOptions::default())
+            .await
+    }
+
+    /// Deletes a local Atlas deployment using the provided options.
+    ///
+    /// A container that is already stopped is removed without an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name or ID of the deployment to delete.
+    /// * `options` - Whether to force the removal and remove the anonymous volumes of the container.
+    pub async fn delete_deployment_with_options(
+        &self,
+        name: &str,
+        options: DeleteDeploymentOptions,
+    ) -> Result<(), DeleteDeploymentError> {
+        let delete = self.delete_deployment_inner(name, options);
+        #[cfg(feature = "tracing")]
+        let delete = tracing::Instrument::instrument(
+            delete,
+            tracing::debug_span!("delete_deployment", deployment = name),
+        );
+        delete.await
+    }
+
+    async fn delete_deployment_inner(
+        &self,
+        name: &str,
+        options: DeleteDeploymentOptions,
+    ) -> Result<(), DeleteDeploymentError> {
         // Check that a deployment with that name exists and get the container ID.
         // This ensures we only try to delete valid Atlas local deployments.
         let deployment = self.get_deployment(name).await?;
         let container_id = deployment.container_id.as_str();
 
         // Attempt to stop the container gracefully before removal.
-        self.docker
+        // Docker answers 304 when the container is not running, a forced removal kills it anyway.
+        match self
+            .docker
             .stop_container(container_id, None::<StopContainerOptions>)
             .await
-            .map_err(DeleteDeploymentError::ContainerStop)?;
+        {
+            Ok(()) | Err(DockerError::NotModified) => {}
+            Err(_) if options.force => {}
+            Err(err) => return Err(DeleteDeploymentError::ContainerStop(err)),
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(step = "stop_container", container_id, "step finished");
 
-        // Remove the container from Docker.
-        self.docker
-            .remove_container(container_id, None::<RemoveContainerOptions>)
+        // Remove the container from Docker, an auto-removed container is already gone once it is stopped.
+        let remove_container_options = RemoveContainerOptions {
+            force: options.force,
+            v: options.remove_volumes,
+            ..Default::default()
+        };
+        match self
+            .docker
+            .remove_container(container_id, Some(remove_container_options))
             .await
-            .map_err(DeleteDeploymentError::ContainerRemove)?;
+        {
+            Ok(()) | Err(DockerError::NotFound) => {}
+            Err(err) => return Err(DeleteDeploymentError::ContainerRemove(err)),
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(step = "remove_container", container_id, "step finished");
 
         Ok(())
     }
 }
 
+impl<D: DockerStopContainer + DockerRemoveContainer + DockerInspectContainer + DockerListContainers>
+    Client<D>
+{
+    /// Deletes all local Atlas deployments, e.g. to clean up between test runs.
+    ///
+    /// Only containers carrying the local deployment label are listed (see [`Client::list_deployments`]),
+    /// other containers are never stopped or removed. A failed deletion does not stop the others,
+    /// the result of every deployment is returned with its name (or container ID when it has no name).
+    pub async fn delete_all_deployments(
+        &self,
+    ) -> Result<Vec<(String, Result<(), DeleteDeploymentError>)>, GetDeploymentError> {
+        let deployments = self.list_deployments().await?;
+
+        let mut results = Vec::with_capacity(deployments.len());
+        for deployment in deployments {
+            let result = self.delete_deployment(&deployment.container_id).await;
+            results.push((deployment.name.unwrap_or(deployment.container_id), result));
+        }
+
+        Ok(results)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::docker::DockerError;
-    use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
-    use mockall::mock;
+    use bollard::{
+        models::{ContainerInspectResponse, ContainerSummary},
+        query_parameters::{InspectContainerOptions, ListContainersOptions},
+    };
+    use mockall::{mock, predicate::eq};
 
     mock! {
         Docker {}
@@ -74,6 +157,13 @@ mod tests {
                 options: Option<InspectContainerOptions>,
             ) -> Result<ContainerInspectResponse, DockerError>;
         }
+
+        impl DockerListContainers for Docker {
+            async fn list_containers(
+                &self,
+                options: Option<ListContainersOptions>,
+            ) -> Result<Vec<ContainerSummary>, DockerError>;
+        }
     }
 
     fn create_test_container_inspect_response() -> ContainerInspectResponse {
@@ -131,7 +221,7 @@ mod tests {
             .expect_remove_container()
             .with(
                 mockall::predicate::eq("test_container_id"),
-                mockall::predicate::eq(None::<RemoveContainerOptions>),
+                mockall::predicate::eq(Some(RemoveContainerOptions::default())),
             )
             .times(1)
             .returning(|_, _| Ok(()));
@@ -162,10 +252,10 @@ mod tests {
         let result = client.delete_deployment("nonexistent-deployment").await;
 
         // Assert
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            DeleteDeploymentError::GetDeployment(_)
+            DeleteDeploymentError::GetDeployment(GetDeploymentError::NotFound { name })
+                if name == "nonexistent-deployment"
         ));
     }
 
@@ -198,6 +288,76 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_delete_deployment_already_stopped() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| Ok(create_test_container_inspect_response()));
+        mock_docker
+            .expect_stop_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotModified));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("test_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.delete_deployment("test-deployment").await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_deployment_with_options_force() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| Ok(create_test_container_inspect_response()));
+        mock_docker
+            .expect_stop_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("test_container_id"),
+                eq(Some(RemoveContainerOptions {
+                    force: true,
+                    v: true,
+                    ..Default::default()
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+        let options = DeleteDeploymentOptions::builder()
+            .force(true)
+            .remove_volumes(true)
+            .build();
+
+        // Act
+        let result = client
+            .delete_deployment_with_options("test-deployment", options)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_delete_deployment_remove_container_error() {
         // Arrange
@@ -231,4 +391,209 @@ mod tests {
             DeleteDeploymentError::ContainerRemove(_)
         ));
     }
+
+    #[tokio::test]
+    async fn test_delete_deployment_auto_removed_container() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // Set up expectations
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_stop_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Docker removed the container when it stopped
+        mock_docker
+            .expect_remove_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.delete_deployment("test-deployment").await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    fn container_summary(id: &str) -> ContainerSummary {
+        ContainerSummary {
+            id: Some(id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_deployments() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_containers()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .and_then(|filters| filters.get("label"))
+                    == Some(&vec!["mongodb-atlas-local=container".to_string()])
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    container_summary("test_container_id"),
+                    container_summary("other_container_id"),
+                ])
+            });
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test_container_id"), eq(None::<InspectContainerOptions>))
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                eq("other_container_id"),
+                eq(None::<InspectContainerOptions>),
+            )
+            .times(2)
+            .returning(|_, _| {
+                Ok(ContainerInspectResponse {
+                    id: Some("other_container_id".to_string()),
+                    name: Some("/other-deployment".to_string()),
+                    ..create_test_container_inspect_response()
+                })
+            });
+        mock_docker
+            .expect_stop_container()
+            .with(eq("test_container_id"), eq(None::<StopContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_stop_container()
+            .with(eq("other_container_id"), eq(None::<StopContainerOptions>))
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("test_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("other_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let results = client.delete_all_deployments().await.unwrap();
+
+        // Assert
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "test-deployment");
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "other-deployment");
+        assert!(matches!(
+            results[1].1,
+            Err(DeleteDeploymentError::ContainerStop(
+                DockerError::ServerError
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_deployments_skips_non_atlas_containers() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // Docker only lists containers with the local deployment label, the other container is not listed
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(|_| Ok(vec![container_summary("test_container_id")]));
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test_container_id"), eq(None::<InspectContainerOptions>))
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+        mock_docker
+            .expect_stop_container()
+            .with(eq("test_container_id"), eq(None::<StopContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("test_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                eq("non_atlas_container_id"),
+                eq(None::<InspectContainerOptions>),
+            )
+            .never();
+        mock_docker
+            .expect_stop_container()
+            .with(
+                eq("non_atlas_container_id"),
+                eq(None::<StopContainerOptions>),
+            )
+            .never();
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("non_atlas_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let results = client.delete_all_deployments().await.unwrap();
+
+        // Assert
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "test-deployment");
+        assert!(results[0].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_deployments_list_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(|_| Err(DockerError::ServerError));
+        mock_docker.expect_stop_container().never();
+        mock_docker.expect_remove_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.delete_all_deployments().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::ContainerInspect(DockerError::ServerError)
+        ));
+    }
 }
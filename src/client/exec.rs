@@ -0,0 +1,200 @@
+use crate::{
+    client::{Client, GetDeploymentError},
+    docker::{
+        CommandOutput, DockerInspectContainer, RunCommandInContainer, RunCommandInContainerError,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Failed to run command: {0}")]
+    RunCommand(#[from] RunCommandInContainerError),
+}
+
+impl<D: DockerInspectContainer + RunCommandInContainer> Client<D> {
+    /// Runs a command inside the container of a deployment, e.g. `mongosh --eval`, and returns its output.
+    ///
+    /// The command is run without a shell, every argument is passed as is.
+    /// A command writing to stderr is not an error, its output is returned in [`CommandOutput::stderr`].
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment to run the command in.
+    /// * `command` - The program to run followed by its arguments.
+    pub async fn exec(
+        &self,
+        container_id_or_name: &str,
+        command: Vec<String>,
+    ) -> Result<CommandOutput, ExecError> {
+        let deployment = self.get_deployment(container_id_or_name).await?;
+
+        Ok(self
+            .docker
+            .run_command_in_container(&deployment.container_id, command)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::DockerError, test_utils::create_container_inspect_response_with_auth};
+    use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    fn command(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_exec() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("test_container_id"),
+                eq(command(&["mongosh", "--quiet", "--eval", "db.version()"])),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec!["8.0.0".to_string()],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .exec(
+                "test-deployment",
+                command(&["mongosh", "--quiet", "--eval", "db.version()"]),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            CommandOutput {
+                stdout: vec!["8.0.0".to_string()],
+                stderr: vec![],
+                exit_code: Some(0),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exec_stderr() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![],
+                    stderr: vec![
+                        "ls: cannot access '/missing': No such file or directory".to_string(),
+                    ],
+                    exit_code: Some(0),
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .exec("test-deployment", command(&["ls", "/missing"]))
+            .await;
+
+        // Assert
+        let output = result.unwrap();
+        assert!(output.stdout.is_empty());
+        assert_eq!(
+            output.stderr,
+            vec!["ls: cannot access '/missing': No such file or directory".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exec_deployment_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_run_command_in_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .exec("nonexistent-deployment", command(&["true"]))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            ExecError::GetDeployment(GetDeploymentError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exec_run_command_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| Err(RunCommandInContainerError::GetOutput));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.exec("test-deployment", command(&["true"])).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            ExecError::RunCommand(RunCommandInContainerError::GetOutput)
+        ));
+    }
+}
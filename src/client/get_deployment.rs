@@ -1,22 +1,33 @@
-use bollard::query_parameters::InspectContainerOptions;
+use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
 
 use crate::{
     client::Client,
-    docker::{DockerError, DockerInspectContainer},
-    models::{Deployment, IntoDeploymentError},
+    docker::{DockerError, DockerInspectContainer, DockerListContainers},
+    models::{ContainerHealthStatus, Deployment, IntoDeploymentError},
 };
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetDeploymentError {
+    #[error("Deployment not found: {name}")]
+    NotFound { name: String },
     #[error("Failed to inspect container: {0}")]
     ContainerInspect(#[from] DockerError),
     #[error("The container is not a local Atlas deployment: {0}")]
     IntoDeployment(#[from] IntoDeploymentError),
+    #[error("\"{identifier}\" matches more than one deployment: {}", .container_ids.join(", "))]
+    AmbiguousIdentifier {
+        identifier: String,
+        container_ids: Vec<String>,
+    },
 }
 
 impl<D: DockerInspectContainer> Client<D> {
     /// Inspects a container.
     ///
+    /// Fails with [`GetDeploymentError::NotFound`] when Docker reports that the container does not exist.
+    /// Docker itself resolves an ID prefix that matches a single container, use [`Client::find_deployment`]
+    /// to only consider deployments and to tell an ambiguous prefix apart from a missing deployment.
+    ///
     /// # Arguments
     ///
     /// * `container_id_or_name` - The ID or name of the container to inspect.
@@ -26,13 +37,104 @@ impl<D: DockerInspectContainer> Client<D> {
     ) -> Result<Deployment, GetDeploymentError> {
         // Inspect the container to get the deployment details
         let container_inspect_response = self
-            .docker
-            .inspect_container(container_id_or_name, None::<InspectContainerOptions>)
+            .inspect_deployment_container(container_id_or_name)
             .await?;
 
         // Convert the container inspect response to a deployment
         Ok(container_inspect_response.try_into()?)
     }
+
+    /// Inspects the container of a deployment, reporting a missing container as [`GetDeploymentError::NotFound`].
+    pub(crate) async fn inspect_deployment_container(
+        &self,
+        container_id_or_name: &str,
+    ) -> Result<ContainerInspectResponse, GetDeploymentError> {
+        self.docker
+            .inspect_container(container_id_or_name, None::<InspectContainerOptions>)
+            .await
+            .map_err(|err| match err {
+                DockerError::NotFound => GetDeploymentError::NotFound {
+                    name: container_id_or_name.to_string(),
+                },
+                err => GetDeploymentError::ContainerInspect(err),
+            })
+    }
+
+    /// Checks whether a deployment exists.
+    ///
+    /// Returns `Ok(false)` only when Docker reports that the container does not exist,
+    /// any other error (e.g. the Docker daemon not being reachable) is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment.
+    pub async fn deployment_exists(
+        &self,
+        container_id_or_name: &str,
+    ) -> Result<bool, GetDeploymentError> {
+        match self.get_deployment(container_id_or_name).await {
+            Ok(_) => Ok(true),
+            Err(GetDeploymentError::NotFound { .. }) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks whether the health check currently reports a deployment as healthy.
+    ///
+    /// Returns `Ok(false)` while the deployment is starting, unhealthy or has no health check.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment.
+    pub async fn is_healthy(&self, container_id_or_name: &str) -> Result<bool, GetDeploymentError> {
+        let deployment = self.get_deployment(container_id_or_name).await?;
+        Ok(deployment.health == Some(ContainerHealthStatus::Healthy))
+    }
+}
+
+impl<D: DockerInspectContainer + DockerListContainers> Client<D> {
+    /// Gets a deployment by name, ID or container ID prefix, such as the 12 character short ID.
+    ///
+    /// The container is inspected directly first, the deployments are only listed when Docker finds nothing
+    /// or rejects the identifier as an ambiguous prefix.
+    /// Fails with [`GetDeploymentError::AmbiguousIdentifier`] when the prefix matches several deployments,
+    /// and with the error of the direct lookup when it matches none.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The name, ID or ID prefix of the deployment.
+    pub async fn find_deployment(
+        &self,
+        identifier: &str,
+    ) -> Result<Deployment, GetDeploymentError> {
+        // Docker answers an ambiguous ID prefix with a bad request
+        let lookup_error = match self.get_deployment(identifier).await {
+            Err(
+                err @ (GetDeploymentError::NotFound { .. }
+                | GetDeploymentError::ContainerInspect(DockerError::BadRequest)),
+            ) => err,
+            result => return result,
+        };
+
+        let mut matches = self
+            .list_deployments()
+            .await?
+            .into_iter()
+            .filter(|deployment| deployment.container_id.starts_with(identifier))
+            .collect::<Vec<_>>();
+
+        match matches.len() {
+            0 => Err(lookup_error),
+            1 => Ok(matches.remove(0)),
+            _ => Err(GetDeploymentError::AmbiguousIdentifier {
+                identifier: identifier.to_string(),
+                container_ids: matches
+                    .into_iter()
+                    .map(|deployment| deployment.container_id)
+                    .collect(),
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -41,9 +143,14 @@ mod tests {
     use crate::{
         docker::DockerError,
         models::{CreationSource, MongodbType, State},
+        test_utils::create_container_inspect_response_with_auth,
     };
-    use bollard::models::{
-        ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+            ContainerSummary, Health, HealthStatusEnum,
+        },
+        query_parameters::ListContainersOptions,
     };
     use maplit::hashmap;
     use mockall::mock;
@@ -53,6 +160,13 @@ mod tests {
     mock! {
         Docker {}
 
+        impl DockerListContainers for Docker {
+            async fn list_containers(
+                &self,
+                options: Option<ListContainersOptions>,
+            ) -> Result<Vec<ContainerSummary>, DockerError>;
+        }
+
         impl DockerInspectContainer for Docker {
             async fn inspect_container(
                 &self,
@@ -109,16 +223,22 @@ mod tests {
                 container_id: "test_container_id".to_string(),
                 name: Some("test-deployment".to_string()),
                 state: State::Running,
+                health: None,
                 mongodb_type: MongodbType::Community,
                 mongodb_version: Version::new(8, 0, 0),
+                replica_set_name: None,
                 port_bindings: None,
+                unix_socket_location: None,
+                network_mode: None,
                 creation_source: Some(CreationSource::AtlasCLI),
+                created_by_version: None,
                 local_seed_location: None,
                 mongodb_initdb_database: None,
                 mongodb_initdb_root_password_file: None,
                 mongodb_initdb_root_password: None,
                 mongodb_initdb_root_username_file: None,
                 mongodb_initdb_root_username: None,
+                mongodb_initdb_root_roles: None,
                 mongodb_load_sample_data: None,
                 voyage_api_key: None,
                 mongot_log_file: None,
@@ -130,7 +250,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_deployment_container_inspect_error() {
+    async fn test_get_deployment_not_found() {
         // Arrange
         let mut mock_docker = MockDocker::new();
 
@@ -150,10 +270,32 @@ mod tests {
         let result = client.get_deployment("nonexistent-deployment").await;
 
         // Assert
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            GetDeploymentError::ContainerInspect(_)
+            GetDeploymentError::NotFound { name } if name == "nonexistent-deployment"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_deployment_container_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // Set up expectations
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_deployment("test-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::ContainerInspect(DockerError::ServerError)
         ));
     }
 
@@ -193,4 +335,277 @@ mod tests {
             GetDeploymentError::IntoDeployment(_)
         ));
     }
+
+    fn create_container_inspect_response(id: &str) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some(id.to_string()),
+            name: Some(format!("/deployment-{id}")),
+            config: Some(ContainerConfig {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => "8.0.0".to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Expects a direct lookup of `identifier` that fails with `lookup_error`, then a listing of `container_ids`.
+    fn expect_prefix_lookup(
+        mock_docker: &mut MockDocker,
+        identifier: &str,
+        lookup_error: DockerError,
+        container_ids: &[&str],
+    ) {
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq(identifier.to_string()),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(move |_, _| Err(lookup_error.clone()));
+
+        let container_summaries = container_ids
+            .iter()
+            .map(|id| ContainerSummary {
+                id: Some(id.to_string()),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+
+        for id in container_ids {
+            let container_inspect_response = create_container_inspect_response(id);
+            mock_docker
+                .expect_inspect_container()
+                .with(
+                    mockall::predicate::eq(id.to_string()),
+                    mockall::predicate::eq(None::<InspectContainerOptions>),
+                )
+                .times(1)
+                .returning(move |_, _| Ok(container_inspect_response.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_deployment_direct() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("deployment-1"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response("0123456789abcdef")));
+        mock_docker.expect_list_containers().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.find_deployment("deployment-1").await;
+
+        // Assert
+        assert_eq!(result.unwrap().container_id, "0123456789abcdef");
+    }
+
+    #[tokio::test]
+    async fn test_find_deployment_unique_prefix() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_prefix_lookup(
+            &mut mock_docker,
+            "0123456789ab",
+            DockerError::NotFound,
+            &["0123456789abcdef", "fedcba9876543210"],
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.find_deployment("0123456789ab").await;
+
+        // Assert
+        assert_eq!(result.unwrap().container_id, "0123456789abcdef");
+    }
+
+    #[tokio::test]
+    async fn test_find_deployment_ambiguous_prefix() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_prefix_lookup(
+            &mut mock_docker,
+            "0123",
+            DockerError::BadRequest,
+            &["0123456789abcdef", "0123fedcba987654", "fedcba9876543210"],
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.find_deployment("0123").await;
+
+        // Assert
+        match result.unwrap_err() {
+            GetDeploymentError::AmbiguousIdentifier {
+                identifier,
+                container_ids,
+            } => {
+                assert_eq!(identifier, "0123");
+                assert_eq!(
+                    container_ids,
+                    vec![
+                        "0123456789abcdef".to_string(),
+                        "0123fedcba987654".to_string()
+                    ]
+                );
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_deployment_no_match() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        expect_prefix_lookup(
+            &mut mock_docker,
+            "abc",
+            DockerError::NotFound,
+            &["0123456789abcdef"],
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.find_deployment("abc").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::NotFound { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deployment_exists() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.deployment_exists("test-deployment").await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_deployment_exists_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.deployment_exists("nonexistent-deployment").await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_deployment_exists_docker_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.deployment_exists("test-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::ContainerInspect(DockerError::ServerError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_is_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = mockall::Sequence::new();
+        for status in [HealthStatusEnum::HEALTHY, HealthStatusEnum::STARTING] {
+            mock_docker
+                .expect_inspect_container()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(move |_, _| {
+                    let mut response = create_container_inspect_response_with_auth(27017);
+                    if let Some(state) = response.state.as_mut() {
+                        state.health = Some(Health {
+                            status: Some(status),
+                            ..Default::default()
+                        });
+                    }
+                    Ok(response)
+                });
+        }
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let healthy = client.is_healthy("test-deployment").await;
+        let starting = client.is_healthy("test-deployment").await;
+
+        // Assert
+        assert!(healthy.unwrap());
+        assert!(!starting.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_healthy_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_healthy("nonexistent-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::NotFound { .. }
+        ));
+    }
 }
@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::{
+    client::Client,
+    docker::{DockerDiskUsage, DockerError},
+    models::{
+        ATLAS_LOCAL_IMAGE, DiskUsage, LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetDiskUsageError {
+    #[error("Failed to get disk usage: {0}")]
+    DiskUsage(#[from] DockerError),
+}
+
+impl<D: DockerDiskUsage> Client<D> {
+    /// Gets the disk space used by local Atlas deployments.
+    ///
+    /// Only resources belonging to deployments are counted: the deployment containers,
+    /// the Atlas Local images and the images deployments run on, and the volumes mounted by deployments.
+    /// Shared image layers are counted for every image using them, like `docker system df -v` does.
+    pub async fn get_disk_usage(&self) -> Result<DiskUsage, GetDiskUsageError> {
+        let system_disk_usage = self.docker.disk_usage().await?;
+
+        let containers = system_disk_usage
+            .container_usage
+            .and_then(|usage| usage.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(is_deployment_container)
+            .collect::<Vec<_>>();
+
+        // Images and volumes are attributed to deployments through the containers using them
+        let image_ids = containers
+            .iter()
+            .filter_map(|container| container["ImageID"].as_str())
+            .collect::<HashSet<_>>();
+        let volume_names = containers
+            .iter()
+            .filter_map(|container| container["Mounts"].as_array())
+            .flatten()
+            .filter(|mount| mount["Type"] == "volume")
+            .filter_map(|mount| mount["Name"].as_str())
+            .collect::<HashSet<_>>();
+
+        let images = system_disk_usage
+            .image_usage
+            .and_then(|usage| usage.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|image| {
+                image["Id"]
+                    .as_str()
+                    .is_some_and(|id| image_ids.contains(id))
+                    || is_atlas_local_image(image)
+            })
+            .map(|image| size(&image["Size"]))
+            .sum();
+
+        let volumes = system_disk_usage
+            .volume_usage
+            .and_then(|usage| usage.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|volume| {
+                volume["Name"]
+                    .as_str()
+                    .is_some_and(|name| volume_names.contains(name))
+            })
+            .map(|volume| size(&volume["UsageData"]["Size"]))
+            .sum();
+
+        Ok(DiskUsage {
+            containers: containers
+                .iter()
+                .map(|container| size(&container["SizeRw"]))
+                .sum(),
+            images,
+            volumes,
+        })
+    }
+}
+
+fn is_deployment_container(container: &Value) -> bool {
+    container["Labels"][LOCAL_DEPLOYMENT_LABEL_KEY] == LOCAL_DEPLOYMENT_LABEL_VALUE
+}
+
+fn is_atlas_local_image(image: &Value) -> bool {
+    image["RepoTags"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .any(|tag| {
+            tag.strip_prefix(ATLAS_LOCAL_IMAGE)
+                .is_some_and(|tag| tag.starts_with(':'))
+        })
+}
+
+/// Reads a size in bytes, Docker reports `-1` when the size was not computed.
+fn size(value: &Value) -> u64 {
+    value
+        .as_i64()
+        .and_then(|size| u64::try_from(size).ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{
+        ContainersDiskUsage, ImagesDiskUsage, SystemDataUsageResponse, VolumesDiskUsage,
+    };
+    use mockall::mock;
+    use serde_json::json;
+
+    mock! {
+        Docker {}
+
+        impl DockerDiskUsage for Docker {
+            async fn disk_usage(&self) -> Result<SystemDataUsageResponse, DockerError>;
+        }
+    }
+
+    fn create_system_data_usage_response() -> SystemDataUsageResponse {
+        SystemDataUsageResponse {
+            container_usage: Some(ContainersDiskUsage {
+                items: Some(vec![
+                    json!({
+                        "Id": "deployment1",
+                        "ImageID": "sha256:atlas-local-8",
+                        "Labels": { "mongodb-atlas-local": "container" },
+                        "SizeRw": 1000,
+                        "Mounts": [
+                            { "Type": "volume", "Name": "deployment1-data" },
+                            { "Type": "bind", "Source": "/host/seed-data" },
+                        ],
+                    }),
+                    json!({
+                        "Id": "deployment2",
+                        "ImageID": "sha256:custom-image",
+                        "Labels": { "mongodb-atlas-local": "container" },
+                        "SizeRw": 500,
+                    }),
+                    json!({
+                        "Id": "unrelated",
+                        "ImageID": "sha256:nginx",
+                        "Labels": { "app": "nginx" },
+                        "SizeRw": 100000,
+                        "Mounts": [{ "Type": "volume", "Name": "nginx-data" }],
+                    }),
+                ]),
+                ..Default::default()
+            }),
+            image_usage: Some(ImagesDiskUsage {
+                items: Some(vec![
+                    json!({
+                        "Id": "sha256:atlas-local-8",
+                        "RepoTags": ["quay.io/mongodb/mongodb-atlas-local:8.0"],
+                        "Size": 10000,
+                    }),
+                    json!({
+                        "Id": "sha256:atlas-local-7",
+                        "RepoTags": ["quay.io/mongodb/mongodb-atlas-local:7.0"],
+                        "Size": 20000,
+                    }),
+                    json!({
+                        "Id": "sha256:custom-image",
+                        "RepoTags": ["registry.example.com/atlas-local:patched"],
+                        "Size": 30000,
+                    }),
+                    json!({
+                        "Id": "sha256:nginx",
+                        "RepoTags": ["nginx:latest"],
+                        "Size": 400000,
+                    }),
+                    json!({
+                        "Id": "sha256:lookalike",
+                        "RepoTags": ["quay.io/mongodb/mongodb-atlas-local-fork:latest"],
+                        "Size": 500000,
+                    }),
+                ]),
+                ..Default::default()
+            }),
+            volume_usage: Some(VolumesDiskUsage {
+                items: Some(vec![
+                    json!({ "Name": "deployment1-data", "UsageData": { "Size": 3000, "RefCount": 1 } }),
+                    json!({ "Name": "nginx-data", "UsageData": { "Size": 600000, "RefCount": 1 } }),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_disk_usage() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_disk_usage()
+            .times(1)
+            .returning(|| Ok(create_system_data_usage_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_disk_usage().await;
+
+        // Assert
+        let disk_usage = result.unwrap();
+        assert_eq!(
+            disk_usage,
+            DiskUsage {
+                containers: 1500,
+                images: 60000,
+                volumes: 3000,
+            }
+        );
+        assert_eq!(disk_usage.total(), 64500);
+    }
+
+    #[tokio::test]
+    async fn test_get_disk_usage_empty() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_disk_usage()
+            .times(1)
+            .returning(|| Ok(SystemDataUsageResponse::default()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_disk_usage().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), DiskUsage::default());
+    }
+
+    #[tokio::test]
+    async fn test_get_disk_usage_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_disk_usage()
+            .times(1)
+            .returning(|| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_disk_usage().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDiskUsageError::DiskUsage(DockerError::ServerError)
+        ));
+    }
+
+    #[test]
+    fn test_size_not_computed() {
+        assert_eq!(size(&json!(-1)), 0);
+        assert_eq!(size(&Value::Null), 0);
+        assert_eq!(size(&json!(42)), 42);
+    }
+}
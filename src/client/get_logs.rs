@@ -1,14 +1,18 @@
+use bollard::query_parameters::InspectContainerOptions;
+use futures_util::{Stream, StreamExt, pin_mut};
+
 use crate::{
     client::Client,
-    docker::DockerLogContainer,
-    models::{LogOutput, LogsOptions},
+    docker::{DockerError, DockerInspectContainer, DockerLogContainer},
+    models::{FollowLogsOptions, LogChunk, LogOutput, LogSource, LogsOptions},
 };
-use futures_util::{StreamExt, pin_mut};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetLogsError {
     #[error("Failed to get container logs: {0}")]
     ContainerLogs(String),
+    #[error("Failed to inspect container: {0}")]
+    ContainerInspect(#[from] DockerError),
 }
 
 impl<D: DockerLogContainer> Client<D> {
@@ -52,13 +56,76 @@ impl<D: DockerLogContainer> Client<D> {
 
         Ok(logs)
     }
+
+    /// Gets the logs from a container as lines, each tagged with the process that most likely wrote it.
+    ///
+    /// Atlas Local runs both mongod and mongot, whose logs interleave. The source of every line is guessed
+    /// from its content with [`LogSource::detect`]. Empty lines are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the container to get logs from.
+    /// * `options` - Optional logging options (e.g., tail, timestamps, etc.)
+    pub async fn get_logs_by_source(
+        &self,
+        container_id_or_name: &str,
+        options: Option<LogsOptions>,
+    ) -> Result<Vec<(LogSource, String)>, GetLogsError> {
+        let logs = self.get_logs(container_id_or_name, options).await?;
+
+        Ok(logs
+            .iter()
+            .flat_map(|log| {
+                log.as_str_lossy()
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| (LogSource::detect(line), line.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+}
+
+impl<D: DockerLogContainer + DockerInspectContainer> Client<D> {
+    /// Follows the logs of a container, yielding chunks as they are written.
+    ///
+    /// The container is inspected first so a missing container is reported before any log is streamed.
+    /// The stream ends when the container stops, errors while streaming are yielded as [`GetLogsError::ContainerLogs`].
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the container to follow the logs of.
+    /// * `options` - Which streams to follow, where to start and whether to add timestamps.
+    pub async fn follow_logs<'a>(
+        &'a self,
+        container_id_or_name: &'a str,
+        options: FollowLogsOptions,
+    ) -> Result<impl Stream<Item = Result<LogChunk, GetLogsError>> + 'a, GetLogsError> {
+        self.docker
+            .inspect_container(container_id_or_name, None::<InspectContainerOptions>)
+            .await?;
+
+        let timestamps = options.timestamps;
+        let stream = self.docker.logs(
+            container_id_or_name,
+            Some(bollard::query_parameters::LogsOptions::from(options)),
+        );
+
+        Ok(stream.map(move |result| {
+            result
+                .map(|output| LogChunk::new(LogOutput::from(output), timestamps))
+                .map_err(GetLogsError::ContainerLogs)
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::LogsOptions;
-    use futures_util::{Stream, stream};
+    use bollard::models::ContainerInspectResponse;
+    use bytes::Bytes;
+    use futures_util::stream;
     use mockall::mock;
 
     mock! {
@@ -71,6 +138,14 @@ mod tests {
                 options: Option<bollard::query_parameters::LogsOptions>,
             ) -> impl Stream<Item = Result<bollard::container::LogOutput, String>>;
         }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
     }
 
     #[tokio::test]
@@ -181,4 +256,192 @@ mod tests {
         // Verify third is stdout
         assert!(logs[2].is_stdout());
     }
+
+    #[tokio::test]
+    async fn test_follow_logs() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .withf(|container_id, _| container_id == "test-container")
+            .times(1)
+            .returning(|_, _| Ok(ContainerInspectResponse::default()));
+
+        mock_docker
+            .expect_logs()
+            .withf(|container_id, options| {
+                container_id == "test-container"
+                    && options
+                        .as_ref()
+                        .is_some_and(|options| options.follow && options.timestamps)
+            })
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    Ok(bollard::container::LogOutput::StdOut {
+                        message: "2024-01-02T03:04:05Z Waiting for connections\n".into(),
+                    }),
+                    Ok(bollard::container::LogOutput::StdErr {
+                        message: "2024-01-02T03:04:06Z warning\n".into(),
+                    }),
+                ]))
+            });
+
+        let client = Client::new(mock_docker);
+        let options = FollowLogsOptions::builder()
+            .stdout(true)
+            .stderr(true)
+            .timestamps(true)
+            .build();
+
+        // Act
+        let chunks = client
+            .follow_logs("test-container", options)
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert: the stream ends once the container stops
+        assert_eq!(chunks.len(), 2);
+        let first = chunks[0].as_ref().unwrap();
+        assert_eq!(
+            first.output,
+            LogOutput::StdOut {
+                message: Bytes::from("2024-01-02T03:04:05Z Waiting for connections\n")
+            }
+        );
+        assert_eq!(first.timestamp.map(|t| t.timestamp()), Some(1704164645));
+        assert!(chunks[1].as_ref().unwrap().output.is_stderr());
+    }
+
+    #[tokio::test]
+    async fn test_follow_logs_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_logs().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .follow_logs("nonexistent-container", FollowLogsOptions::default())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.err().unwrap(),
+            GetLogsError::ContainerInspect(DockerError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_follow_logs_stream_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(ContainerInspectResponse::default()));
+
+        mock_docker.expect_logs().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                Ok(bollard::container::LogOutput::StdOut {
+                    message: "line\n".into(),
+                }),
+                Err("connection reset".to_string()),
+            ]))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let chunks = client
+            .follow_logs("test-container", FollowLogsOptions::default())
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].as_ref().unwrap().timestamp, None);
+        assert!(matches!(
+            chunks[1].as_ref().unwrap_err(),
+            GetLogsError::ContainerLogs(message) if message == "connection reset"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_by_source() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_logs()
+            .withf(|container_id, _| container_id == "test-container")
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    Ok(bollard::container::LogOutput::StdOut {
+                        message: "Starting runner...\n".into(),
+                    }),
+                    // A single chunk can hold lines of both processes
+                    Ok(bollard::container::LogOutput::StdOut {
+                        message: concat!(
+                            r#"{"t":{"$date":"2025-01-01T00:00:00.000+00:00"},"s":"I","c":"NETWORK","msg":"Waiting for connections"}"#,
+                            "\n",
+                            r#"{"t":"2025-01-01T00:00:00.100Z","s":"INFO","svc":"MONGOT","msg":"Started"}"#,
+                            "\n\n",
+                        )
+                        .into(),
+                    }),
+                ]))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let logs = client
+            .get_logs_by_source("test-container", None)
+            .await
+            .expect("get_logs_by_source should succeed");
+
+        // Assert
+        let sources = logs.iter().map(|(source, _)| *source).collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![LogSource::Unknown, LogSource::Mongod, LogSource::Mongot]
+        );
+        assert_eq!(logs[0].1, "Starting runner...");
+    }
+
+    #[tokio::test]
+    async fn test_get_logs_by_source_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_logs()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::iter(vec![Err("No such container".to_string())])));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .get_logs_by_source("nonexistent-container", None)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetLogsError::ContainerLogs(_)
+        ));
+    }
 }
@@ -23,11 +23,138 @@ pub async fn get_mongodb_secret<D: RunCommandInContainer>(
             )
             .await?;
 
-        if let Some(line) = command_output.stdout.into_iter().next() {
-            return Ok(Some(line));
+        // cat fails when the file is missing or not readable by the exec user
+        if command_output
+            .exit_code
+            .is_some_and(|exit_code| exit_code != 0)
+        {
+            return Err(RunCommandInContainerError::SecretFileUnreadable {
+                path: file_value.to_string(),
+            });
+        }
+
+        // Secret files are often written with a trailing newline, which is not part of the secret
+        let secret = command_output.stdout.join("\n");
+        let secret = secret.trim_end();
+        if !secret.is_empty() {
+            return Ok(Some(secret.to_string()));
         }
     }
 
     // If the value is not found in the environment variables or the file, return None
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::CommandOutput, test_utils::create_container_inspect_response_no_auth};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    fn create_test_deployment() -> Deployment {
+        let mut container_inspect_response = create_container_inspect_response_no_auth(27017);
+        if let Some(config) = container_inspect_response.config.as_mut() {
+            config.env = Some(vec![
+                "MONGODB_INITDB_ROOT_PASSWORD_FILE=/run/secrets/password".to_string(),
+            ]);
+        }
+        Deployment::try_from(container_inspect_response).unwrap()
+    }
+
+    async fn get_password(
+        mock_docker: &MockDocker,
+    ) -> Result<Option<String>, RunCommandInContainerError> {
+        get_mongodb_secret(
+            mock_docker,
+            &create_test_deployment(),
+            |d| d.mongodb_initdb_root_password.as_deref(),
+            |d| d.mongodb_initdb_root_password_file.as_deref(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_get_mongodb_secret_trims_trailing_newline() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("test_container_id"),
+                eq(vec!["cat".to_string(), "/run/secrets/password".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec!["s3cret  ".to_string(), "".to_string()],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        // Act
+        let result = get_password(&mock_docker).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), Some("s3cret".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_mongodb_secret_unreadable_file() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![],
+                    stderr: vec!["cat: /run/secrets/password: Permission denied".to_string()],
+                    exit_code: Some(1),
+                })
+            });
+
+        // Act
+        let result = get_password(&mock_docker).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            RunCommandInContainerError::SecretFileUnreadable { path } if path == "/run/secrets/password"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_mongodb_secret_empty_file() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec!["".to_string()],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        // Act
+        let result = get_password(&mock_docker).await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::path::PathBuf;
+
+use crate::{
+    client::Client,
+    docker::DockerInspectContainer,
+    models::{Deployment, MongoDBPortBinding, unix_socket_file_name},
+};
+
+use super::GetDeploymentError;
+
+impl<D: DockerInspectContainer> Client<D> {
+    /// Gets the host-side path of the mongod unix socket of a local Atlas deployment.
+    ///
+    /// Returns `None` when the deployment was not created with a `unix_socket_location`.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment.
+    pub async fn get_socket_path(
+        &self,
+        container_id_or_name: &str,
+    ) -> Result<Option<PathBuf>, GetDeploymentError> {
+        let container_inspect_response = self
+            .inspect_deployment_container(container_id_or_name)
+            .await?;
+
+        // The socket is named after the port mongod listens on inside the container
+        let container_port = MongoDBPortBinding::container_port_of(&container_inspect_response);
+        let deployment = Deployment::try_from(container_inspect_response)?;
+
+        Ok(deployment.unix_socket_location.map(|unix_socket_location| {
+            PathBuf::from(unix_socket_location).join(unix_socket_file_name(container_port))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        docker::DockerError,
+        models::{MONGODB_CONTAINER_PORT_LABEL_KEY, UNIX_SOCKET_LOCATION},
+    };
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+            MountPoint,
+        },
+        query_parameters::InspectContainerOptions,
+    };
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+    use std::collections::HashMap;
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+    }
+
+    fn create_test_container_inspect_response(mounts: Vec<MountPoint>) -> ContainerInspectResponse {
+        create_test_container_inspect_response_with_labels(mounts, hashmap! {})
+    }
+
+    fn create_test_container_inspect_response_with_labels(
+        mounts: Vec<MountPoint>,
+        extra_labels: HashMap<String, String>,
+    ) -> ContainerInspectResponse {
+        let mut labels = hashmap! {
+            "mongodb-atlas-local".to_string() => "container".to_string(),
+            "version".to_string() => "8.0.0".to_string(),
+            "mongodb-type".to_string() => "community".to_string(),
+        };
+        labels.extend(extra_labels);
+
+        ContainerInspectResponse {
+            id: Some("test_container_id".to_string()),
+            name: Some("/test-deployment".to_string()),
+            config: Some(ContainerConfig {
+                labels: Some(labels),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            mounts: Some(mounts),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_socket_path() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(vec![MountPoint {
+                    destination: Some(UNIX_SOCKET_LOCATION.to_string()),
+                    source: Some("/host/sockets".to_string()),
+                    ..Default::default()
+                }]))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_socket_path("test-deployment").await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            Some(PathBuf::from("/host/sockets/mongodb-27017.sock"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_socket_path_custom_container_port() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response_with_labels(
+                    vec![MountPoint {
+                        destination: Some(UNIX_SOCKET_LOCATION.to_string()),
+                        source: Some("/host/sockets".to_string()),
+                        ..Default::default()
+                    }],
+                    hashmap! {
+                        MONGODB_CONTAINER_PORT_LABEL_KEY.to_string() => "28017".to_string(),
+                    },
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_socket_path("test-deployment").await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            Some(PathBuf::from("/host/sockets/mongodb-28017.sock"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_socket_path_not_configured() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response(vec![])));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_socket_path("test-deployment").await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_socket_path_get_deployment_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_socket_path("test-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetDeploymentError::NotFound { .. }
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    client::Client,
+    docker::{DockerError, DockerStats},
+    models::DeploymentStats,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetStatsError {
+    #[error("Failed to get container stats: {0}")]
+    Stats(#[from] DockerError),
+}
+
+impl<D: DockerStats> Client<D> {
+    /// Gets the current CPU, memory and network usage of a deployment.
+    ///
+    /// Docker takes two samples to compute the CPU usage, so this takes about a second.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment.
+    pub async fn get_stats(
+        &self,
+        container_id_or_name: &str,
+    ) -> Result<DeploymentStats, GetStatsError> {
+        let stats = self.docker.stats(container_id_or_name).await?;
+
+        Ok(DeploymentStats::from(&stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{
+        ContainerCpuStats, ContainerCpuUsage, ContainerMemoryStats, ContainerNetworkStats,
+        ContainerStatsResponse,
+    };
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerStats for Docker {
+            async fn stats(&self, container_id: &str) -> Result<ContainerStatsResponse, DockerError>;
+        }
+    }
+
+    fn cpu_stats(total_usage: u64, system_cpu_usage: u64) -> ContainerCpuStats {
+        ContainerCpuStats {
+            cpu_usage: Some(ContainerCpuUsage {
+                total_usage: Some(total_usage),
+                ..Default::default()
+            }),
+            system_cpu_usage: Some(system_cpu_usage),
+            online_cpus: Some(8),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_stats() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_stats()
+            .with(eq("test-deployment"))
+            .times(1)
+            .returning(|_| {
+                Ok(ContainerStatsResponse {
+                    cpu_stats: Some(cpu_stats(2_100_000_000, 18_000_000_000)),
+                    precpu_stats: Some(cpu_stats(2_000_000_000, 10_000_000_000)),
+                    memory_stats: Some(ContainerMemoryStats {
+                        usage: Some(512 * 1024 * 1024),
+                        limit: Some(8 * 1024 * 1024 * 1024),
+                        ..Default::default()
+                    }),
+                    networks: Some(hashmap! {
+                        "eth0".to_string() => ContainerNetworkStats {
+                            rx_bytes: Some(4096),
+                            tx_bytes: Some(8192),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_stats("test-deployment").await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            DeploymentStats {
+                // 100ms of CPU time while the system used 8s over 8 CPUs
+                cpu_percent: 10.0,
+                memory_usage_bytes: 512 * 1024 * 1024,
+                memory_limit_bytes: 8 * 1024 * 1024 * 1024,
+                network_rx_bytes: 4096,
+                network_tx_bytes: 8192,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_stats_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_stats()
+            .times(1)
+            .returning(|_| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.get_stats("nonexistent-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            GetStatsError::Stats(DockerError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{
+    client::Client,
+    docker::{DockerError, DockerInspectContainer, DockerTopContainer},
+    models::State,
+};
+
+use super::GetDeploymentError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IsSearchEnabledError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Failed to list container processes: {0}")]
+    TopProcesses(DockerError),
+}
+
+impl<D: DockerInspectContainer + DockerTopContainer> Client<D> {
+    /// Checks whether search (mongot) is enabled for a local Atlas deployment.
+    ///
+    /// A deployment configured with a mongot log file has search enabled.
+    /// Otherwise a running deployment is checked for a mongot process.
+    pub async fn is_search_enabled(&self, name: &str) -> Result<bool, IsSearchEnabledError> {
+        let deployment = self.get_deployment(name).await?;
+
+        if deployment.mongot_log_file.is_some() {
+            return Ok(true);
+        }
+
+        // Processes can only be listed while the container is running
+        if deployment.state != State::Running {
+            return Ok(false);
+        }
+
+        let top = self
+            .docker
+            .top_processes(&deployment.container_id)
+            .await
+            .map_err(IsSearchEnabledError::TopProcesses)?;
+
+        // The columns depend on the platform, so look for mongot in any of them
+        Ok(top.processes.unwrap_or_default().iter().any(|process| {
+            process
+                .iter()
+                .any(|column| column.split_whitespace().any(is_mongot_command))
+        }))
+    }
+}
+
+/// Matches both a bare `mongot` and a path to the executable, e.g. `/usr/local/bin/mongot`.
+fn is_mongot_command(word: &str) -> bool {
+    word.rsplit('/').next() == Some("mongot")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+            ContainerTopResponse,
+        },
+        query_parameters::InspectContainerOptions,
+    };
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerTopContainer for Docker {
+            async fn top_processes(
+                &self,
+                container_id: &str,
+            ) -> Result<ContainerTopResponse, DockerError>;
+        }
+    }
+
+    fn create_test_container_inspect_response(
+        env: Vec<String>,
+        status: ContainerStateStatusEnum,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("test_container_id".to_string()),
+            name: Some("/test-deployment".to_string()),
+            config: Some(ContainerConfig {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => "8.0.0".to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                env: Some(env),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(status),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn top_response(commands: &[&str]) -> ContainerTopResponse {
+        ContainerTopResponse {
+            titles: Some(vec!["PID".to_string(), "CMD".to_string()]),
+            processes: Some(
+                commands
+                    .iter()
+                    .enumerate()
+                    .map(|(pid, command)| vec![pid.to_string(), command.to_string()])
+                    .collect(),
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_is_search_enabled_mongot_process() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    vec![],
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+
+        mock_docker
+            .expect_top_processes()
+            .with(eq("test_container_id"))
+            .times(1)
+            .returning(|_| {
+                Ok(top_response(&[
+                    "/usr/local/bin/runner server",
+                    "mongod --replSet local --port 27017",
+                    "/usr/local/bin/mongot --config /etc/mongot/config.yml",
+                ]))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_search_enabled("test-deployment").await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_search_enabled_no_mongot_process() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    vec![],
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+
+        mock_docker.expect_top_processes().times(1).returning(|_| {
+            Ok(top_response(&[
+                "/usr/local/bin/runner server",
+                "mongod --replSet local --port 27017",
+            ]))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_search_enabled("test-deployment").await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_search_enabled_mongot_log_file() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // The log file is enough, the processes are not listed
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    vec!["MONGOT_LOG_FILE=/tmp/mongot.log".to_string()],
+                    ContainerStateStatusEnum::EXITED,
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_search_enabled("test-deployment").await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_search_enabled_stopped_deployment() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    vec![],
+                    ContainerStateStatusEnum::EXITED,
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_search_enabled("test-deployment").await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_search_enabled_top_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_test_container_inspect_response(
+                    vec![],
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+
+        mock_docker
+            .expect_top_processes()
+            .times(1)
+            .returning(|_| Err(DockerError::Conflict));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.is_search_enabled("test-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            IsSearchEnabledError::TopProcesses(DockerError::Conflict)
+        ));
+    }
+
+    #[test]
+    fn test_is_mongot_command() {
+        assert!(is_mongot_command("mongot"));
+        assert!(is_mongot_command("/usr/local/bin/mongot"));
+        assert!(!is_mongot_command("mongod"));
+        assert!(!is_mongot_command("/tmp/mongot.log"));
+    }
+}
@@ -1,10 +1,16 @@
-use bollard::query_parameters::ListContainersOptionsBuilder;
+use std::collections::BTreeMap;
+
+use bollard::query_parameters::{ListContainersOptions, ListContainersOptionsBuilder};
 use maplit::hashmap;
+use semver::Version;
 
 use crate::{
     client::Client,
     docker::{DockerInspectContainer, DockerListContainers},
-    models::{Deployment, LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE},
+    models::{
+        Deployment, IntoDeploymentError, LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE,
+        ListDeploymentsFilter,
+    },
 };
 
 use super::GetDeploymentError;
@@ -12,13 +18,118 @@ use super::GetDeploymentError;
 impl<D: DockerListContainers + DockerInspectContainer> Client<D> {
     /// Lists all local Atlas deployments.
     pub async fn list_deployments(&self) -> Result<Vec<Deployment>, GetDeploymentError> {
-        // Build the list containers options which will filter for containers with the local deployment label
-        let list_container_options = ListContainersOptionsBuilder::default()
-            .all(true)
-            .filters(&hashmap! {
-                "label" => vec![format!("{}={}", LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE)],
-            })
-            .build();
+        self.list_deployments_with_labels(vec![], &ListDeploymentsFilter::default())
+            .await
+    }
+
+    /// Lists the local Atlas deployments matching a filter.
+    ///
+    /// The state is filtered by Docker and the name prefix on the container list,
+    /// so only the containers that can match are inspected.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The filters the deployments must match.
+    pub async fn list_deployments_filtered(
+        &self,
+        filter: ListDeploymentsFilter,
+    ) -> Result<Vec<Deployment>, GetDeploymentError> {
+        self.list_deployments_with_labels(vec![], &filter).await
+    }
+
+    /// Lists the local Atlas deployments that also carry the label `key=value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The label key to filter on.
+    /// * `value` - The value the label must have.
+    pub async fn list_deployments_by_label(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<Vec<Deployment>, GetDeploymentError> {
+        self.list_deployments_with_labels(
+            vec![format!("{key}={value}")],
+            &ListDeploymentsFilter::default(),
+        )
+        .await
+    }
+
+    /// Lists all local Atlas deployments grouped by their MongoDB version.
+    ///
+    /// Versions are ordered from oldest to newest, deployments keep the order of [`Client::list_deployments`].
+    pub async fn deployments_by_version(
+        &self,
+    ) -> Result<BTreeMap<Version, Vec<Deployment>>, GetDeploymentError> {
+        let deployments = self.list_deployments().await?;
+
+        let mut deployments_by_version: BTreeMap<Version, Vec<Deployment>> = BTreeMap::new();
+        for deployment in deployments {
+            deployments_by_version
+                .entry(deployment.mongodb_version.clone())
+                .or_default()
+                .push(deployment);
+        }
+
+        Ok(deployments_by_version)
+    }
+
+    /// Lists the local Atlas deployments that need attention.
+    ///
+    /// A deployment is included when its health check reports it as unhealthy, or when its
+    /// container has exited or is dead, see [`Deployment::is_unhealthy`].
+    pub async fn list_unhealthy_deployments(&self) -> Result<Vec<Deployment>, GetDeploymentError> {
+        let deployments = self.list_deployments().await?;
+
+        Ok(deployments
+            .into_iter()
+            .filter(Deployment::is_unhealthy)
+            .collect())
+    }
+
+    /// Lists all local Atlas deployments, reporting containers that cannot be read as a deployment separately.
+    ///
+    /// Unlike [`Client::list_deployments`], a container with e.g. a malformed version label does not fail the
+    /// whole list. Its ID is returned with the error instead.
+    pub async fn list_deployments_lenient(
+        &self,
+    ) -> Result<(Vec<Deployment>, Vec<(String, IntoDeploymentError)>), GetDeploymentError> {
+        let list_container_options =
+            list_containers_options(vec![], &ListDeploymentsFilter::default());
+        let container_summaries = self
+            .docker
+            .list_containers(Some(list_container_options))
+            .await?;
+
+        let mut deployments = Vec::with_capacity(container_summaries.len());
+        let mut errors = Vec::new();
+        for container_id in container_summaries
+            .into_iter()
+            .filter_map(|container_summary| container_summary.id)
+        {
+            // Skip containers that were removed since they were listed
+            let container_inspect_response =
+                match self.inspect_deployment_container(&container_id).await {
+                    Ok(container_inspect_response) => container_inspect_response,
+                    Err(GetDeploymentError::NotFound { .. }) => continue,
+                    Err(err) => return Err(err),
+                };
+
+            match Deployment::try_from(container_inspect_response) {
+                Ok(deployment) => deployments.push(deployment),
+                Err(err) => errors.push((container_id, err)),
+            }
+        }
+
+        Ok((deployments, errors))
+    }
+
+    async fn list_deployments_with_labels(
+        &self,
+        extra_labels: Vec<String>,
+        filter: &ListDeploymentsFilter,
+    ) -> Result<Vec<Deployment>, GetDeploymentError> {
+        let list_container_options = list_containers_options(extra_labels, filter);
 
         // Get all the containers using the list containers options
         let container_summaries = self
@@ -34,9 +145,22 @@ impl<D: DockerListContainers + DockerInspectContainer> Client<D> {
             // Get the container ID from the container summary
             // This should always be present, but it's cleaner to not use unwrap and skip if it's not present
             if let Some(container_id) = container_summary.id {
-                // Get the deployment details for the container
-                let deployment = self.get_deployment(container_id.as_ref()).await?;
-                deployments.push(deployment);
+                // Skip containers whose name does not match before inspecting them
+                if !filter.matches_names(container_summary.names.as_deref().unwrap_or_default()) {
+                    continue;
+                }
+
+                // Get the deployment details for the container, skip it when it was removed since it was listed
+                let deployment = match self.get_deployment(container_id.as_ref()).await {
+                    Ok(deployment) => deployment,
+                    Err(GetDeploymentError::NotFound { .. }) => continue,
+                    Err(err) => return Err(err),
+                };
+
+                // The state can have changed since the containers were listed
+                if filter.matches(&deployment) {
+                    deployments.push(deployment);
+                }
             }
         }
 
@@ -44,6 +168,32 @@ impl<D: DockerListContainers + DockerInspectContainer> Client<D> {
     }
 }
 
+/// Builds the options listing the containers of local Atlas deployments that carry `extra_labels` and can match `filter`.
+fn list_containers_options(
+    extra_labels: Vec<String>,
+    filter: &ListDeploymentsFilter,
+) -> ListContainersOptions {
+    // Always filter for containers with the local deployment label, Docker combines multiple labels with AND
+    let mut labels = vec![format!(
+        "{}={}",
+        LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE
+    )];
+    labels.extend(extra_labels);
+
+    // Build the list containers options which will filter for containers with the labels (and statuses)
+    let mut filters = hashmap! {
+        "label" => labels,
+    };
+    let statuses = filter.statuses();
+    if !statuses.is_empty() {
+        filters.insert("status", statuses);
+    }
+    ListContainersOptionsBuilder::default()
+        .all(true)
+        .filters(&filters)
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,7 +204,7 @@ mod tests {
     use bollard::{
         models::{
             ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
-            ContainerSummary,
+            ContainerSummary, Health, HealthStatusEnum,
         },
         query_parameters::{InspectContainerOptions, ListContainersOptions},
     };
@@ -172,6 +322,195 @@ mod tests {
         assert_eq!(deployments[1].mongodb_type, MongodbType::Community);
     }
 
+    #[tokio::test]
+    async fn test_list_deployments_lenient() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let container_summaries = vec![
+            create_container_summary("container1", "deployment1"),
+            create_container_summary("container2", "deployment2"),
+        ];
+        let container_inspect_response1 =
+            create_container_inspect_response("container1", "deployment1");
+        let mut container_inspect_response2 =
+            create_container_inspect_response("container2", "deployment2");
+        if let Some(labels) = container_inspect_response2
+            .config
+            .as_mut()
+            .and_then(|config| config.labels.as_mut())
+        {
+            labels.insert("version".to_string(), "not-a-version".to_string());
+        }
+
+        mock_docker
+            .expect_list_containers()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .and_then(|filters| filters.get("label"))
+                    == Some(&vec!["mongodb-atlas-local=container".to_string()])
+            })
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("container1"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(move |_, _| Ok(container_inspect_response1.clone()));
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("container2"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(move |_, _| Ok(container_inspect_response2.clone()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let (deployments, errors) = client.list_deployments_lenient().await.unwrap();
+
+        // Assert
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].container_id, "container1");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "container2");
+        assert!(matches!(
+            errors[0].1,
+            IntoDeploymentError::LocalDeploymentLabels(_)
+        ));
+    }
+
+    fn create_container_inspect_response_with_state(
+        id: &str,
+        status: ContainerStateStatusEnum,
+        health: Option<HealthStatusEnum>,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                status: Some(status),
+                health: health.map(|status| Health {
+                    status: Some(status),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..create_container_inspect_response(id, id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_unhealthy_deployments() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let container_inspect_responses = [
+            create_container_inspect_response_with_state(
+                "healthy",
+                ContainerStateStatusEnum::RUNNING,
+                Some(HealthStatusEnum::HEALTHY),
+            ),
+            create_container_inspect_response_with_state(
+                "unhealthy",
+                ContainerStateStatusEnum::RUNNING,
+                Some(HealthStatusEnum::UNHEALTHY),
+            ),
+            create_container_inspect_response_with_state(
+                "starting",
+                ContainerStateStatusEnum::RUNNING,
+                Some(HealthStatusEnum::STARTING),
+            ),
+            create_container_inspect_response_with_state(
+                "exited",
+                ContainerStateStatusEnum::EXITED,
+                None,
+            ),
+            create_container_inspect_response_with_state(
+                "dead",
+                ContainerStateStatusEnum::DEAD,
+                None,
+            ),
+            create_container_inspect_response_with_state(
+                "paused",
+                ContainerStateStatusEnum::PAUSED,
+                Some(HealthStatusEnum::HEALTHY),
+            ),
+        ];
+        let container_summaries = container_inspect_responses
+            .iter()
+            .map(|response| {
+                let id = response.id.as_deref().unwrap();
+                create_container_summary(id, id)
+            })
+            .collect::<Vec<_>>();
+
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(6)
+            .returning(move |container_id, _| {
+                Ok(container_inspect_responses
+                    .iter()
+                    .find(|response| response.id.as_deref() == Some(container_id))
+                    .unwrap()
+                    .clone())
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.list_unhealthy_deployments().await;
+
+        // Assert
+        let container_ids = result
+            .unwrap()
+            .into_iter()
+            .map(|deployment| deployment.container_id)
+            .collect::<Vec<_>>();
+        assert_eq!(container_ids, vec!["unhealthy", "exited", "dead"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_unhealthy_deployments_all_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let container_summaries = vec![create_container_summary("container1", "deployment1")];
+        let container_inspect_response = create_container_inspect_response_with_state(
+            "container1",
+            ContainerStateStatusEnum::RUNNING,
+            Some(HealthStatusEnum::HEALTHY),
+        );
+
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| Ok(container_inspect_response.clone()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.list_unhealthy_deployments().await;
+
+        // Assert
+        assert!(result.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_deployments_empty() {
         // Arrange
@@ -238,7 +577,7 @@ mod tests {
                 mockall::predicate::eq(None::<InspectContainerOptions>),
             )
             .times(1)
-            .returning(|_, _| Err(DockerError::NotFound));
+            .returning(|_, _| Err(DockerError::ServerError));
 
         let client = Client::new(mock_docker);
 
@@ -246,13 +585,60 @@ mod tests {
         let result = client.list_deployments().await;
 
         // Assert
-        assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            GetDeploymentError::ContainerInspect(_)
+            GetDeploymentError::ContainerInspect(DockerError::ServerError)
         ));
     }
 
+    #[tokio::test]
+    async fn test_list_deployments_skips_removed_containers() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let container_summaries = vec![
+            create_container_summary("container1", "deployment1"),
+            create_container_summary("container2", "deployment2"),
+        ];
+
+        let container_inspect_response2 =
+            create_container_inspect_response("container2", "deployment2");
+
+        // Set up expectations
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+
+        // The first container is removed between listing and inspecting it
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("container1"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("container2"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(move |_, _| Ok(container_inspect_response2.clone()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let deployments = client.list_deployments().await.unwrap();
+
+        // Assert
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].container_id, "container2");
+    }
+
     #[tokio::test]
     async fn test_list_deployments_skip_containers_without_id() {
         // Arrange
@@ -296,4 +682,326 @@ mod tests {
         assert_eq!(deployments[0].container_id, "container2");
         assert_eq!(deployments[0].name, Some("deployment2".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_list_deployments_by_label() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let container_summaries = [
+            ContainerSummary {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "project".to_string() => "foo".to_string(),
+                }),
+                ..create_container_summary("container1", "deployment1")
+            },
+            ContainerSummary {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "project".to_string() => "bar".to_string(),
+                }),
+                ..create_container_summary("container2", "deployment2")
+            },
+            ContainerSummary {
+                labels: Some(hashmap! {
+                    "project".to_string() => "foo".to_string(),
+                }),
+                ..create_container_summary("container3", "not-a-deployment")
+            },
+        ];
+
+        // Apply the label filter the way the Docker daemon does, every label must match
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |options| {
+                let label_filters = options
+                    .and_then(|o| o.filters)
+                    .and_then(|f| f.get("label").cloned())
+                    .unwrap_or_default();
+                assert_eq!(
+                    label_filters,
+                    vec![
+                        "mongodb-atlas-local=container".to_string(),
+                        "project=foo".to_string()
+                    ]
+                );
+
+                Ok(container_summaries
+                    .iter()
+                    .filter(|summary| {
+                        label_filters.iter().all(|filter| {
+                            let (key, value) = filter.split_once('=').unwrap();
+                            summary
+                                .labels
+                                .as_ref()
+                                .and_then(|labels| labels.get(key))
+                                .is_some_and(|v| v == value)
+                        })
+                    })
+                    .cloned()
+                    .collect())
+            });
+
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("container1"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(create_container_inspect_response(
+                    "container1",
+                    "deployment1",
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.list_deployments_by_label("project", "foo").await;
+
+        // Assert
+        let deployments = result.unwrap();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].container_id, "container1");
+        assert_eq!(deployments[0].name, Some("deployment1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_deployments_by_version() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        let deployments = [
+            ("container1", "deployment1", "8.0.0"),
+            ("container2", "deployment2", "7.0.12"),
+            ("container3", "deployment3", "8.0.0"),
+        ];
+
+        let container_summaries = deployments
+            .iter()
+            .map(|(id, name, _)| create_container_summary(id, name))
+            .collect::<Vec<_>>();
+
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(move |_| Ok(container_summaries.clone()));
+
+        for (id, name, version) in deployments {
+            let mut container_inspect_response = create_container_inspect_response(id, name);
+            if let Some(labels) = container_inspect_response
+                .config
+                .as_mut()
+                .and_then(|c| c.labels.as_mut())
+            {
+                labels.insert("version".to_string(), version.to_string());
+            }
+
+            mock_docker
+                .expect_inspect_container()
+                .with(
+                    mockall::predicate::eq(id),
+                    mockall::predicate::eq(None::<InspectContainerOptions>),
+                )
+                .times(1)
+                .returning(move |_, _| Ok(container_inspect_response.clone()));
+        }
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.deployments_by_version().await;
+
+        // Assert
+        let deployments_by_version = result.unwrap();
+        assert_eq!(
+            deployments_by_version.keys().collect::<Vec<_>>(),
+            vec![&Version::new(7, 0, 12), &Version::new(8, 0, 0)]
+        );
+
+        let names = |version: Version| {
+            deployments_by_version[&version]
+                .iter()
+                .map(|d| d.name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Version::new(7, 0, 12)), vec!["deployment2"]);
+        assert_eq!(
+            names(Version::new(8, 0, 0)),
+            vec!["deployment1", "deployment3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deployments_by_version_empty() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.deployments_by_version().await;
+
+        // Assert
+        assert!(result.unwrap().is_empty());
+    }
+
+    fn expect_inspect_with_state(
+        mock_docker: &mut MockDocker,
+        id: &'static str,
+        status: ContainerStateStatusEnum,
+    ) {
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq(id),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(move |_, _| {
+                Ok(create_container_inspect_response_with_state(
+                    id, status, None,
+                ))
+            });
+    }
+
+    #[tokio::test]
+    async fn test_list_deployments_filtered_by_state_and_name_prefix() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // The mock returns every container, as if Docker ignored the status filter
+        mock_docker
+            .expect_list_containers()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .and_then(|filters| filters.get("status"))
+                    == Some(&vec!["paused".to_string()])
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    create_container_summary("app-1", "app-1"),
+                    create_container_summary("app-2", "app-2"),
+                    create_container_summary("other-1", "other-1"),
+                ])
+            });
+
+        expect_inspect_with_state(&mut mock_docker, "app-1", ContainerStateStatusEnum::RUNNING);
+        expect_inspect_with_state(&mut mock_docker, "app-2", ContainerStateStatusEnum::PAUSED);
+        // "other-1" does not match the name prefix, so it is never inspected
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .list_deployments_filtered(ListDeploymentsFilter {
+                state: Some(State::Paused),
+                name_prefix: Some("app-".to_string()),
+                ..Default::default()
+            })
+            .await;
+
+        // Assert
+        let deployments = result.unwrap();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].container_id, "app-2");
+        assert_eq!(deployments[0].state, State::Paused);
+    }
+
+    #[tokio::test]
+    async fn test_list_deployments_filtered_running_only() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_list_containers()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .and_then(|filters| filters.get("status"))
+                    == Some(&vec!["running".to_string()])
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    create_container_summary("running-1", "running-1"),
+                    create_container_summary("exited-1", "exited-1"),
+                    create_container_summary("running-2", "running-2"),
+                ])
+            });
+
+        expect_inspect_with_state(
+            &mut mock_docker,
+            "running-1",
+            ContainerStateStatusEnum::RUNNING,
+        );
+        expect_inspect_with_state(
+            &mut mock_docker,
+            "exited-1",
+            ContainerStateStatusEnum::EXITED,
+        );
+        expect_inspect_with_state(
+            &mut mock_docker,
+            "running-2",
+            ContainerStateStatusEnum::RUNNING,
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .list_deployments_filtered(ListDeploymentsFilter {
+                running_only: true,
+                ..Default::default()
+            })
+            .await;
+
+        // Assert
+        let container_ids = result
+            .unwrap()
+            .into_iter()
+            .map(|deployment| deployment.container_id)
+            .collect::<Vec<_>>();
+        assert_eq!(container_ids, vec!["running-1", "running-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_deployments_without_filter_has_no_status_filter() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_list_containers()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .is_some_and(|filters| !filters.contains_key("status"))
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .list_deployments_filtered(ListDeploymentsFilter::default())
+            .await;
+
+        // Assert
+        assert!(result.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,281 @@
+use crate::{
+    Client,
+    client::{MongoshError, get_deployment::GetDeploymentError},
+    docker::{DockerInspectContainer, RunCommandInContainer},
+    models::{LogComponent, MAX_LOG_VERBOSITY, MIN_LOG_VERBOSITY},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LogVerbosityError {
+    #[error(
+        "Invalid log verbosity level {level}, expected a level between {MIN_LOG_VERBOSITY} and {MAX_LOG_VERBOSITY}"
+    )]
+    InvalidLevel { level: i32 },
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error(transparent)]
+    Mongosh(#[from] MongoshError),
+    #[error("Failed to parse log verbosity: {output}")]
+    ParseLogVerbosity { output: String },
+    #[error("Log verbosity of {component} is {actual} after setting it to {expected}")]
+    VerbosityNotApplied {
+        component: LogComponent,
+        expected: i32,
+        actual: i32,
+    },
+}
+
+impl<D: DockerInspectContainer + RunCommandInContainer> Client<D> {
+    /// Sets the log verbosity of a mongod component of a running deployment, e.g. to debug queries.
+    ///
+    /// Runs `db.setLogLevel()` with mongosh inside the deployment container. The change is not persisted,
+    /// mongod logs with its configured verbosity again after a restart.
+    ///
+    /// # Arguments
+    ///
+    /// * `cluster_id_or_name` - The ID or name of the deployment.
+    /// * `component` - The component to change the verbosity of, [`LogComponent::Default`] for all components.
+    /// * `level` - The verbosity from 0 (informational) to 5 (most verbose), -1 to inherit the verbosity of the parent component.
+    pub async fn set_log_verbosity(
+        &self,
+        cluster_id_or_name: &str,
+        component: LogComponent,
+        level: i32,
+    ) -> Result<(), LogVerbosityError> {
+        if !(MIN_LOG_VERBOSITY..=MAX_LOG_VERBOSITY).contains(&level) {
+            return Err(LogVerbosityError::InvalidLevel { level });
+        }
+
+        let set_log_level = match component {
+            LogComponent::Default => format!("db.setLogLevel({level})"),
+            component => format!("db.setLogLevel({level}, '{component}')"),
+        };
+        let script = format!(
+            "{set_log_level}; print({})",
+            component.verbosity_expression()
+        );
+
+        let actual = self
+            .run_log_verbosity_script(cluster_id_or_name, &script)
+            .await?;
+        if actual != level {
+            return Err(LogVerbosityError::VerbosityNotApplied {
+                component,
+                expected: level,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Gets the log verbosity of a mongod component of a running deployment, -1 when it inherits the verbosity of its parent.
+    pub async fn get_log_verbosity(
+        &self,
+        cluster_id_or_name: &str,
+        component: LogComponent,
+    ) -> Result<i32, LogVerbosityError> {
+        let script = format!("print({})", component.verbosity_expression());
+
+        self.run_log_verbosity_script(cluster_id_or_name, &script)
+            .await
+    }
+
+    /// Runs a script printing a verbosity level inside the deployment container and parses it.
+    async fn run_log_verbosity_script(
+        &self,
+        cluster_id_or_name: &str,
+        script: &str,
+    ) -> Result<i32, LogVerbosityError> {
+        let deployment = self.get_deployment(cluster_id_or_name).await?;
+
+        let output = self
+            .run_mongosh(&deployment, script)
+            .await?
+            .stdout
+            .join("\n");
+        output
+            .trim()
+            .parse::<i32>()
+            .map_err(|_| LogVerbosityError::ParseLogVerbosity { output })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        docker::{CommandOutput, DockerError, RunCommandInContainerError},
+        test_utils::create_container_inspect_response_with_auth,
+    };
+    use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    fn create_mock_docker(script: &str, stdout: &'static str) -> MockDocker {
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("test_container_id"),
+                eq(vec![
+                    "mongosh".to_string(),
+                    "mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                    "--username=testuser".to_string(),
+                    "--password=testpass".to_string(),
+                    "--eval".to_string(),
+                    script.to_string(),
+                    "--quiet".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(move |_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![stdout.to_string()],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+        mock_docker
+    }
+
+    #[tokio::test]
+    async fn test_set_log_verbosity() {
+        // Arrange
+        let mock_docker = create_mock_docker(
+            "db.setLogLevel(2, 'query'); print(db.getLogComponents().query.verbosity)",
+            "2",
+        );
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .set_log_verbosity("test-deployment", LogComponent::Query, 2)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_log_verbosity_default_component() {
+        // Arrange
+        let mock_docker = create_mock_docker(
+            "db.setLogLevel(1); print(db.getLogComponents().verbosity)",
+            "1",
+        );
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .set_log_verbosity("test-deployment", LogComponent::Default, 1)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_log_verbosity_not_applied() {
+        // Arrange
+        let mock_docker = create_mock_docker(
+            "db.setLogLevel(3, 'replication.heartbeats'); print(db.getLogComponents().replication.heartbeats.verbosity)",
+            "0",
+        );
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .set_log_verbosity("test-deployment", LogComponent::ReplicationHeartbeats, 3)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            LogVerbosityError::VerbosityNotApplied {
+                component: LogComponent::ReplicationHeartbeats,
+                expected: 3,
+                actual: 0,
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_set_log_verbosity_invalid_level() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_inspect_container().never();
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .set_log_verbosity("test-deployment", LogComponent::Query, 6)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            LogVerbosityError::InvalidLevel { level: 6 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_log_verbosity() {
+        // Arrange
+        let mock_docker =
+            create_mock_docker("print(db.getLogComponents().network.verbosity)", "-1");
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .get_log_verbosity("test-deployment", LogComponent::Network)
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), -1);
+    }
+
+    #[tokio::test]
+    async fn test_get_log_verbosity_parse_error() {
+        // Arrange
+        let mock_docker = create_mock_docker(
+            "print(db.getLogComponents().network.verbosity)",
+            "MongoServerError: command getParameter requires authentication",
+        );
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .get_log_verbosity("test-deployment", LogComponent::Network)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            LogVerbosityError::ParseLogVerbosity { .. }
+        ));
+    }
+}
@@ -1,36 +1,89 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use bollard::Docker;
 
+#[cfg(feature = "bollard")]
+use crate::docker::DockerError;
+use crate::models::{RetryPolicy, WatchOptions};
+use shutdown::TaskTracker;
+use tokio::time::Instant;
+
+mod capabilities;
+mod check_ready;
+mod clone_deployment;
+mod compare_deployments;
 mod create_deployment;
 mod delete_deployment;
+mod exec;
+mod get_connection_count;
 mod get_connection_string;
 mod get_deployment;
 mod get_deployment_id;
+mod get_disk_usage;
 mod get_logs;
 mod get_mongodb_secret;
+mod get_server_status;
+mod get_socket_path;
+mod get_stats;
+mod is_search_enabled;
 mod list_deployments;
+mod log_verbosity;
+mod mongosh_command;
+mod negotiate_api_version;
 mod pause_deployment;
+mod provision_and_connect;
+mod prune_deployment_volumes;
 mod pull_image;
+mod reconcile_deployment;
+mod rename_deployment;
+mod retry;
+mod run_aggregation;
+mod shutdown;
 mod start_deployment;
 mod stop_deployment;
+mod stream_slow_queries;
+mod subscribe_events;
+mod tail_logs_resilient;
 mod unpause_deployment;
+mod verify_image_digest;
+mod wait_for_state;
 mod watch_deployment;
 
+pub use check_ready::CheckReadyError;
+pub use clone_deployment::CloneDeploymentError;
 pub use create_deployment::{
-    CreateDeploymentError, CreateDeploymentProgress, CreateDeploymentStepOutcome,
+    CreateDeploymentError, CreateDeploymentProgress, CreateDeploymentStep,
+    CreateDeploymentStepOutcome,
 };
 pub use delete_deployment::DeleteDeploymentError;
-pub use get_connection_string::GetConnectionStringError;
+pub use exec::ExecError;
+pub use get_connection_count::GetConnectionCountError;
+pub use get_connection_string::{DEFAULT_VERIFY_TIMEOUT, GetConnectionStringError};
 pub use get_deployment::GetDeploymentError;
 pub use get_deployment_id::GetDeploymentIdError;
+pub use get_disk_usage::GetDiskUsageError;
 pub use get_logs::GetLogsError;
+pub use get_server_status::GetServerStatusError;
+pub use get_stats::GetStatsError;
+pub use is_search_enabled::IsSearchEnabledError;
+pub use log_verbosity::LogVerbosityError;
+pub use mongosh_command::MongoshError;
+pub use negotiate_api_version::NegotiateApiVersionError;
 pub use pause_deployment::PauseDeploymentError;
-pub use pull_image::PullImageError;
+pub use provision_and_connect::ProvisionAndConnectError;
+pub use prune_deployment_volumes::PruneVolumesError;
+pub use pull_image::{PullImageError, PullOutcome, PullProgress};
+pub use reconcile_deployment::{ReconcileAction, ReconcileDeploymentError, ReconcileOutcome};
+pub use rename_deployment::RenameDeploymentError;
+pub use run_aggregation::{Document, RunAggregationError};
 pub use start_deployment::StartDeploymentError;
 pub use stop_deployment::StopDeploymentError;
+pub use stream_slow_queries::StreamSlowQueriesError;
+pub use subscribe_events::SubscribeEventsError;
 pub use unpause_deployment::UnpauseDeploymentError;
-pub use watch_deployment::WatchDeploymentError;
+pub use verify_image_digest::VerifyImageDigestError;
+pub use wait_for_state::WatchStateError;
+pub use watch_deployment::{DEFAULT_POLL_INTERVAL, DEFAULT_WATCH_TIMEOUT, WatchDeploymentError};
 
 /// The main entry point for interacting with local Atlas deployments.
 ///
@@ -38,12 +91,21 @@ pub use watch_deployment::WatchDeploymentError;
 /// through Docker. It serves as the primary abstraction layer between your application
 /// and the underlying Docker containers running Atlas services.
 ///
+/// # Background tasks
+///
+/// [`Client::create_deployment`] creates the deployment in a background task. Those tasks are
+/// aborted when the last clone of the client is dropped, use [`Client::shutdown`] to also remove
+/// the containers of the deployments that were still being created.
+///
 /// # Examples
 ///
 /// See the [module-level documentation](crate) for a complete example of creating
 /// a new client instance.
 pub struct Client<D = Docker> {
     docker: Arc<D>,
+    tasks: Arc<TaskTracker>,
+    default_watch_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl<D> Client<D> {
@@ -63,31 +125,267 @@ impl<D> Client<D> {
     pub fn new(docker: D) -> Client<D> {
         Client {
             docker: Arc::new(docker),
+            tasks: Arc::default(),
+            default_watch_timeout: None,
+            retry_policy: None,
         }
     }
+
+    /// Sets the timeout used when waiting for a deployment to become healthy and no timeout is given per call.
+    ///
+    /// The timeout is resolved in this order:
+    /// 1. The per-call deadline ([`WatchOptions::deadline`](crate::models::WatchOptions::deadline))
+    ///    or timeout ([`WatchOptions::timeout_duration`](crate::models::WatchOptions::timeout_duration)
+    ///    or [`CreateDeploymentOptions::wait_until_healthy_timeout`](crate::models::CreateDeploymentOptions::wait_until_healthy_timeout))
+    /// 2. The client default set with this method
+    /// 3. [`DEFAULT_WATCH_TIMEOUT`]
+    pub fn with_default_watch_timeout(mut self, timeout: Duration) -> Self {
+        self.default_watch_timeout = Some(timeout);
+        self
+    }
+
+    /// Retries Docker requests that fail with a transient server error, e.g. when the daemon is under load.
+    ///
+    /// Creating a deployment retries inspecting, creating and starting its container, see [`RetryPolicy`]
+    /// for which errors are retried. Without a retry policy every request is sent once.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Returns the timeout to wait for, see [`Client::with_default_watch_timeout`] for the precedence.
+    pub(crate) fn watch_timeout(&self, per_call: Option<Duration>) -> Duration {
+        per_call
+            .or(self.default_watch_timeout)
+            .unwrap_or(DEFAULT_WATCH_TIMEOUT)
+    }
+
+    /// Returns the instant to stop waiting at, the deadline of the options wins over any timeout.
+    pub(crate) fn watch_deadline(&self, options: &WatchOptions) -> Instant {
+        options
+            .deadline
+            .unwrap_or_else(|| Instant::now() + self.watch_timeout(options.timeout_duration))
+    }
 }
 
+/// Timeout of requests to Docker when none is set, the default of bollard.
+#[cfg(feature = "bollard")]
+const DEFAULT_DOCKER_TIMEOUT: Duration = Duration::from_secs(120);
+
 #[cfg(feature = "bollard")]
 impl Client<bollard::Docker> {
     /// Creates a new client by connecting to Docker using the default connection method.
     ///
     /// Equivalent to calling `Client::new(Docker::connect_with_defaults()?)`.
-    pub fn connect_with_defaults() -> Result<Self, bollard::errors::Error> {
+    /// Returns [`DockerError::DaemonUnavailable`] when Docker is not running.
+    /// Requests use the newest API version this crate supports, call [`Client::negotiate_api_version`]
+    /// (or use [`ClientBuilder::build_negotiated`]) to work with older Docker daemons.
+    pub fn connect_with_defaults() -> Result<Self, DockerError> {
         Ok(Client::new(bollard::Docker::connect_with_defaults()?))
     }
 
     /// Creates a new client by connecting to Docker via the default Unix socket.
     ///
     /// Equivalent to calling `Client::new(Docker::connect_with_socket_defaults()?)`.
-    pub fn connect_with_socket_defaults() -> Result<Self, bollard::errors::Error> {
+    /// Returns [`DockerError::DaemonUnavailable`] when the Docker socket does not exist.
+    /// See [`Client::connect_with_defaults`] about the API version.
+    pub fn connect_with_socket_defaults() -> Result<Self, DockerError> {
         Ok(Client::new(bollard::Docker::connect_with_socket_defaults()?))
     }
 }
 
+#[cfg(feature = "bollard")]
+#[derive(Debug, thiserror::Error)]
+pub enum BuildClientError {
+    #[error("Failed to connect to Docker at {endpoint}: {source}")]
+    Connect {
+        endpoint: String,
+        #[source]
+        source: DockerError,
+    },
+    #[error(transparent)]
+    NegotiateApiVersion(#[from] NegotiateApiVersionError),
+}
+
+/// Where [`ClientBuilder`] connects to Docker.
+#[cfg(feature = "bollard")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DockerEndpoint {
+    Socket(String),
+    Http(String),
+}
+
+/// Builds a [`Client`] connected to Docker with custom connection settings.
+///
+/// Without an endpoint the client connects like [`Client::connect_with_defaults`] does,
+/// the last endpoint set wins when several are set.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use atlas_local::client::ClientBuilder;
+///
+/// let client = ClientBuilder::new()
+///     .with_socket_path("/var/run/docker.sock")
+///     .with_timeout(Duration::from_secs(30))
+///     .build()?;
+/// # Ok::<(), atlas_local::client::BuildClientError>(())
+/// ```
+#[cfg(feature = "bollard")]
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    endpoint: Option<DockerEndpoint>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature = "bollard")]
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connects through the Unix socket (or named pipe on Windows) at `path`, the socket must exist.
+    pub fn with_socket_path(mut self, path: &str) -> Self {
+        self.endpoint = Some(DockerEndpoint::Socket(path.to_string()));
+        self
+    }
+
+    /// Connects over HTTP to `address`, e.g. `tcp://127.0.0.1:2375`.
+    pub fn with_http(mut self, address: &str) -> Self {
+        self.endpoint = Some(DockerEndpoint::Http(address.to_string()));
+        self
+    }
+
+    /// Sets the timeout of requests to Docker, bollard defaults to 2 minutes.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Connects to Docker and creates the client.
+    pub fn build(self) -> Result<Client<bollard::Docker>, BuildClientError> {
+        let (endpoint, docker) = match &self.endpoint {
+            Some(DockerEndpoint::Socket(path)) => (
+                path.clone(),
+                bollard::Docker::connect_with_socket(
+                    path,
+                    DEFAULT_DOCKER_TIMEOUT.as_secs(),
+                    bollard::API_DEFAULT_VERSION,
+                ),
+            ),
+            Some(DockerEndpoint::Http(address)) => (
+                address.clone(),
+                bollard::Docker::connect_with_http(
+                    address,
+                    DEFAULT_DOCKER_TIMEOUT.as_secs(),
+                    bollard::API_DEFAULT_VERSION,
+                ),
+            ),
+            None => (
+                "default endpoint".to_string(),
+                bollard::Docker::connect_with_defaults(),
+            ),
+        };
+
+        let docker = docker.map_err(|err| BuildClientError::Connect {
+            endpoint,
+            source: DockerError::from(err),
+        })?;
+
+        Ok(Client::new(match self.timeout {
+            Some(timeout) => docker.with_timeout(timeout),
+            None => docker,
+        }))
+    }
+
+    /// Connects to Docker, creates the client and negotiates the API version with the daemon,
+    /// see [`Client::negotiate_api_version`].
+    pub async fn build_negotiated(self) -> Result<Client<bollard::Docker>, BuildClientError> {
+        let client = self.build()?;
+        client.negotiate_api_version().await?;
+        Ok(client)
+    }
+}
+
 impl<D> Clone for Client<D> {
     fn clone(&self) -> Self {
         Client {
             docker: self.docker.clone(),
+            tasks: self.tasks.clone(),
+            default_watch_timeout: self.default_watch_timeout,
+            retry_policy: self.retry_policy,
         }
     }
 }
+
+#[cfg(all(test, feature = "bollard"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_builder_http() {
+        let client = ClientBuilder::new()
+            .with_http("tcp://127.0.0.1:2375")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.docker.timeout(), DEFAULT_DOCKER_TIMEOUT);
+    }
+
+    #[test]
+    fn test_client_builder_http_with_timeout() {
+        let client = ClientBuilder::new()
+            .with_http("http://127.0.0.1:2375")
+            .with_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.docker.timeout(), Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_client_builder_socket() {
+        // Connecting is lazy, the socket only has to exist
+        let socket_path =
+            std::env::temp_dir().join(format!("atlas-local-builder-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+
+        let result = ClientBuilder::new()
+            .with_socket_path(socket_path.to_str().unwrap())
+            .with_timeout(Duration::from_secs(5))
+            .build();
+        drop(listener);
+        let _ = std::fs::remove_file(&socket_path);
+
+        assert_eq!(result.unwrap().docker.timeout(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_client_builder_missing_socket() {
+        let result = ClientBuilder::new()
+            .with_socket_path("/nonexistent/atlas-local/docker.sock")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(BuildClientError::Connect {
+                endpoint,
+                source: DockerError::DaemonUnavailable { .. },
+            }) if endpoint == "/nonexistent/atlas-local/docker.sock"
+        ));
+    }
+
+    #[test]
+    fn test_client_builder_last_endpoint_wins() {
+        let result = ClientBuilder::new()
+            .with_socket_path("/nonexistent/atlas-local/docker.sock")
+            .with_http("tcp://127.0.0.1:2375")
+            .build();
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{
+    client::{Client, get_mongodb_secret::get_mongodb_secret},
+    docker::{CommandOutput, RunCommandInContainer, RunCommandInContainerError},
+    models::Deployment,
+};
+
+/// The connection string mongosh uses to reach the deployment from inside its container.
+pub const LOCAL_MONGODB_URI: &str = "mongodb://127.0.0.1:27017/?directConnection=true";
+
+/// Prints `1` when the server answers a ping.
+pub const PING_SCRIPT: &str = "print(db.runCommand({ ping: 1 }).ok)";
+
+/// Builds the command line to run mongosh inside a deployment container.
+///
+/// The credentials are only passed when set, `eval` is the script to run and `quiet` suppresses the banner.
+pub fn build_mongosh_command(
+    uri: &str,
+    username: Option<&str>,
+    password: Option<&str>,
+    eval: Option<&str>,
+    quiet: bool,
+) -> Vec<String> {
+    let mut mongosh_command = vec!["mongosh".to_string(), uri.to_string()];
+    if let Some(username) = username {
+        mongosh_command.push(format!("--username={}", username));
+    }
+    if let Some(password) = password {
+        mongosh_command.push(format!("--password={}", password));
+    }
+
+    if let Some(eval) = eval {
+        mongosh_command.push("--eval".to_string());
+        mongosh_command.push(eval.to_string());
+    }
+    if quiet {
+        mongosh_command.push("--quiet".to_string());
+    }
+
+    mongosh_command
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MongoshError {
+    #[error("Failed to get MongoDB username: {0}")]
+    GetMongodbUsername(RunCommandInContainerError),
+    #[error("Failed to get MongoDB password: {0}")]
+    GetMongodbPassword(RunCommandInContainerError),
+    #[error("Failed to run mongosh command: {0}")]
+    RunMongoshCommand(RunCommandInContainerError),
+    #[error("mongosh exited with code {exit_code}: {}", .stderr.join("\n"))]
+    NonZeroExit { exit_code: i64, stderr: Vec<String> },
+}
+
+/// The root username and password of a deployment, `None` when they are not configured.
+pub struct RootCredentials {
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl<D: RunCommandInContainer> Client<D> {
+    /// Gets the root credentials of a deployment, from its environment or from the secret files it points to.
+    pub(crate) async fn root_credentials(
+        &self,
+        deployment: &Deployment,
+    ) -> Result<RootCredentials, MongoshError> {
+        // Try to get the MongoDB root username
+        let username = get_mongodb_secret(
+            self.docker.as_ref(),
+            deployment,
+            |d| d.mongodb_initdb_root_username.as_deref(),
+            |d| d.mongodb_initdb_root_username_file.as_deref(),
+        )
+        .await
+        .map_err(MongoshError::GetMongodbUsername)?;
+
+        // Try to get the MongoDB root password
+        let password = get_mongodb_secret(
+            self.docker.as_ref(),
+            deployment,
+            |d| d.mongodb_initdb_root_password.as_deref(),
+            |d| d.mongodb_initdb_root_password_file.as_deref(),
+        )
+        .await
+        .map_err(MongoshError::GetMongodbPassword)?;
+
+        Ok(RootCredentials { username, password })
+    }
+
+    /// Runs a script with mongosh inside the deployment container as the root user and returns its output.
+    ///
+    /// A mongosh exiting with a non-zero code fails with its stderr, instead of returning an empty stdout.
+    pub(crate) async fn run_mongosh(
+        &self,
+        deployment: &Deployment,
+        script: &str,
+    ) -> Result<CommandOutput, MongoshError> {
+        let credentials = self.root_credentials(deployment).await?;
+
+        let mongosh_command = build_mongosh_command(
+            LOCAL_MONGODB_URI,
+            credentials.username.as_deref(),
+            credentials.password.as_deref(),
+            Some(script),
+            true,
+        );
+
+        let command_output = self
+            .docker
+            .run_command_in_container(&deployment.container_id, mongosh_command)
+            .await
+            .map_err(MongoshError::RunMongoshCommand)?;
+
+        if let Some(exit_code) = command_output.exit_code.filter(|exit_code| *exit_code != 0) {
+            return Err(MongoshError::NonZeroExit {
+                exit_code,
+                stderr: command_output.stderr,
+            });
+        }
+
+        Ok(command_output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::DockerError, test_utils::create_container_inspect_response_with_auth};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+    }
+
+    const EVAL: &str = "db.getSiblingDB('admin').atlascli.findOne()?.uuid";
+
+    fn create_test_deployment() -> Deployment {
+        Deployment::try_from(create_container_inspect_response_with_auth(27017)).unwrap()
+    }
+
+    #[test]
+    fn test_build_mongosh_command_without_auth() {
+        let command = build_mongosh_command(LOCAL_MONGODB_URI, None, None, Some(EVAL), true);
+
+        assert_eq!(
+            command,
+            vec![
+                "mongosh".to_string(),
+                "mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                "--eval".to_string(),
+                EVAL.to_string(),
+                "--quiet".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_mongosh_command_with_auth() {
+        let command = build_mongosh_command(
+            LOCAL_MONGODB_URI,
+            Some("testuser"),
+            Some("testpass"),
+            Some(EVAL),
+            true,
+        );
+
+        assert_eq!(
+            command,
+            vec![
+                "mongosh".to_string(),
+                "mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                "--username=testuser".to_string(),
+                "--password=testpass".to_string(),
+                "--eval".to_string(),
+                EVAL.to_string(),
+                "--quiet".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_mongosh_command_username_only() {
+        let command =
+            build_mongosh_command(LOCAL_MONGODB_URI, Some("testuser"), None, Some(EVAL), true);
+
+        assert_eq!(
+            command,
+            vec![
+                "mongosh".to_string(),
+                "mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                "--username=testuser".to_string(),
+                "--eval".to_string(),
+                EVAL.to_string(),
+                "--quiet".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_mongosh_command_without_eval() {
+        let command = build_mongosh_command(
+            "mongodb://localhost:27018",
+            Some("testuser"),
+            Some("testpass"),
+            None,
+            false,
+        );
+
+        assert_eq!(
+            command,
+            vec![
+                "mongosh".to_string(),
+                "mongodb://localhost:27018".to_string(),
+                "--username=testuser".to_string(),
+                "--password=testpass".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_mongosh() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("test_container_id"),
+                eq(build_mongosh_command(
+                    LOCAL_MONGODB_URI,
+                    Some("testuser"),
+                    Some("testpass"),
+                    Some(EVAL),
+                    true,
+                )),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec!["deployment-uuid".to_string()],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.run_mongosh(&create_test_deployment(), EVAL).await;
+
+        // Assert
+        assert_eq!(result.unwrap().stdout, vec!["deployment-uuid".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_run_mongosh_non_zero_exit() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![],
+                    stderr: vec!["MongoServerError: Authentication failed.".to_string()],
+                    exit_code: Some(1),
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.run_mongosh(&create_test_deployment(), EVAL).await;
+
+        // Assert
+        match result.unwrap_err() {
+            MongoshError::NonZeroExit { exit_code, stderr } => {
+                assert_eq!(exit_code, 1);
+                assert_eq!(
+                    stderr,
+                    vec!["MongoServerError: Authentication failed.".to_string()]
+                );
+            }
+            other => panic!("Expected NonZeroExit, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_mongosh_run_command_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Err(RunCommandInContainerError::StartExec(
+                    DockerError::ServerError,
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.run_mongosh(&create_test_deployment(), EVAL).await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            MongoshError::RunMongoshCommand(_)
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+use bollard::models::SystemVersion;
+
+use crate::{
+    client::Client,
+    docker::{DockerError, DockerNegotiateVersion},
+    models::{ApiVersion, MINIMUM_API_VERSION, ParseApiVersionError},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum NegotiateApiVersionError {
+    #[error("Failed to get Docker version: {0}")]
+    Version(#[from] DockerError),
+    #[error("Docker did not report a valid API version: {0}")]
+    InvalidServerVersion(#[from] ParseApiVersionError),
+    #[error(
+        "Docker API version {server} is not supported, the minimum is {minimum}, please upgrade Docker"
+    )]
+    ServerTooOld {
+        server: ApiVersion,
+        minimum: ApiVersion,
+    },
+    #[error("Docker requires API version {server_minimum} or newer, this client uses {client}")]
+    ClientTooOld {
+        client: ApiVersion,
+        server_minimum: ApiVersion,
+    },
+}
+
+impl<D: DockerNegotiateVersion> Client<D> {
+    /// Returns the Docker API version requests are made with.
+    pub fn api_version(&self) -> ApiVersion {
+        self.docker.api_version()
+    }
+
+    /// Negotiates the Docker API version with the daemon and returns the version used from now on.
+    ///
+    /// When the daemon is older than the API version of the client, requests are made with the version of the daemon.
+    /// Fails when that version is older than [`MINIMUM_API_VERSION`], or when the daemon no longer accepts
+    /// the API version of the client.
+    pub async fn negotiate_api_version(&self) -> Result<ApiVersion, NegotiateApiVersionError> {
+        let system_version = self.docker.version().await?;
+        let client = self.docker.api_version();
+        let negotiated = negotiated_api_version(client, &system_version)?;
+
+        if negotiated < client {
+            self.docker.negotiate_version().await?;
+        }
+
+        Ok(negotiated)
+    }
+}
+
+/// Picks the newest API version both the client and the daemon support.
+fn negotiated_api_version(
+    client: ApiVersion,
+    system_version: &SystemVersion,
+) -> Result<ApiVersion, NegotiateApiVersionError> {
+    let server = ApiVersion::try_from(system_version.api_version.as_deref().unwrap_or_default())?;
+    let negotiated = client.min(server);
+
+    if negotiated < MINIMUM_API_VERSION {
+        return Err(NegotiateApiVersionError::ServerTooOld {
+            server,
+            minimum: MINIMUM_API_VERSION,
+        });
+    }
+
+    if let Some(server_minimum) = system_version.min_api_version.as_deref() {
+        let server_minimum = ApiVersion::try_from(server_minimum)?;
+        if negotiated < server_minimum {
+            return Err(NegotiateApiVersionError::ClientTooOld {
+                client,
+                server_minimum,
+            });
+        }
+    }
+
+    Ok(negotiated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        Docker {}
+
+        impl DockerNegotiateVersion for Docker {
+            async fn version(&self) -> Result<SystemVersion, DockerError>;
+            fn api_version(&self) -> ApiVersion;
+            async fn negotiate_version(&self) -> Result<(), DockerError>;
+        }
+    }
+
+    fn system_version(api_version: &str, min_api_version: &str) -> SystemVersion {
+        SystemVersion {
+            api_version: Some(api_version.to_string()),
+            min_api_version: Some(min_api_version.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn create_mock_docker(api_version: &str, min_api_version: &str) -> MockDocker {
+        let mut mock_docker = MockDocker::new();
+        let system_version = system_version(api_version, min_api_version);
+        mock_docker
+            .expect_version()
+            .times(1)
+            .returning(move || Ok(system_version.clone()));
+        mock_docker
+            .expect_api_version()
+            .returning(|| ApiVersion::new(1, 52));
+        mock_docker
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_older_server() {
+        // Arrange
+        let mut mock_docker = create_mock_docker("1.43", "1.24");
+        mock_docker
+            .expect_negotiate_version()
+            .times(1)
+            .returning(|| Ok(()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ApiVersion::new(1, 43));
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_newer_server() {
+        // Arrange
+        let mut mock_docker = create_mock_docker("1.54", "1.24");
+        mock_docker.expect_negotiate_version().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ApiVersion::new(1, 52));
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_server_too_old() {
+        // Arrange
+        let mut mock_docker = create_mock_docker("1.40", "1.12");
+        mock_docker.expect_negotiate_version().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            NegotiateApiVersionError::ServerTooOld { server, minimum }
+                if server == ApiVersion::new(1, 40) && minimum == MINIMUM_API_VERSION
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_client_too_old() {
+        // Arrange
+        let mut mock_docker = create_mock_docker("1.60", "1.55");
+        mock_docker.expect_negotiate_version().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            NegotiateApiVersionError::ClientTooOld { client, server_minimum }
+                if client == ApiVersion::new(1, 52) && server_minimum == ApiVersion::new(1, 55)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_missing_server_version() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_version()
+            .times(1)
+            .returning(|| Ok(SystemVersion::default()));
+        mock_docker
+            .expect_api_version()
+            .returning(|| ApiVersion::new(1, 52));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            NegotiateApiVersionError::InvalidServerVersion(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_api_version_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_version().times(1).returning(|| {
+            Err(DockerError::DaemonUnavailable {
+                message: "connection refused".to_string(),
+            })
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.negotiate_api_version().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            NegotiateApiVersionError::Version(DockerError::DaemonUnavailable { .. })
+        ));
+    }
+}
@@ -0,0 +1,455 @@
+use bollard::query_parameters::{ListVolumesOptionsBuilder, RemoveVolumeOptions};
+use maplit::hashmap;
+
+use crate::{
+    client::Client,
+    docker::{DockerError, DockerListVolumes, DockerRemoveVolume},
+    models::{LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PruneVolumesError {
+    #[error("Failed to list volumes: {0}")]
+    ListVolumes(DockerError),
+    #[error("Failed to remove volume {name}: {source}")]
+    RemoveVolume { name: String, source: DockerError },
+}
+
+impl<D: DockerListVolumes + DockerRemoveVolume> Client<D> {
+    /// Removes the volumes of local Atlas deployments that are no longer used by any container.
+    ///
+    /// Only volumes carrying the local deployment label are removed, other volumes are never touched.
+    /// [`Client::create_deployment`] puts the label on the data volume it creates.
+    /// A volume that is taken into use or removed since it was listed is skipped.
+    ///
+    /// # Returns
+    ///
+    /// The names of the removed volumes.
+    pub async fn prune_deployment_volumes(&self) -> Result<Vec<String>, PruneVolumesError> {
+        // Let Docker only list the labeled volumes no container refers to
+        let list_volumes_options = ListVolumesOptionsBuilder::new()
+            .filters(&hashmap! {
+                "label" => vec![format!("{LOCAL_DEPLOYMENT_LABEL_KEY}={LOCAL_DEPLOYMENT_LABEL_VALUE}")],
+                "dangling" => vec!["true".to_string()],
+            })
+            .build();
+
+        let volumes = self
+            .docker
+            .list_volumes(Some(list_volumes_options))
+            .await
+            .map_err(PruneVolumesError::ListVolumes)?
+            .volumes
+            .unwrap_or_default();
+
+        let mut removed_volumes = Vec::new();
+        for volume in volumes {
+            // Check the label again, a volume without it must never be removed
+            if volume
+                .labels
+                .get(LOCAL_DEPLOYMENT_LABEL_KEY)
+                .map(String::as_str)
+                != Some(LOCAL_DEPLOYMENT_LABEL_VALUE)
+            {
+                continue;
+            }
+
+            // Docker refuses to remove a volume that is in use (409), don't force it
+            match self
+                .docker
+                .remove_volume(&volume.name, None::<RemoveVolumeOptions>)
+                .await
+            {
+                Ok(()) => removed_volumes.push(volume.name),
+                Err(DockerError::Conflict | DockerError::NotFound) => {}
+                Err(source) => {
+                    return Err(PruneVolumesError::RemoveVolume {
+                        name: volume.name,
+                        source,
+                    });
+                }
+            }
+        }
+
+        Ok(removed_volumes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use super::*;
+    use crate::{
+        docker::{
+            DockerCreateContainer, DockerCreateVolume, DockerInspectContainer, DockerPullImage,
+            DockerRemoveContainer, DockerStartContainer, DockerStopContainer,
+        },
+        models::{CreateDeploymentOptions, DATA_DIRECTORY, WaitStrategy},
+        test_utils::create_container_inspect_response_with_auth,
+    };
+    use bollard::{
+        models::{
+            ContainerCreateBody, ContainerCreateResponse, ContainerInspectResponse,
+            CreateImageInfo, Volume, VolumeCreateRequest, VolumeListResponse,
+        },
+        query_parameters::{
+            CreateContainerOptions, InspectContainerOptions, ListVolumesOptions,
+            RemoveContainerOptions, StartContainerOptions, StopContainerOptions,
+        },
+    };
+    use futures_util::Stream;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerListVolumes for Docker {
+            async fn list_volumes(
+                &self,
+                options: Option<ListVolumesOptions>,
+            ) -> Result<VolumeListResponse, DockerError>;
+        }
+
+        impl DockerRemoveVolume for Docker {
+            async fn remove_volume(
+                &self,
+                volume_name: &str,
+                options: Option<RemoveVolumeOptions>,
+            ) -> Result<(), DockerError>;
+        }
+    }
+
+    fn volume(name: &str, labeled: bool) -> Volume {
+        Volume {
+            name: name.to_string(),
+            labels: if labeled {
+                hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                }
+            } else {
+                Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn volume_list_response(volumes: Vec<Volume>) -> VolumeListResponse {
+        VolumeListResponse {
+            volumes: Some(volumes),
+            warnings: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_deployment_volumes() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_volumes()
+            .withf(|options| {
+                let filters = options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref());
+                filters.and_then(|filters| filters.get("label"))
+                    == Some(&vec!["mongodb-atlas-local=container".to_string()])
+                    && filters.and_then(|filters| filters.get("dangling"))
+                        == Some(&vec!["true".to_string()])
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(volume_list_response(vec![
+                    volume("atlas-local-data", true),
+                    volume("other-data", false),
+                    volume("atlas-local-in-use", true),
+                ]))
+            });
+        mock_docker
+            .expect_remove_volume()
+            .with(eq("atlas-local-data"), eq(None::<RemoveVolumeOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_remove_volume()
+            .with(eq("other-data"), eq(None::<RemoveVolumeOptions>))
+            .never();
+        mock_docker
+            .expect_remove_volume()
+            .with(eq("atlas-local-in-use"), eq(None::<RemoveVolumeOptions>))
+            .times(1)
+            .returning(|_, _| Err(DockerError::Conflict));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.prune_deployment_volumes().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), vec!["atlas-local-data".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_prune_deployment_volumes_none_orphaned() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_list_volumes().times(1).returning(|_| {
+            Ok(VolumeListResponse {
+                volumes: None,
+                warnings: None,
+            })
+        });
+        mock_docker.expect_remove_volume().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.prune_deployment_volumes().await;
+
+        // Assert
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_deployment_volumes_remove_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_volumes()
+            .times(1)
+            .returning(|_| Ok(volume_list_response(vec![volume("atlas-local-data", true)])));
+        mock_docker
+            .expect_remove_volume()
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.prune_deployment_volumes().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            PruneVolumesError::RemoveVolume { name, source: DockerError::ServerError }
+                if name == "atlas-local-data"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_prune_deployment_volumes_list_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_volumes()
+            .times(1)
+            .returning(|_| Err(DockerError::ServerError));
+        mock_docker.expect_remove_volume().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.prune_deployment_volumes().await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            PruneVolumesError::ListVolumes(DockerError::ServerError)
+        ));
+    }
+
+    mock! {
+        DeploymentDocker {}
+
+        impl DockerPullImage for DeploymentDocker {
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerCreateVolume for DeploymentDocker {
+            async fn create_volume(
+                &self,
+                config: VolumeCreateRequest,
+            ) -> Result<Volume, DockerError>;
+        }
+
+        impl DockerCreateContainer for DeploymentDocker {
+            async fn create_container(
+                &self,
+                options: Option<CreateContainerOptions>,
+                config: ContainerCreateBody,
+            ) -> Result<ContainerCreateResponse, DockerError>;
+        }
+
+        impl DockerStartContainer for DeploymentDocker {
+            async fn start_container(
+                &self,
+                container_id: &str,
+                options: Option<StartContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerInspectContainer for DeploymentDocker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerStopContainer for DeploymentDocker {
+            async fn stop_container(
+                &self,
+                container_id: &str,
+                options: Option<StopContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerRemoveContainer for DeploymentDocker {
+            async fn remove_container(
+                &self,
+                container_id: &str,
+                options: Option<RemoveContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerListVolumes for DeploymentDocker {
+            async fn list_volumes(
+                &self,
+                options: Option<ListVolumesOptions>,
+            ) -> Result<VolumeListResponse, DockerError>;
+        }
+
+        impl DockerRemoveVolume for DeploymentDocker {
+            async fn remove_volume(
+                &self,
+                volume_name: &str,
+                options: Option<RemoveVolumeOptions>,
+            ) -> Result<(), DockerError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_deployment_volumes_removes_data_volume_of_deleted_deployment() {
+        // Arrange
+        let mut mock_docker = MockDeploymentDocker::new();
+
+        // Docker keeps the created volumes, a volume is dangling once the container is removed
+        let volumes = Arc::new(Mutex::new(Vec::<Volume>::new()));
+        let container_exists = Arc::new(AtomicBool::new(false));
+
+        mock_docker.expect_pull_image().never();
+        let created_volumes = volumes.clone();
+        mock_docker
+            .expect_create_volume()
+            .times(1)
+            .returning(move |config| {
+                let volume = Volume {
+                    name: config.name.unwrap_or_default(),
+                    labels: config.labels.unwrap_or_default(),
+                    ..Default::default()
+                };
+                created_volumes.lock().unwrap().push(volume.clone());
+                Ok(volume)
+            });
+        let created_container = container_exists.clone();
+        mock_docker
+            .expect_create_container()
+            .withf(|_, config| {
+                config
+                    .host_config
+                    .as_ref()
+                    .and_then(|host_config| host_config.binds.clone())
+                    == Some(vec![format!("atlas-local-data:{DATA_DIRECTORY}:rw")])
+            })
+            .times(1)
+            .returning(move |_, _| {
+                created_container.store(true, Ordering::SeqCst);
+                Ok(ContainerCreateResponse {
+                    id: "test_container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_stop_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let removed_container = container_exists.clone();
+        mock_docker
+            .expect_remove_container()
+            .times(1)
+            .returning(move |_, _| {
+                removed_container.store(false, Ordering::SeqCst);
+                Ok(())
+            });
+        let listed_volumes = volumes.clone();
+        let listed_container = container_exists.clone();
+        mock_docker
+            .expect_list_volumes()
+            .times(1)
+            .returning(move |options| {
+                let label = options
+                    .and_then(|options| options.filters)
+                    .and_then(|mut filters| filters.remove("label"))
+                    .unwrap_or_default();
+                let dangling = !listed_container.load(Ordering::SeqCst);
+                let volumes = listed_volumes
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .filter(|volume| {
+                        dangling
+                            && label.iter().all(|label| {
+                                label.split_once('=').is_some_and(|(key, value)| {
+                                    volume.labels.get(key).map(String::as_str) == Some(value)
+                                })
+                            })
+                    })
+                    .cloned()
+                    .collect();
+                Ok(volume_list_response(volumes))
+            });
+        let removed_volumes = volumes.clone();
+        mock_docker
+            .expect_remove_volume()
+            .with(eq("atlas-local-data"), eq(None))
+            .times(1)
+            .returning(move |name, _| {
+                removed_volumes
+                    .lock()
+                    .unwrap()
+                    .retain(|volume| volume.name != name);
+                Ok(())
+            });
+
+        let client = Client::new(mock_docker);
+        let deployment_options = CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            data_volume: Some("atlas-local-data".to_string()),
+            skip_pull_image: Some(true),
+            wait_strategy: Some(WaitStrategy::None),
+            ..Default::default()
+        };
+
+        // Act
+        client.create_deployment(deployment_options).await.unwrap();
+        client.delete_deployment("test-deployment").await.unwrap();
+        let result = client.prune_deployment_volumes().await;
+
+        // Assert
+        assert_eq!(result.unwrap(), vec!["atlas-local-data".to_string()]);
+        assert!(volumes.lock().unwrap().is_empty());
+    }
+}
@@ -1,11 +1,95 @@
+use std::time::Duration;
+
+use bollard::models::CreateImageInfo;
+use futures_util::{Stream, StreamExt, pin_mut};
+
 use crate::{
     client::Client,
-    docker::{DockerError, DockerPullImage},
+    docker::{DockerError, DockerInspectImage, DockerPullImage},
 };
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
-#[error("Failed to pull image: {0}")]
-pub struct PullImageError(#[from] DockerError);
+pub enum PullImageError {
+    #[error("Failed to pull image: {0}")]
+    Docker(DockerError),
+    /// The registry refused the pull because too many images were pulled (HTTP 429), e.g. the Docker Hub pull rate limit.
+    #[error("Failed to pull image: the registry pull rate limit was reached")]
+    RateLimited {
+        /// How long the registry asked to wait before retrying, when it said so.
+        retry_after: Option<Duration>,
+    },
+    #[error("Failed to inspect pulled image: {0}")]
+    InspectImage(DockerError),
+    #[error("Pulled image {0} has no ID")]
+    MissingImageId(String),
+}
+
+impl From<DockerError> for PullImageError {
+    fn from(err: DockerError) -> Self {
+        // Rate limits are reported as a 429 response, or as an error message in the pull stream
+        let is_rate_limited = match &err {
+            DockerError::Other {
+                status_code: Some(429),
+                ..
+            } => true,
+            DockerError::Other { message, .. } => {
+                let message = message.to_ascii_lowercase();
+                message.contains("toomanyrequests") || message.contains("rate limit")
+            }
+            _ => false,
+        };
+
+        match err {
+            DockerError::Other { message, .. } if is_rate_limited => PullImageError::RateLimited {
+                retry_after: parse_retry_after(&message),
+            },
+            err => PullImageError::Docker(err),
+        }
+    }
+}
+
+/// The result of pulling an image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullOutcome {
+    /// Whether new image content was downloaded, `false` when the local image was already up to date.
+    pub downloaded: bool,
+    /// The ID of the pulled image (e.g. `sha256:...`), as reported by inspecting it after the pull.
+    pub image_id: String,
+    /// The image digest reported by the registry (e.g. `sha256:...`), `None` when the daemon does not report one.
+    pub digest: Option<String>,
+}
+
+/// What the pull stream reported about a pull.
+struct PullStatus {
+    downloaded: bool,
+    digest: Option<String>,
+}
+
+/// A single progress event reported while pulling an image.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullProgress {
+    /// The layer the event is about, `None` for events about the whole image.
+    pub layer_id: Option<String>,
+    /// The status message, e.g. `Downloading` or `Pull complete`.
+    pub status: Option<String>,
+    /// The number of bytes processed so far for the layer.
+    pub current_bytes: Option<u64>,
+    /// The total size of the layer in bytes.
+    pub total_bytes: Option<u64>,
+}
+
+impl From<CreateImageInfo> for PullProgress {
+    fn from(info: CreateImageInfo) -> Self {
+        let progress_detail = info.progress_detail.unwrap_or_default();
+
+        PullProgress {
+            layer_id: info.id,
+            status: info.status,
+            current_bytes: progress_detail.current.and_then(|b| u64::try_from(b).ok()),
+            total_bytes: progress_detail.total.and_then(|b| u64::try_from(b).ok()),
+        }
+    }
+}
 
 impl<D: DockerPullImage> Client<D> {
     /// Pulls the Atlas Local image.
@@ -15,25 +99,224 @@ impl<D: DockerPullImage> Client<D> {
     /// * `image` - The image to pull.
     /// * `tag` - The tag to pull.
     pub async fn pull_image(&self, image: &str, tag: &str) -> Result<(), PullImageError> {
-        self.docker.pull_image(image, tag).await?;
+        self.pull_image_and_report(image, tag, |_| {}).await?;
         Ok(())
     }
+
+    /// Pulls an image and yields every progress event reported by Docker, e.g. to render a progress bar.
+    ///
+    /// The stream ends when the pull is finished, an error is yielded when the pull fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to pull.
+    /// * `tag` - The tag to pull.
+    pub fn pull_image_with_progress<'a>(
+        &'a self,
+        image: &'a str,
+        tag: &'a str,
+    ) -> impl Stream<Item = Result<PullProgress, PullImageError>> + 'a {
+        self.docker
+            .pull_image(image, tag)
+            .map(|result| result.map(PullProgress::from).map_err(PullImageError::from))
+    }
+
+    async fn pull_image_and_report(
+        &self,
+        image: &str,
+        tag: &str,
+        on_progress: impl FnMut(PullProgress),
+    ) -> Result<PullStatus, PullImageError> {
+        let pull = self.report_pull_progress(image, tag, on_progress);
+        #[cfg(feature = "tracing")]
+        let pull =
+            tracing::Instrument::instrument(pull, tracing::debug_span!("pull_image", image, tag));
+        pull.await
+    }
+
+    async fn report_pull_progress(
+        &self,
+        image: &str,
+        tag: &str,
+        mut on_progress: impl FnMut(PullProgress),
+    ) -> Result<PullStatus, PullImageError> {
+        let stream = self.pull_image_with_progress(image, tag);
+        pin_mut!(stream);
+
+        let mut downloaded = false;
+        let mut digest = None;
+
+        // Docker reports the pull result through status messages, for example:
+        // "Pull complete", "Digest: sha256:...", "Status: Downloaded newer image for ..." or "Status: Image is up to date for ..."
+        while let Some(result) = stream.next().await {
+            #[cfg(feature = "tracing")]
+            if let Err(PullImageError::RateLimited { retry_after }) = &result {
+                tracing::warn!(?retry_after, "pull rate limited by the registry");
+            }
+            let progress = result?;
+            let Some(status) = progress.status.as_deref() else {
+                on_progress(progress);
+                continue;
+            };
+
+            if let Some(reported_digest) = status.strip_prefix("Digest: ") {
+                digest = Some(reported_digest.trim().to_string());
+            } else if status == "Pull complete"
+                || status.starts_with("Status: Downloaded newer image")
+            {
+                downloaded = true;
+            }
+
+            on_progress(progress);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(downloaded, "image pulled");
+
+        Ok(PullStatus { downloaded, digest })
+    }
+}
+
+impl<D: DockerPullImage + DockerInspectImage> Client<D> {
+    /// Pulls the Atlas Local image and reports whether a download occurred.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to pull.
+    /// * `tag` - The tag to pull.
+    pub async fn pull_image_with_outcome(
+        &self,
+        image: &str,
+        tag: &str,
+    ) -> Result<PullOutcome, PullImageError> {
+        self.pull_and_inspect_image(image, tag, |_| {}).await
+    }
+
+    /// Pulls an image and invokes `on_progress` for every event reported by Docker.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The image reference, e.g. `mongodb/mongodb-atlas-local:8.0.0`.
+    ///   The `latest` tag is pulled when the reference has no tag or digest.
+    /// * `on_progress` - Called with each pull event, in the order Docker reports them.
+    pub async fn pull_image_with_callback(
+        &self,
+        reference: &str,
+        on_progress: impl FnMut(PullProgress),
+    ) -> Result<PullOutcome, PullImageError> {
+        let (image, tag) = split_image_reference(reference);
+        self.pull_and_inspect_image(image, tag, on_progress).await
+    }
+
+    /// Pulls an image, then inspects it to get the ID of the image now present locally.
+    async fn pull_and_inspect_image(
+        &self,
+        image: &str,
+        tag: &str,
+        on_progress: impl FnMut(PullProgress),
+    ) -> Result<PullOutcome, PullImageError> {
+        let PullStatus { downloaded, digest } =
+            self.pull_image_and_report(image, tag, on_progress).await?;
+
+        // A digest is referenced with `@`, e.g. `image@sha256:...`
+        let reference = if tag.contains(':') {
+            format!("{image}@{tag}")
+        } else {
+            format!("{image}:{tag}")
+        };
+        let image_id = self
+            .docker
+            .inspect_image(&reference)
+            .await
+            .map_err(PullImageError::InspectImage)?
+            .id
+            .ok_or(PullImageError::MissingImageId(reference))?;
+
+        Ok(PullOutcome {
+            downloaded,
+            image_id,
+            digest,
+        })
+    }
+}
+
+/// Parses the retry hint of a rate limit error, e.g. `Retry-After: 60` or `retry after 60 seconds`.
+fn parse_retry_after(message: &str) -> Option<Duration> {
+    let message = message.to_ascii_lowercase();
+    let start = ["retry-after", "retry after"]
+        .iter()
+        .find_map(|marker| message.find(marker).map(|index| index + marker.len()))?;
+
+    let seconds = message[start..]
+        .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse::<u64>()
+        .ok()?;
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Splits an image reference into the image and the tag (or digest) to pull.
+pub(super) fn split_image_reference(reference: &str) -> (&str, &str) {
+    if let Some((image, digest)) = reference.split_once('@') {
+        return (image, digest);
+    }
+
+    // A colon before the last slash belongs to a registry port, e.g. `localhost:5000/image`
+    match reference.rsplit_once(':') {
+        Some((image, tag)) if !tag.contains('/') => (image, tag),
+        _ => (reference, "latest"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::docker::DockerError;
-    use mockall::mock;
+    use bollard::models::{ImageInspect, ProgressDetail};
+    use futures_util::{Stream, stream};
+    use mockall::{mock, predicate::eq};
 
     mock! {
         Docker {}
 
         impl DockerPullImage for Docker {
-            async fn pull_image(&self, image: &str, tag: &str) -> Result<(), DockerError>;
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerInspectImage for Docker {
+            async fn inspect_image(&self, image: &str) -> Result<ImageInspect, DockerError>;
         }
     }
 
+    const IMAGE_ID: &str =
+        "sha256:4f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0";
+
+    fn expect_inspect_image(mock_docker: &mut MockDocker, reference: &'static str) {
+        mock_docker
+            .expect_inspect_image()
+            .with(eq(reference))
+            .times(1)
+            .returning(|_| {
+                Ok(ImageInspect {
+                    id: Some(IMAGE_ID.to_string()),
+                    ..Default::default()
+                })
+            });
+    }
+
+    fn status(status: &str) -> Result<CreateImageInfo, DockerError> {
+        Ok(CreateImageInfo {
+            status: Some(status.to_string()),
+            ..Default::default()
+        })
+    }
+
     #[tokio::test]
     async fn test_pull_image() {
         // Arrange
@@ -47,7 +330,7 @@ mod tests {
                 mockall::predicate::eq("8.0.0"),
             )
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Box::pin(stream::empty()));
 
         let client = Client::new(mock_docker);
 
@@ -60,6 +343,103 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_pull_image_rate_limited() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                status("Pulling from mongodb/mongodb-atlas-local"),
+                Err(DockerError::Other {
+                    status_code: Some(429),
+                    message: "toomanyrequests: Too Many Requests. Retry-After: 60".to_string(),
+                }),
+            ]))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err(),
+            PullImageError::RateLimited {
+                retry_after: Some(Duration::from_secs(60))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_rate_limited_without_retry_hint() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        // The daemon reports the registry error in the pull stream, without a status code
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![Err(DockerError::Other {
+                status_code: None,
+                message: "toomanyrequests: You have reached your pull rate limit. You may increase the limit by authenticating and upgrading: https://www.docker.com/increase-rate-limit".to_string(),
+            })]))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err(),
+            PullImageError::RateLimited { retry_after: None }
+        );
+    }
+
+    #[test]
+    fn test_pull_image_error_from_docker_error() {
+        assert_eq!(
+            PullImageError::from(DockerError::NotFound),
+            PullImageError::Docker(DockerError::NotFound)
+        );
+        assert_eq!(
+            PullImageError::from(DockerError::Other {
+                status_code: Some(502),
+                message: "bad gateway".to_string(),
+            }),
+            PullImageError::Docker(DockerError::Other {
+                status_code: Some(502),
+                message: "bad gateway".to_string(),
+            })
+        );
+        assert_eq!(
+            PullImageError::from(DockerError::Other {
+                status_code: Some(429),
+                message: "".to_string(),
+            }),
+            PullImageError::RateLimited { retry_after: None }
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(
+            parse_retry_after("Retry-After: 120"),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("rate limited, retry after 30 seconds"),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("toomanyrequests"), None);
+        assert_eq!(parse_retry_after("Retry-After: soon"), None);
+    }
+
     #[tokio::test]
     async fn test_pull_image_docker_error() {
         // Arrange
@@ -73,7 +453,7 @@ mod tests {
                 mockall::predicate::eq("invalid-tag"),
             )
             .times(1)
-            .returning(|_, _| Err(DockerError::NotFound));
+            .returning(|_, _| Box::pin(stream::iter(vec![Err(DockerError::NotFound)])));
 
         let client = Client::new(mock_docker);
 
@@ -84,6 +464,374 @@ mod tests {
 
         // Assert
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), PullImageError(_)));
+        assert!(matches!(result.unwrap_err(), PullImageError::Docker(_)));
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_outcome_up_to_date() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                status("Pulling from mongodb/mongodb-atlas-local"),
+                status("Digest: sha256:0123456789abcdef"),
+                status("Status: Image is up to date for mongodb/mongodb-atlas-local:8.0.0"),
+            ]))
+        });
+
+        expect_inspect_image(&mut mock_docker, "mongodb/mongodb-atlas-local:8.0.0");
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image_with_outcome("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            PullOutcome {
+                downloaded: false,
+                image_id: IMAGE_ID.to_string(),
+                digest: Some("sha256:0123456789abcdef".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_outcome_fresh_download() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                status("Pulling from mongodb/mongodb-atlas-local"),
+                status("Pulling fs layer"),
+                status("Downloading"),
+                status("Pull complete"),
+                status("Digest: sha256:fedcba9876543210"),
+                status("Status: Downloaded newer image for mongodb/mongodb-atlas-local:8.0.0"),
+            ]))
+        });
+
+        expect_inspect_image(&mut mock_docker, "mongodb/mongodb-atlas-local:8.0.0");
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image_with_outcome("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            PullOutcome {
+                downloaded: true,
+                image_id: IMAGE_ID.to_string(),
+                digest: Some("sha256:fedcba9876543210".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_outcome_without_digest() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+
+        expect_inspect_image(&mut mock_docker, "mongodb/mongodb-atlas-local:8.0.0");
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image_with_outcome("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            PullOutcome {
+                downloaded: false,
+                image_id: IMAGE_ID.to_string(),
+                digest: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_callback_digest_reference() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .with(
+                eq("mongodb/mongodb-atlas-local"),
+                eq("sha256:fedcba9876543210"),
+            )
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+        expect_inspect_image(
+            &mut mock_docker,
+            "mongodb/mongodb-atlas-local@sha256:fedcba9876543210",
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image_with_callback(
+                "mongodb/mongodb-atlas-local@sha256:fedcba9876543210",
+                |_| {},
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap().image_id, IMAGE_ID);
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_outcome_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+        mock_docker
+            .expect_inspect_image()
+            .times(1)
+            .returning(|_| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .pull_image_with_outcome("mongodb/mongodb-atlas-local", "8.0.0")
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err(),
+            PullImageError::InspectImage(DockerError::NotFound)
+        );
+    }
+
+    fn downloading(
+        layer_id: &str,
+        current: i64,
+        total: i64,
+    ) -> Result<CreateImageInfo, DockerError> {
+        Ok(CreateImageInfo {
+            id: Some(layer_id.to_string()),
+            status: Some("Downloading".to_string()),
+            progress_detail: Some(ProgressDetail {
+                current: Some(current),
+                total: Some(total),
+            }),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_callback() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .with(
+                mockall::predicate::eq("mongodb/mongodb-atlas-local"),
+                mockall::predicate::eq("8.0.0"),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    status("Pulling from mongodb/mongodb-atlas-local"),
+                    downloading("layer1", 10, 100),
+                    downloading("layer1", 50, 100),
+                    downloading("layer1", 100, 100),
+                    status("Pull complete"),
+                    status("Digest: sha256:fedcba9876543210"),
+                ]))
+            });
+
+        expect_inspect_image(&mut mock_docker, "mongodb/mongodb-atlas-local:8.0.0");
+
+        let client = Client::new(mock_docker);
+        let mut events = Vec::new();
+
+        // Act
+        let result = client
+            .pull_image_with_callback("mongodb/mongodb-atlas-local:8.0.0", |progress| {
+                events.push(progress)
+            })
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            PullOutcome {
+                downloaded: true,
+                image_id: IMAGE_ID.to_string(),
+                digest: Some("sha256:fedcba9876543210".to_string()),
+            }
+        );
+        assert_eq!(events.len(), 6);
+
+        let downloaded_bytes: Vec<u64> = events
+            .iter()
+            .filter(|progress| progress.layer_id.as_deref() == Some("layer1"))
+            .filter_map(|progress| progress.current_bytes)
+            .collect();
+        assert_eq!(downloaded_bytes, vec![10, 50, 100]);
+        assert!(downloaded_bytes.is_sorted());
+        assert!(
+            events
+                .iter()
+                .filter(|progress| progress.layer_id.is_some())
+                .all(|progress| progress.total_bytes == Some(100))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_callback_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                downloading("layer1", 10, 100),
+                Err(DockerError::NotFound),
+            ]))
+        });
+
+        mock_docker.expect_inspect_image().never();
+
+        let client = Client::new(mock_docker);
+        let mut events = 0;
+
+        // Act
+        let result = client
+            .pull_image_with_callback("mongodb/mongodb-atlas-local:8.0.0", |_| events += 1)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            PullImageError::Docker(DockerError::NotFound)
+        ));
+        assert_eq!(events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_progress() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .with(
+                mockall::predicate::eq("mongodb/mongodb-atlas-local"),
+                mockall::predicate::eq("8.0.0"),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    status("Pulling from mongodb/mongodb-atlas-local"),
+                    downloading("layer1", 10, 100),
+                    downloading("layer2", 20, 200),
+                    downloading("layer1", 100, 100),
+                    status("Pull complete"),
+                ]))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .pull_image_with_progress("mongodb/mongodb-atlas-local", "8.0.0")
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        let events = events.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(events.len(), 5);
+        assert_eq!(
+            events[0].status.as_deref(),
+            Some("Pulling from mongodb/mongodb-atlas-local")
+        );
+        assert_eq!(
+            events[2],
+            PullProgress {
+                layer_id: Some("layer2".to_string()),
+                status: Some("Downloading".to_string()),
+                current_bytes: Some(20),
+                total_bytes: Some(200),
+            }
+        );
+        assert_eq!(events[3].current_bytes, Some(100));
+        assert_eq!(events[4].layer_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_pull_image_with_progress_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker.expect_pull_image().times(1).returning(|_, _| {
+            Box::pin(stream::iter(vec![
+                downloading("layer1", 10, 100),
+                Err(DockerError::Other {
+                    status_code: Some(429),
+                    message: "toomanyrequests".to_string(),
+                }),
+            ]))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .pull_image_with_progress("mongodb/mongodb-atlas-local", "8.0.0")
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_ref().unwrap().current_bytes, Some(10));
+        assert_eq!(
+            events[1],
+            Err(PullImageError::RateLimited { retry_after: None })
+        );
+    }
+
+    #[test]
+    fn test_split_image_reference() {
+        assert_eq!(
+            split_image_reference("mongodb/mongodb-atlas-local:8.0.0"),
+            ("mongodb/mongodb-atlas-local", "8.0.0")
+        );
+        assert_eq!(
+            split_image_reference("mongodb/mongodb-atlas-local"),
+            ("mongodb/mongodb-atlas-local", "latest")
+        );
+        assert_eq!(
+            split_image_reference("localhost:5000/mongodb-atlas-local"),
+            ("localhost:5000/mongodb-atlas-local", "latest")
+        );
+        assert_eq!(
+            split_image_reference("quay.io/mongodb/mongodb-atlas-local@sha256:0123"),
+            ("quay.io/mongodb/mongodb-atlas-local", "sha256:0123")
+        );
     }
 }
@@ -0,0 +1,628 @@
+use std::collections::{BTreeSet, HashMap};
+
+use bollard::models::{ContainerCreateBody, ContainerInspectResponse, HostConfig};
+
+use crate::{
+    client::Client,
+    docker::{
+        DockerCreateContainer, DockerCreateVolume, DockerInspectContainer, DockerPullImage,
+        DockerRemoveContainer, DockerStartContainer, DockerStopContainer,
+    },
+    models::{CreateDeploymentOptions, DATA_DIRECTORY, Deployment, OPTION_ENV_VARS},
+};
+
+use super::{CreateDeploymentError, DeleteDeploymentError, GetDeploymentError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReconcileDeploymentError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Failed to delete outdated deployment: {0}")]
+    DeleteDeployment(#[from] DeleteDeploymentError),
+    #[error("Failed to create deployment: {0}")]
+    CreateDeployment(#[from] CreateDeploymentError),
+}
+
+/// The action taken by [`Client::reconcile_deployment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconcileAction {
+    /// The deployment did not exist and was created.
+    Created,
+    /// The deployment did not match the desired options and was recreated, keeping its data volume.
+    Recreated {
+        /// The options that did not match, e.g. `image` or `env.MONGODB_INITDB_DATABASE`.
+        changed_fields: Vec<String>,
+    },
+    /// The deployment already matched the desired options and was left untouched.
+    Unchanged,
+}
+
+/// The result of reconciling a deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconcileOutcome {
+    pub action: ReconcileAction,
+    /// The deployment after reconciliation.
+    pub deployment: Deployment,
+}
+
+impl<
+    D: DockerPullImage
+        + DockerCreateVolume
+        + DockerCreateContainer
+        + DockerStartContainer
+        + DockerInspectContainer
+        + DockerStopContainer
+        + DockerRemoveContainer
+        + Send
+        + Sync
+        + 'static,
+> Client<D>
+{
+    /// Brings a local Atlas deployment in line with the desired options.
+    ///
+    /// The deployment is created when it does not exist. An existing deployment is compared to the
+    /// options that end up in its container (image, environment, port binding, mounts and container
+    /// settings), options only used while creating, such as `wait_strategy`, are ignored.
+    /// When something differs the deployment is deleted and created again, its data volume is mounted
+    /// in the new container unless `data_volume` is set, so the data is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the deployment, takes precedence over the name in `desired`.
+    /// * `desired` - The options the deployment should have been created with.
+    pub async fn reconcile_deployment(
+        &self,
+        name: &str,
+        mut desired: CreateDeploymentOptions,
+    ) -> Result<ReconcileOutcome, ReconcileDeploymentError> {
+        desired.name = Some(name.to_string());
+
+        let container_inspect_response = match self.inspect_deployment_container(name).await {
+            Ok(container_inspect_response) => container_inspect_response,
+            Err(GetDeploymentError::NotFound { .. }) => {
+                let deployment = self.create_deployment(desired).await?;
+                return Ok(ReconcileOutcome {
+                    action: ReconcileAction::Created,
+                    deployment,
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Only reconcile local Atlas deployments
+        let deployment = Deployment::try_from(container_inspect_response.clone())
+            .map_err(GetDeploymentError::from)?;
+
+        let changed_fields = changed_fields(&desired, &container_inspect_response);
+        if changed_fields.is_empty() {
+            return Ok(ReconcileOutcome {
+                action: ReconcileAction::Unchanged,
+                deployment,
+            });
+        }
+
+        // Deleting the container keeps its volumes, mount the data volume in the new container
+        if desired.data_volume.is_none() {
+            desired.data_volume = data_volume(&container_inspect_response);
+        }
+
+        self.delete_deployment(name).await?;
+        let deployment = self.create_deployment(desired).await?;
+
+        Ok(ReconcileOutcome {
+            action: ReconcileAction::Recreated { changed_fields },
+            deployment,
+        })
+    }
+}
+
+/// Lists the options that do not match the container.
+fn changed_fields(
+    desired: &CreateDeploymentOptions,
+    container_inspect_response: &ContainerInspectResponse,
+) -> Vec<String> {
+    let desired_body = ContainerCreateBody::from(desired);
+    let desired_host_config = desired_body.host_config.unwrap_or_default();
+    let actual_config = container_inspect_response
+        .config
+        .clone()
+        .unwrap_or_default();
+    let actual_host_config = container_inspect_response
+        .host_config
+        .clone()
+        .unwrap_or_default();
+
+    let mut changed_fields = Vec::new();
+
+    if desired_body.image != actual_config.image {
+        changed_fields.push("image".to_string());
+    }
+
+    let desired_env = env_map(desired_body.env.as_deref());
+    let actual_env = env_map(actual_config.env.as_deref());
+    for key in OPTION_ENV_VARS {
+        if desired_env.get(key) != actual_env.get(key) {
+            changed_fields.push(format!("env.{key}"));
+        }
+    }
+    for key in desired
+        .extra_env
+        .iter()
+        .flat_map(|extra_env| extra_env.keys())
+    {
+        let key = key.as_str();
+        if !OPTION_ENV_VARS.contains(&key) && desired_env.get(key) != actual_env.get(key) {
+            changed_fields.push(format!("env.{key}"));
+        }
+    }
+
+    if mongodb_port_bindings(&desired_host_config) != mongodb_port_bindings(&actual_host_config) {
+        changed_fields.push("mongodb_port_binding".to_string());
+    }
+
+    // The data directory is compared on its own, a recreated deployment keeps its data volume by default
+    if binds(&desired_host_config) != binds(&actual_host_config) {
+        changed_fields.push("binds".to_string());
+    }
+    if desired.data_volume.is_some()
+        && desired.data_volume != data_volume(container_inspect_response)
+    {
+        changed_fields.push("data_volume".to_string());
+    }
+
+    // Docker fills in defaults for the container settings, so they are only compared when set
+    if desired_host_config.network_mode.is_some()
+        && desired_host_config.network_mode != actual_host_config.network_mode
+    {
+        changed_fields.push("network_mode".to_string());
+    }
+    if desired.oom_score_adj.is_some() && desired.oom_score_adj != actual_host_config.oom_score_adj
+    {
+        changed_fields.push("oom_score_adj".to_string());
+    }
+    if desired.shm_size.is_some() && desired.shm_size != actual_host_config.shm_size {
+        changed_fields.push("shm_size".to_string());
+    }
+    if desired.cgroup_parent.is_some() && desired.cgroup_parent != actual_host_config.cgroup_parent
+    {
+        changed_fields.push("cgroup_parent".to_string());
+    }
+    if desired.auto_remove.is_some() && desired.auto_remove != actual_host_config.auto_remove {
+        changed_fields.push("auto_remove".to_string());
+    }
+    if desired_host_config.restart_policy.is_some()
+        && desired_host_config.restart_policy != actual_host_config.restart_policy
+    {
+        changed_fields.push("restart_policy".to_string());
+    }
+
+    changed_fields
+}
+
+fn env_map(env: Option<&[String]>) -> HashMap<&str, &str> {
+    env.unwrap_or_default()
+        .iter()
+        .filter_map(|var| var.split_once('='))
+        .collect()
+}
+
+/// Returns the `(container port, host IP, host port)` of the published ports, only the MongoDB port is published.
+/// Docker stores a random host port as an empty string.
+fn mongodb_port_bindings(host_config: &HostConfig) -> Vec<(String, String, String)> {
+    let mut port_bindings = host_config
+        .port_bindings
+        .iter()
+        .flatten()
+        .flat_map(|(container_port, bindings)| {
+            bindings.iter().flatten().map(move |binding| {
+                (
+                    container_port.clone(),
+                    binding.host_ip.clone().unwrap_or_default(),
+                    binding.host_port.clone().unwrap_or_default(),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    port_bindings.sort();
+    port_bindings
+}
+
+/// Returns the binds, except the one of the data directory.
+fn binds(host_config: &HostConfig) -> BTreeSet<&str> {
+    host_config
+        .binds
+        .iter()
+        .flatten()
+        .map(String::as_str)
+        .filter(|bind| bind.split(':').nth(1) != Some(DATA_DIRECTORY))
+        .collect()
+}
+
+/// Returns the name of the volume mounted as the data directory, including anonymous volumes.
+fn data_volume(container_inspect_response: &ContainerInspectResponse) -> Option<String> {
+    container_inspect_response
+        .mounts
+        .as_ref()?
+        .iter()
+        .find(|mount| {
+            mount.destination.as_deref() == Some(DATA_DIRECTORY)
+                && mount.typ.as_deref() == Some("volume")
+        })?
+        .name
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{docker::DockerError, models::WaitStrategy};
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerCreateResponse, ContainerState, ContainerStateStatusEnum,
+            CreateImageInfo, MountPoint, NetworkSettings, PortBinding, Volume, VolumeCreateRequest,
+        },
+        query_parameters::{
+            CreateContainerOptions, InspectContainerOptions, RemoveContainerOptions,
+            StartContainerOptions, StopContainerOptions,
+        },
+    };
+    use futures_util::Stream;
+    use maplit::hashmap;
+    use mockall::{Sequence, mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerPullImage for Docker {
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerCreateVolume for Docker {
+            async fn create_volume(
+                &self,
+                config: VolumeCreateRequest,
+            ) -> Result<Volume, DockerError>;
+        }
+
+        impl DockerCreateContainer for Docker {
+            async fn create_container(
+                &self,
+                options: Option<CreateContainerOptions>,
+                config: ContainerCreateBody,
+            ) -> Result<ContainerCreateResponse, DockerError>;
+        }
+
+        impl DockerStartContainer for Docker {
+            async fn start_container(
+                &self,
+                container_id: &str,
+                options: Option<StartContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerStopContainer for Docker {
+            async fn stop_container(
+                &self,
+                container_id: &str,
+                options: Option<StopContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerRemoveContainer for Docker {
+            async fn remove_container(
+                &self,
+                container_id: &str,
+                options: Option<RemoveContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+    }
+
+    fn desired_options(database: &str) -> CreateDeploymentOptions {
+        CreateDeploymentOptions {
+            skip_pull_image: Some(true),
+            wait_strategy: Some(WaitStrategy::None),
+            mongodb_initdb_database: Some(database.to_string()),
+            skip_version_label: Some(true),
+            ..Default::default()
+        }
+    }
+
+    fn create_test_container_inspect_response(database: &str) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("test_container_id".to_string()),
+            name: Some("/test-deployment".to_string()),
+            config: Some(ContainerConfig {
+                image: Some("quay.io/mongodb/mongodb-atlas-local:latest".to_string()),
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => "8.0.0".to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                env: Some(vec![
+                    "PATH=/usr/local/bin:/usr/bin:/bin".to_string(),
+                    format!("MONGODB_INITDB_DATABASE={database}"),
+                ]),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                port_bindings: Some(hashmap! {
+                    "27017/tcp".to_string() => Some(vec![PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: Some("".to_string()),
+                    }]),
+                }),
+                // Docker fills in the default shared memory size
+                shm_size: Some(64 * 1024 * 1024),
+                ..Default::default()
+            }),
+            mounts: Some(vec![MountPoint {
+                typ: Some("volume".to_string()),
+                name: Some("test-data-volume".to_string()),
+                destination: Some(DATA_DIRECTORY.to_string()),
+                ..Default::default()
+            }]),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            // The host port Docker picked for the random port binding
+            network_settings: Some(NetworkSettings {
+                ports: Some(hashmap! {
+                    "27017/tcp".to_string() => Some(vec![PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: Some("49152".to_string()),
+                    }]),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn expect_create_and_start(mock_docker: &mut MockDocker, expected_binds: Option<Vec<String>>) {
+        mock_docker
+            .expect_create_container()
+            .withf(move |options, config| {
+                options.as_ref().and_then(|options| options.name.as_deref())
+                    == Some("test-deployment")
+                    && config
+                        .host_config
+                        .as_ref()
+                        .and_then(|host_config| host_config.binds.clone())
+                        == expected_binds
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "test_container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+
+        mock_docker
+            .expect_start_container()
+            .with(eq("test-deployment"), eq(None::<StartContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deployment_unchanged() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response("testdb")));
+
+        mock_docker.expect_stop_container().never();
+        mock_docker.expect_remove_container().never();
+        mock_docker.expect_create_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .reconcile_deployment("test-deployment", desired_options("testdb"))
+            .await;
+
+        // Assert
+        let outcome = result.unwrap();
+        assert_eq!(outcome.action, ReconcileAction::Unchanged);
+        assert_eq!(outcome.deployment.container_id, "test_container_id");
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deployment_recreated() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+
+        // Reconcile and delete inspect the old container, create inspects the new one
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response("testdb")));
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response("otherdb")));
+
+        mock_docker
+            .expect_stop_container()
+            .with(eq("test_container_id"), eq(None::<StopContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // The volumes are kept when removing the container
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("test_container_id"),
+                eq(Some(RemoveContainerOptions::default())),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // The volume of the replaced container is reused, Docker returns it unchanged
+        mock_docker
+            .expect_create_volume()
+            .withf(|config| config.name.as_deref() == Some("test-data-volume"))
+            .times(1)
+            .returning(|_| {
+                Ok(Volume {
+                    name: "test-data-volume".to_string(),
+                    ..Default::default()
+                })
+            });
+        expect_create_and_start(
+            &mut mock_docker,
+            Some(vec![format!("test-data-volume:{DATA_DIRECTORY}:rw")]),
+        );
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .reconcile_deployment("test-deployment", desired_options("otherdb"))
+            .await;
+
+        // Assert
+        let outcome = result.unwrap();
+        assert_eq!(
+            outcome.action,
+            ReconcileAction::Recreated {
+                changed_fields: vec!["env.MONGODB_INITDB_DATABASE".to_string()]
+            }
+        );
+        assert_eq!(
+            outcome.deployment.mongodb_initdb_database,
+            Some("otherdb".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deployment_created() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response("testdb")));
+
+        mock_docker.expect_stop_container().never();
+        mock_docker.expect_remove_container().never();
+
+        expect_create_and_start(&mut mock_docker, None);
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .reconcile_deployment("test-deployment", desired_options("testdb"))
+            .await;
+
+        // Assert
+        let outcome = result.unwrap();
+        assert_eq!(outcome.action, ReconcileAction::Created);
+        assert_eq!(outcome.deployment.name, Some("test-deployment".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deployment_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::ServerError));
+
+        mock_docker.expect_create_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .reconcile_deployment("test-deployment", desired_options("testdb"))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            ReconcileDeploymentError::GetDeployment(GetDeploymentError::ContainerInspect(
+                DockerError::ServerError
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_changed_fields() {
+        let container_inspect_response = create_test_container_inspect_response("testdb");
+        let desired = CreateDeploymentOptions {
+            image: Some("example.com/atlas-local".to_string()),
+            creation_source: Some(crate::models::CreationSource::Container),
+            mongodb_port_binding: Some(crate::models::MongoDBPortBinding::new(
+                Some(27017),
+                crate::models::BindingType::Loopback,
+            )),
+            local_seed_location: Some("/host/seed-data".to_string()),
+            data_volume: Some("other-data-volume".to_string()),
+            shm_size: Some(64 * 1024 * 1024),
+            ..desired_options("testdb")
+        };
+
+        assert_eq!(
+            changed_fields(&desired, &container_inspect_response),
+            vec![
+                "image",
+                "env.TOOL",
+                "mongodb_port_binding",
+                "binds",
+                "data_volume"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_fields_extra_env() {
+        let container_inspect_response = create_test_container_inspect_response("testdb");
+        let desired = CreateDeploymentOptions {
+            extra_env: Some(hashmap! {
+                "MONGOT_INDEX_DEFINITIONS".to_string() => "/etc/mongot/indexes.json".to_string(),
+                // Ignored when creating the container, so never reported as changed
+                "TOOL".to_string() => "OVERRIDE".to_string(),
+            }),
+            ..desired_options("testdb")
+        };
+
+        assert_eq!(
+            changed_fields(&desired, &container_inspect_response),
+            vec!["env.MONGOT_INDEX_DEFINITIONS"]
+        );
+    }
+}
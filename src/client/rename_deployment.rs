@@ -0,0 +1,173 @@
+use crate::{
+    client::{Client, GetDeploymentError},
+    docker::{DockerError, DockerInspectContainer, DockerRenameContainer},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenameDeploymentError {
+    #[error("Invalid deployment name: \"{0}\"")]
+    InvalidName(String),
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("The name \"{0}\" is already in use by another container")]
+    NameAlreadyInUse(String),
+    #[error("Failed to rename container: {0}")]
+    ContainerRename(DockerError),
+}
+
+impl<D: DockerInspectContainer + DockerRenameContainer> Client<D> {
+    /// Renames a local Atlas deployment, e.g. once the final name of a temporarily named deployment is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or current name of the deployment.
+    /// * `new_name` - The new name of the deployment.
+    pub async fn rename_deployment(
+        &self,
+        container_id_or_name: &str,
+        new_name: &str,
+    ) -> Result<(), RenameDeploymentError> {
+        if new_name.trim().is_empty() {
+            return Err(RenameDeploymentError::InvalidName(new_name.to_string()));
+        }
+
+        // Check that a deployment with that name exists and get the container ID.
+        // This ensures we only try to rename valid Atlas local deployments.
+        let deployment = self.get_deployment(container_id_or_name).await?;
+
+        self.docker
+            .rename_container(&deployment.container_id, new_name)
+            .await
+            .map_err(|err| match err {
+                DockerError::Conflict => {
+                    RenameDeploymentError::NameAlreadyInUse(new_name.to_string())
+                }
+                err => RenameDeploymentError::ContainerRename(err),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_container_inspect_response_with_auth;
+    use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerRenameContainer for Docker {
+            async fn rename_container(&self, container_id: &str, new_name: &str) -> Result<(), DockerError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rename_deployment() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("tmp-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_rename_container()
+            .with(eq("test_container_id"), eq("final-deployment"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .rename_deployment("tmp-deployment", "final-deployment")
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rename_deployment_name_already_in_use() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_rename_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::Conflict));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .rename_deployment("tmp-deployment", "existing-deployment")
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            RenameDeploymentError::NameAlreadyInUse(name) if name == "existing-deployment"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rename_deployment_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_rename_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .rename_deployment("nonexistent-deployment", "final-deployment")
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            RenameDeploymentError::GetDeployment(GetDeploymentError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rename_deployment_invalid_name() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_inspect_container().never();
+        mock_docker.expect_rename_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let empty = client.rename_deployment("tmp-deployment", "").await;
+        let whitespace = client.rename_deployment("tmp-deployment", "  \t").await;
+
+        // Assert
+        assert!(matches!(
+            empty.unwrap_err(),
+            RenameDeploymentError::InvalidName(_)
+        ));
+        assert!(matches!(
+            whitespace.unwrap_err(),
+            RenameDeploymentError::InvalidName(_)
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{client::GetDeploymentError, docker::DockerError};
+
+use super::Client;
+
+/// An error that can go away when the request is sent again.
+pub(crate) trait TransientError: std::fmt::Display {
+    fn is_transient(&self) -> bool;
+}
+
+impl TransientError for DockerError {
+    fn is_transient(&self) -> bool {
+        match self {
+            DockerError::ServerError => true,
+            DockerError::Other {
+                status_code: Some(status_code),
+                ..
+            } => (500..600).contains(status_code),
+            _ => false,
+        }
+    }
+}
+
+impl TransientError for GetDeploymentError {
+    fn is_transient(&self) -> bool {
+        matches!(self, GetDeploymentError::ContainerInspect(err) if err.is_transient())
+    }
+}
+
+impl<D> Client<D> {
+    /// Runs a request, retrying it on transient errors according to the retry policy of the client.
+    ///
+    /// Without a retry policy the request is sent once.
+    pub(crate) async fn with_retries<T, E, F, Fut>(&self, mut request: F) -> Result<T, E>
+    where
+        E: TransientError,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let Some(retry_policy) = self.retry_policy else {
+            return request().await;
+        };
+
+        let mut attempt = 1;
+        loop {
+            match request().await {
+                Err(err) if err.is_transient() && attempt < retry_policy.max_attempts => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(attempt, error = %err, "transient Docker error, retrying");
+                    tokio::time::sleep(retry_policy.delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        assert!(DockerError::ServerError.is_transient());
+        assert!(
+            DockerError::Other {
+                status_code: Some(503),
+                message: "service unavailable".to_string(),
+            }
+            .is_transient()
+        );
+        assert!(!DockerError::NotFound.is_transient());
+        assert!(!DockerError::Conflict.is_transient());
+        assert!(
+            !DockerError::Other {
+                status_code: None,
+                message: "connection reset".to_string(),
+            }
+            .is_transient()
+        );
+        assert!(GetDeploymentError::ContainerInspect(DockerError::ServerError).is_transient());
+        assert!(
+            !GetDeploymentError::NotFound {
+                name: "test-deployment".to_string()
+            }
+            .is_transient()
+        );
+    }
+}
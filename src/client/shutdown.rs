@@ -0,0 +1,317 @@
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use bollard::query_parameters::RemoveContainerOptions;
+use tokio::task::JoinHandle;
+
+use crate::{client::Client, docker::DockerRemoveContainer};
+
+/// Keeps track of the background tasks spawned by [`Client::create_deployment`].
+///
+/// Dropping the tracker aborts every task that is still running.
+#[derive(Default)]
+pub(crate) struct TaskTracker {
+    tasks: Mutex<Vec<TrackedTask>>,
+}
+
+struct TrackedTask {
+    created_container_id: Arc<OnceLock<String>>,
+    handle: JoinHandle<()>,
+}
+
+impl TaskTracker {
+    /// Tracks a task creating a container, `created_container_id` is set by the task once Docker created it.
+    pub(crate) fn track(
+        &self,
+        created_container_id: Arc<OnceLock<String>>,
+        handle: JoinHandle<()>,
+    ) {
+        let mut tasks = self.tasks.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Forget about the tasks that are already done so the list does not grow forever
+        tasks.retain(|task| !task.handle.is_finished());
+        tasks.push(TrackedTask {
+            created_container_id,
+            handle,
+        });
+    }
+
+    fn take(&self) -> Vec<TrackedTask> {
+        std::mem::take(&mut *self.tasks.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl Drop for TaskTracker {
+    fn drop(&mut self) {
+        let tasks = self.tasks.get_mut().unwrap_or_else(PoisonError::into_inner);
+        for task in tasks.iter() {
+            task.handle.abort();
+        }
+    }
+}
+
+impl<D: DockerRemoveContainer> Client<D> {
+    /// Shuts down the client, aborting the deployments that are still being created.
+    ///
+    /// Containers created by aborted creations are force removed on a best-effort basis, errors are ignored.
+    /// Nothing is removed when the creation was aborted before Docker created its container, an existing
+    /// container with the same name is never removed. Deployments that finished creating are kept.
+    ///
+    /// Dropping the last clone of a client also aborts the creations, but cannot remove their containers.
+    pub async fn shutdown(self) {
+        for task in self.tasks.take() {
+            task.handle.abort();
+
+            // The task may have finished before it could be aborted, its deployment is complete then
+            let Err(join_error) = task.handle.await else {
+                continue;
+            };
+            if !join_error.is_cancelled() {
+                continue;
+            }
+            let Some(container_id) = task.created_container_id.get() else {
+                continue;
+            };
+
+            let remove_container_options = RemoveContainerOptions {
+                force: true,
+                ..Default::default()
+            };
+            _ = self
+                .docker
+                .remove_container(container_id, Some(remove_container_options))
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        docker::{
+            DockerCreateContainer, DockerCreateVolume, DockerError, DockerInspectContainer,
+            DockerPullImage, DockerStartContainer,
+        },
+        models::CreateDeploymentOptions,
+    };
+    use bollard::{
+        models::{
+            ContainerCreateBody, ContainerCreateResponse, ContainerInspectResponse, ContainerState,
+            Health, HealthStatusEnum, Volume, VolumeCreateRequest,
+        },
+        query_parameters::{
+            CreateContainerOptions, InspectContainerOptions, StartContainerOptions,
+        },
+    };
+    use futures_util::{Stream, stream};
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerPullImage for Docker {
+            fn pull_image<'a>(
+                &'a self,
+                image: &str,
+                tag: &str,
+            ) -> impl Stream<Item = Result<bollard::models::CreateImageInfo, DockerError>> + Send;
+        }
+
+        impl DockerCreateVolume for Docker {
+            async fn create_volume(
+                &self,
+                config: VolumeCreateRequest,
+            ) -> Result<Volume, DockerError>;
+        }
+
+        impl DockerCreateContainer for Docker {
+            async fn create_container(
+                &self,
+                options: Option<CreateContainerOptions>,
+                config: ContainerCreateBody,
+            ) -> Result<ContainerCreateResponse, DockerError>;
+        }
+
+        impl DockerStartContainer for Docker {
+            async fn start_container(
+                &self,
+                container_id: &str,
+                options: Option<StartContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerRemoveContainer for Docker {
+            async fn remove_container(
+                &self,
+                container_id: &str,
+                options: Option<RemoveContainerOptions>,
+            ) -> Result<(), DockerError>;
+        }
+    }
+
+    /// A mock whose image pull never completes, so the creation stays in flight.
+    fn create_mock_docker_with_pending_pull() -> MockDocker {
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::pending()));
+
+        mock_docker.expect_create_container().never();
+
+        mock_docker
+    }
+
+    fn create_container_inspect_response_starting() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                health: Some(Health {
+                    status: Some(HealthStatusEnum::STARTING),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn create_deployment_options() -> CreateDeploymentOptions {
+        CreateDeploymentOptions {
+            name: Some("test-deployment".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_client_aborts_create_deployment() {
+        // Arrange
+        let client = Client::new(create_mock_docker_with_pending_pull());
+        let progress = client.create_deployment(create_deployment_options());
+
+        // Let the background task start pulling the image
+        tokio::task::yield_now().await;
+
+        // Act
+        drop(client);
+        let result = progress.await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            crate::client::CreateDeploymentError::ReceiveDeployment(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_drop_clone_keeps_create_deployment_running() {
+        // Arrange
+        let client = Client::new(create_mock_docker_with_pending_pull());
+        let mut progress = client.create_deployment(create_deployment_options());
+
+        // Act
+        drop(client.clone());
+        tokio::task::yield_now().await;
+
+        // Assert
+        let deployment = futures_util::FutureExt::now_or_never(&mut progress);
+        assert!(deployment.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_aborts_and_removes_created_container() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_pull_image()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::empty()));
+        mock_docker
+            .expect_create_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerCreateResponse {
+                    id: "created_container_id".to_string(),
+                    warnings: vec![],
+                })
+            });
+        mock_docker
+            .expect_start_container()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // The deployment never becomes healthy
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_container_inspect_response_starting()));
+        mock_docker
+            .expect_remove_container()
+            .with(
+                eq("created_container_id"),
+                eq(Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+        let mut progress = client.create_deployment(create_deployment_options());
+        progress.wait_for_start_container_outcome().await.unwrap();
+
+        // Act
+        client.shutdown().await;
+        let result = progress.await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            crate::client::CreateDeploymentError::ReceiveDeployment(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_keeps_existing_container_with_same_name() {
+        // Arrange
+        // The creation is aborted before it created a container, the container named
+        // `test-deployment` that already exists belongs to someone else
+        let mut mock_docker = create_mock_docker_with_pending_pull();
+        mock_docker.expect_remove_container().never();
+
+        let client = Client::new(mock_docker);
+        let progress = client.create_deployment(create_deployment_options());
+        tokio::task::yield_now().await;
+
+        // Act
+        client.shutdown().await;
+        let result = progress.await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            crate::client::CreateDeploymentError::ReceiveDeployment(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_without_tasks() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_remove_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act & Assert
+        client.shutdown().await;
+    }
+}
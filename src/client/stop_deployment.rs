@@ -3,6 +3,7 @@ use bollard::query_parameters::StopContainerOptions;
 use crate::{
     client::Client,
     docker::{DockerInspectContainer, DockerStopContainer},
+    models::StopDeploymentOptions,
 };
 
 use super::GetDeploymentError;
@@ -18,14 +19,37 @@ pub enum StopDeploymentError {
 impl<D: DockerStopContainer + DockerInspectContainer> Client<D> {
     /// Stops a local Atlas deployment.
     pub async fn stop_deployment(&self, name: &str) -> Result<(), StopDeploymentError> {
+        self.stop_deployment_with_options(name, StopDeploymentOptions::default())
+            .await
+    }
+
+    /// Stops a local Atlas deployment using the provided options.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name or ID of the deployment to stop.
+    /// * `options` - How long to wait for the deployment to shut down before it is killed.
+    pub async fn stop_deployment_with_options(
+        &self,
+        name: &str,
+        options: StopDeploymentOptions,
+    ) -> Result<(), StopDeploymentError> {
         // Check that a deployment with that name exists and get the container ID.
         // This ensures we only try to stop valid Atlas local deployments.
         let deployment = self.get_deployment(name).await?;
         let container_id = deployment.container_id.as_str();
 
-        // Stop the container gracefully.
+        // Stop the container gracefully, Docker's default grace period applies without a timeout.
+        // Docker takes the timeout as a 32 bit integer.
+        let stop_container_options =
+            options
+                .timeout_secs
+                .map(|timeout_secs| StopContainerOptions {
+                    t: Some(timeout_secs.clamp(i32::MIN.into(), i32::MAX.into()) as i32),
+                    ..Default::default()
+                });
         self.docker
-            .stop_container(container_id, None::<StopContainerOptions>)
+            .stop_container(container_id, stop_container_options)
             .await
             .map_err(|e| StopDeploymentError::ContainerStop(e.to_string()))?;
 
@@ -120,6 +144,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_stop_deployment_with_options_timeout() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(move |_, _| Ok(create_test_container_inspect_response()));
+        mock_docker
+            .expect_stop_container()
+            .with(
+                mockall::predicate::eq("test_container_id"),
+                mockall::predicate::eq(Some(StopContainerOptions {
+                    t: Some(2),
+                    ..Default::default()
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let client = Client::new(mock_docker);
+        let options = StopDeploymentOptions::builder().timeout_secs(2).build();
+
+        // Act
+        let result = client
+            .stop_deployment_with_options("test-deployment", options)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_stop_deployment_get_deployment_error() {
         // Arrange
@@ -0,0 +1,322 @@
+use chrono::Utc;
+use futures_util::{Stream, StreamExt, future, stream};
+
+use crate::{
+    Client,
+    client::{MongoshError, get_deployment::GetDeploymentError},
+    docker::{DockerInspectContainer, DockerLogContainer, RunCommandInContainer},
+    models::{LogOutput, SlowQuery},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StreamSlowQueriesError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error(transparent)]
+    Mongosh(#[from] MongoshError),
+    #[error("Failed to get container logs: {0}")]
+    ContainerLogs(String),
+    #[error(
+        "The current time {timestamp} does not fit the 32-bit timestamp of the Docker logs API"
+    )]
+    TimestampOutOfRange { timestamp: i64 },
+}
+
+impl<D: DockerInspectContainer + RunCommandInContainer + DockerLogContainer> Client<D> {
+    /// Streams the slow queries of a local Atlas deployment.
+    ///
+    /// Sets the slow operation threshold of the deployment to `threshold_ms` using mongosh,
+    /// then follows the container logs and yields every slow query logged from now on.
+    /// The stream ends when the container stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment.
+    /// * `threshold_ms` - Operations slower than this many milliseconds are reported.
+    pub async fn stream_slow_queries<'a>(
+        &'a self,
+        container_id_or_name: &'a str,
+        threshold_ms: u64,
+    ) -> Result<
+        impl Stream<Item = Result<SlowQuery, StreamSlowQueriesError>> + 'a,
+        StreamSlowQueriesError,
+    > {
+        let deployment = self.get_deployment(container_id_or_name).await?;
+
+        // Profiling level 0 only changes the slow operation threshold
+        self.run_mongosh(
+            &deployment,
+            &format!("db.setProfilingLevel(0, {{ slowms: {threshold_ms} }})"),
+        )
+        .await?;
+
+        // Follow the logs, starting now so earlier slow queries are not replayed
+        let timestamp = Utc::now().timestamp();
+        let since = i32::try_from(timestamp)
+            .map_err(|_| StreamSlowQueriesError::TimestampOutOfRange { timestamp })?;
+        let logs_options = bollard::query_parameters::LogsOptions {
+            follow: true,
+            stdout: true,
+            stderr: true,
+            since,
+            ..Default::default()
+        };
+
+        // Follow the container that was resolved, not whatever container has the name later on
+        let logs = self
+            .docker
+            .logs(&deployment.container_id, Some(logs_options));
+
+        Ok(split_lines(logs).filter_map(|line| {
+            future::ready(match line {
+                Ok(line) => SlowQuery::from_log_line(&line).map(Ok),
+                Err(err) => Some(Err(err)),
+            })
+        }))
+    }
+}
+
+/// Turns a stream of log chunks into a stream of complete lines.
+/// A chunk can hold several lines, or only part of a line when mongod writes a long entry.
+fn split_lines<'a>(
+    logs: impl Stream<Item = Result<bollard::container::LogOutput, String>> + 'a,
+) -> impl Stream<Item = Result<String, StreamSlowQueriesError>> + 'a {
+    logs.scan(String::new(), |buffer, chunk| {
+        let lines = match chunk {
+            Ok(chunk) => {
+                buffer.push_str(&LogOutput::from(chunk).as_str_lossy());
+
+                let mut lines = Vec::new();
+                while let Some(end) = buffer.find('\n') {
+                    let line: String = buffer.drain(..=end).collect();
+                    lines.push(Ok(line.trim_end().to_string()));
+                }
+                lines
+            }
+            Err(err) => vec![Err(StreamSlowQueriesError::ContainerLogs(err))],
+        };
+
+        future::ready(Some(stream::iter(lines)))
+    })
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{CommandOutput, DockerError, RunCommandInContainerError};
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+        },
+        query_parameters::InspectContainerOptions,
+    };
+    use bytes::Bytes;
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl RunCommandInContainer for Docker {
+            async fn run_command_in_container(
+                &self,
+                container_id: &str,
+                command: Vec<String>,
+            ) -> Result<CommandOutput, RunCommandInContainerError>;
+        }
+
+        impl DockerLogContainer for Docker {
+            fn logs<'a>(
+                &'a self,
+                container_id: &str,
+                options: Option<bollard::query_parameters::LogsOptions>,
+            ) -> impl Stream<Item = Result<bollard::container::LogOutput, String>>;
+        }
+    }
+
+    const SLOW_FIND: &str = r#"{"t":{"$date":"2024-05-01T12:00:00.123+00:00"},"s":"I","c":"COMMAND","id":51803,"ctx":"conn12","msg":"Slow query","attr":{"type":"command","ns":"test.orders","planSummary":"COLLSCAN","durationMillis":250}}"#;
+    const SLOW_UPDATE: &str = r#"{"t":{"$date":"2024-05-01T12:00:01.000+00:00"},"s":"I","c":"WRITE","id":51803,"ctx":"conn12","msg":"Slow query","attr":{"type":"update","ns":"test.orders","planSummary":"IXSCAN { status: 1 }","durationMillis":120}}"#;
+    const CONNECTION_ACCEPTED: &str = r#"{"t":{"$date":"2024-05-01T12:00:00.500+00:00"},"s":"I","c":"NETWORK","id":22943,"ctx":"listener","msg":"Connection accepted","attr":{"connectionCount":3}}"#;
+
+    fn stdout(message: String) -> Result<bollard::container::LogOutput, String> {
+        Ok(bollard::container::LogOutput::StdOut {
+            message: Bytes::from(message),
+        })
+    }
+
+    fn create_test_container_inspect_response() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("test_container_id".to_string()),
+            name: Some("/test-deployment".to_string()),
+            config: Some(ContainerConfig {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => "8.0.0".to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_slow_queries() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_run_command_in_container()
+            .with(
+                eq("test_container_id"),
+                eq(vec![
+                    "mongosh".to_string(),
+                    "mongodb://127.0.0.1:27017/?directConnection=true".to_string(),
+                    "--eval".to_string(),
+                    "db.setProfilingLevel(0, { slowms: 100 })".to_string(),
+                    "--quiet".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        // The second slow query is split over two chunks
+        let (update_start, update_end) = SLOW_UPDATE.split_at(40);
+        let chunks = vec![
+            stdout(format!("{SLOW_FIND}\n{CONNECTION_ACCEPTED}\n")),
+            stdout(update_start.to_string()),
+            stdout(format!("{update_end}\n")),
+        ];
+        mock_docker
+            .expect_logs()
+            .withf(|container_id, options| {
+                container_id == "test_container_id"
+                    && options.as_ref().is_some_and(|options| options.follow)
+            })
+            .times(1)
+            .returning(move |_, _| Box::pin(stream::iter(chunks.clone())));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let slow_queries = client
+            .stream_slow_queries("test-deployment", 100)
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        let slow_queries = slow_queries
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(slow_queries.len(), 2);
+        assert_eq!(slow_queries[0].duration_ms, 250);
+        assert_eq!(slow_queries[0].plan_summary, Some("COLLSCAN".to_string()));
+        assert_eq!(slow_queries[1].duration_ms, 120);
+        assert_eq!(slow_queries[1].op_type, Some("update".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_stream_slow_queries_log_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CommandOutput {
+                    stdout: vec![],
+                    stderr: vec![],
+                    exit_code: Some(0),
+                })
+            });
+
+        mock_docker
+            .expect_logs()
+            .times(1)
+            .returning(|_, _| Box::pin(stream::iter(vec![Err("connection lost".to_string())])));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let slow_queries = client
+            .stream_slow_queries("test-deployment", 100)
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(slow_queries.len(), 1);
+        assert!(matches!(
+            slow_queries[0],
+            Err(StreamSlowQueriesError::ContainerLogs(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_slow_queries_mongosh_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_run_command_in_container()
+            .times(1)
+            .returning(|_, _| {
+                Err(RunCommandInContainerError::StartExec(
+                    DockerError::ServerError,
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.stream_slow_queries("test-deployment", 100).await;
+
+        // Assert
+        assert!(matches!(
+            result.err(),
+            Some(StreamSlowQueriesError::Mongosh(
+                MongoshError::RunMongoshCommand(_)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+use bollard::query_parameters::EventsOptionsBuilder;
+use futures_util::{Stream, StreamExt, future};
+use maplit::hashmap;
+
+use crate::{
+    client::{Client, GetDeploymentError},
+    docker::{DockerError, DockerEvents, DockerInspectContainer},
+    models::DeploymentEvent,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubscribeEventsError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Failed to receive Docker events: {0}")]
+    Events(#[from] DockerError),
+}
+
+impl<D: DockerEvents + DockerInspectContainer> Client<D> {
+    /// Subscribes to the Docker events of a single deployment, e.g. it being stopped or becoming healthy.
+    ///
+    /// The deployment is looked up first so a missing deployment is reported before subscribing.
+    /// Only events about its container are yielded, the stream does not end when the container is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment to get the events of.
+    pub async fn subscribe_events<'a>(
+        &'a self,
+        container_id_or_name: &str,
+    ) -> Result<
+        impl Stream<Item = Result<DeploymentEvent, SubscribeEventsError>> + 'a,
+        SubscribeEventsError,
+    > {
+        let deployment = self.get_deployment(container_id_or_name).await?;
+        let container_id = deployment.container_id;
+
+        // Let Docker filter the events, the container ID is checked again below in case it does not
+        let events_options = EventsOptionsBuilder::new()
+            .filters(&hashmap! {
+                "type" => vec!["container".to_string()],
+                "container" => vec![container_id.clone()],
+            })
+            .build();
+
+        let events = self.docker.events(Some(events_options));
+
+        Ok(events.filter_map(move |result| {
+            let event = match result {
+                Ok(event_message) => DeploymentEvent::from_event_message(event_message)
+                    .filter(|event| event.container_id == container_id)
+                    .map(Ok),
+                Err(err) => Some(Err(SubscribeEventsError::Events(err))),
+            };
+            future::ready(event)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::DeploymentEventAction, test_utils::create_container_inspect_response_with_auth,
+    };
+    use bollard::{
+        models::{ContainerInspectResponse, EventActor, EventMessage, EventMessageTypeEnum},
+        query_parameters::{EventsOptions, InspectContainerOptions},
+    };
+    use futures_util::stream;
+    use mockall::mock;
+
+    mock! {
+        Docker {}
+
+        impl DockerEvents for Docker {
+            fn events<'a>(
+                &'a self,
+                options: Option<EventsOptions>,
+            ) -> impl Stream<Item = Result<EventMessage, DockerError>> + Send;
+        }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+    }
+
+    fn event_message(typ: EventMessageTypeEnum, id: &str, action: &str) -> EventMessage {
+        EventMessage {
+            typ: Some(typ),
+            action: Some(action.to_string()),
+            actor: Some(EventActor {
+                id: Some(id.to_string()),
+                attributes: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_events()
+            .withf(|options| {
+                options
+                    .as_ref()
+                    .and_then(|options| options.filters.as_ref())
+                    .and_then(|filters| filters.get("container"))
+                    == Some(&vec!["test_container_id".to_string()])
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(stream::iter(vec![
+                    Ok(event_message(
+                        EventMessageTypeEnum::CONTAINER,
+                        "test_container_id",
+                        "stop",
+                    )),
+                    Ok(event_message(
+                        EventMessageTypeEnum::CONTAINER,
+                        "other_container_id",
+                        "start",
+                    )),
+                    Ok(event_message(
+                        EventMessageTypeEnum::NETWORK,
+                        "test_container_id",
+                        "disconnect",
+                    )),
+                    Ok(event_message(
+                        EventMessageTypeEnum::CONTAINER,
+                        "test_container_id",
+                        "start",
+                    )),
+                ]))
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .subscribe_events("test-deployment")
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        let actions = events
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                assert_eq!(event.container_id, "test_container_id");
+                event.action
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![DeploymentEventAction::Stop, DeploymentEventAction::Start]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_stream_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_container_inspect_response_with_auth(27017)));
+        mock_docker
+            .expect_events()
+            .times(1)
+            .returning(|_| Box::pin(stream::iter(vec![Err(DockerError::ServerError)])));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .subscribe_events("test-deployment")
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            Err(SubscribeEventsError::Events(DockerError::ServerError))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_events().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.subscribe_events("nonexistent-deployment").await;
+
+        // Assert
+        assert!(matches!(
+            result.err(),
+            Some(SubscribeEventsError::GetDeployment(_))
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+use std::{pin::Pin, time::Duration};
+
+use bollard::{models::ContainerStateStatusEnum, query_parameters::InspectContainerOptions};
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::{
+    client::{Client, GetLogsError},
+    docker::{DockerError, DockerInspectContainer, DockerLogContainer},
+    models::{LogChunk, LogOutput, TailLogsEvent},
+};
+
+/// Time to wait before subscribing again after the log stream ended, and between checks of a stopped container.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+
+type LogStream<'a> =
+    Pin<Box<dyn Stream<Item = Result<bollard::container::LogOutput, String>> + 'a>>;
+
+enum TailState<'a> {
+    /// Waiting for the container to run to subscribe to its logs, `reconnect` is false for the first subscription.
+    Connecting {
+        reconnect: bool,
+    },
+    Streaming(LogStream<'a>),
+    Done,
+}
+
+struct Tail<'a, D> {
+    client: &'a Client<D>,
+    container_id_or_name: &'a str,
+    state: TailState<'a>,
+    /// When the first subscription was made, logs written before are not yielded.
+    started_at: DateTime<Utc>,
+    /// Timestamp of the last yielded chunk, used to skip the chunks replayed after subscribing again.
+    last_timestamp: Option<DateTime<Utc>>,
+}
+
+impl<D: DockerLogContainer + DockerInspectContainer> Client<D> {
+    /// Follows the logs of a container across restarts, until the container is removed.
+    ///
+    /// Unlike [`Client::follow_logs`] the stream does not end when the container stops: it waits for the
+    /// container to run again and subscribes to its logs again, yielding [`TailLogsEvent::Reconnected`].
+    /// Transient errors of the log stream are handled the same way. Only logs written from now on are yielded,
+    /// chunks already yielded before a reconnection are skipped using their timestamps.
+    ///
+    /// Fails with [`GetLogsError::ContainerInspect`] when the container does not exist or cannot be inspected,
+    /// the stream then ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the container to follow the logs of.
+    pub fn tail_logs_resilient<'a>(
+        &'a self,
+        container_id_or_name: &'a str,
+    ) -> impl Stream<Item = Result<TailLogsEvent, GetLogsError>> + 'a {
+        let tail = Tail {
+            client: self,
+            container_id_or_name,
+            state: TailState::Connecting { reconnect: false },
+            started_at: Utc::now(),
+            last_timestamp: None,
+        };
+
+        stream::unfold(tail, |mut tail| async move {
+            let event = tail.next_event().await?;
+            Some((event, tail))
+        })
+    }
+}
+
+impl<'a, D: DockerLogContainer + DockerInspectContainer> Tail<'a, D> {
+    /// Returns the next event, `None` once the container is removed or an error was yielded.
+    async fn next_event(&mut self) -> Option<Result<TailLogsEvent, GetLogsError>> {
+        loop {
+            match &mut self.state {
+                TailState::Connecting { reconnect } => {
+                    let reconnect = *reconnect;
+                    if reconnect {
+                        tokio::time::sleep(RECONNECT_INTERVAL).await;
+                    }
+
+                    let container_inspect_response = match self
+                        .client
+                        .docker
+                        .inspect_container(
+                            self.container_id_or_name,
+                            None::<InspectContainerOptions>,
+                        )
+                        .await
+                    {
+                        Ok(container_inspect_response) => container_inspect_response,
+                        // The container was removed while tailing its logs, the stream is over
+                        Err(DockerError::NotFound) if reconnect => {
+                            self.state = TailState::Done;
+                            return None;
+                        }
+                        Err(err) => {
+                            self.state = TailState::Done;
+                            return Some(Err(GetLogsError::ContainerInspect(err)));
+                        }
+                    };
+
+                    let running = container_inspect_response
+                        .state
+                        .and_then(|state| state.status)
+                        == Some(ContainerStateStatusEnum::RUNNING);
+                    if !running {
+                        // Check again later, the container might be restarted
+                        self.state = TailState::Connecting { reconnect: true };
+                        continue;
+                    }
+
+                    self.state = TailState::Streaming(self.subscribe());
+                    if reconnect {
+                        return Some(Ok(TailLogsEvent::Reconnected));
+                    }
+                }
+                TailState::Streaming(logs) => match logs.next().await {
+                    Some(Ok(output)) => {
+                        let chunk = LogChunk::new(LogOutput::from(output), true);
+
+                        // Subscribing again replays the chunks written during the last second
+                        if let (Some(timestamp), Some(last_timestamp)) =
+                            (chunk.timestamp, self.last_timestamp)
+                            && timestamp <= last_timestamp
+                        {
+                            continue;
+                        }
+
+                        self.last_timestamp = chunk.timestamp.or(self.last_timestamp);
+                        return Some(Ok(TailLogsEvent::Log(chunk)));
+                    }
+                    // The stream ended or broke, e.g. because the container stopped
+                    Some(Err(_)) | None => {
+                        self.state = TailState::Connecting { reconnect: true };
+                    }
+                },
+                TailState::Done => return None,
+            }
+        }
+    }
+
+    /// Subscribes to the logs written since the last yielded chunk.
+    fn subscribe(&self) -> LogStream<'a> {
+        let since = self.last_timestamp.unwrap_or(self.started_at);
+        let logs_options = bollard::query_parameters::LogsOptions {
+            follow: true,
+            stdout: true,
+            stderr: true,
+            since: since.timestamp() as i32,
+            timestamps: true,
+            ..Default::default()
+        };
+
+        Box::pin(
+            self.client
+                .docker
+                .logs(self.container_id_or_name, Some(logs_options)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ContainerInspectResponse, ContainerState};
+    use mockall::{Sequence, mock};
+
+    mock! {
+        Docker {}
+
+        impl DockerLogContainer for Docker {
+            fn logs<'a>(
+                &'a self,
+                container_id: &str,
+                options: Option<bollard::query_parameters::LogsOptions>,
+            ) -> impl Stream<Item = Result<bollard::container::LogOutput, String>>;
+        }
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+    }
+
+    fn container_inspect_response(status: ContainerStateStatusEnum) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                status: Some(status),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn log_line(line: &str) -> Result<bollard::container::LogOutput, String> {
+        Ok(bollard::container::LogOutput::StdOut {
+            message: format!("{line}\n").into(),
+        })
+    }
+
+    fn log_messages(events: &[Result<TailLogsEvent, GetLogsError>]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                Ok(TailLogsEvent::Log(chunk)) => chunk.output.as_str_lossy().trim_end().to_string(),
+                Ok(TailLogsEvent::Reconnected) => "<reconnected>".to_string(),
+                Err(err) => format!("<error: {err}>"),
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_tail_logs_resilient_reconnects() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+        mock_docker
+            .expect_logs()
+            .withf(|container_id, options| {
+                container_id == "test-deployment"
+                    && options
+                        .as_ref()
+                        .is_some_and(|options| options.follow && options.timestamps)
+            })
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    log_line("2025-01-01T00:00:00.100000000Z starting"),
+                    log_line("2025-01-01T00:00:00.200000000Z restarting"),
+                ]))
+            });
+
+        // The container is restarting, then runs again
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RESTARTING,
+                ))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+        mock_docker
+            .expect_logs()
+            .withf(|_, options| {
+                options.as_ref().is_some_and(|options| {
+                    options.since
+                        == DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+                            .unwrap()
+                            .timestamp() as i32
+                })
+            })
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    // Replayed because the subscription starts at the second
+                    log_line("2025-01-01T00:00:00.200000000Z restarting"),
+                    log_line("2025-01-01T00:00:05.000000000Z started again"),
+                ]))
+            });
+
+        // The container is removed
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .tail_logs_resilient("test-deployment")
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(
+            log_messages(&events),
+            vec![
+                "2025-01-01T00:00:00.100000000Z starting",
+                "2025-01-01T00:00:00.200000000Z restarting",
+                "<reconnected>",
+                "2025-01-01T00:00:05.000000000Z started again",
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_tail_logs_resilient_reconnects_after_stream_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+        mock_docker
+            .expect_logs()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![
+                    log_line("2025-01-01T00:00:00.100000000Z before"),
+                    Err("connection reset".to_string()),
+                ]))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+        mock_docker
+            .expect_logs()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Box::pin(stream::iter(vec![log_line(
+                    "2025-01-01T00:00:01.000000000Z after",
+                )]))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .tail_logs_resilient("test-deployment")
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(
+            log_messages(&events),
+            vec![
+                "2025-01-01T00:00:00.100000000Z before",
+                "<reconnected>",
+                "2025-01-01T00:00:01.000000000Z after",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_resilient_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let events = client
+            .tail_logs_resilient("nonexistent-deployment")
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            Err(GetLogsError::ContainerInspect(DockerError::NotFound))
+        ));
+    }
+}
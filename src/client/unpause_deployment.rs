@@ -1,6 +1,8 @@
+use futures_util::future;
+
 use crate::{
     client::Client,
-    docker::{DockerInspectContainer, DockerUnpauseContainer},
+    docker::{DockerInspectContainer, DockerListContainers, DockerUnpauseContainer},
 };
 
 use super::GetDeploymentError;
@@ -31,11 +33,39 @@ impl<D: DockerUnpauseContainer + DockerInspectContainer> Client<D> {
     }
 }
 
+impl<D: DockerUnpauseContainer + DockerInspectContainer + DockerListContainers> Client<D> {
+    /// Unpauses all local Atlas deployments concurrently.
+    ///
+    /// A failure to unpause one deployment does not stop the others from being unpaused, the result of
+    /// every deployment is returned along with its name (or container ID when it has no name).
+    /// Fails only when the deployments cannot be listed.
+    pub async fn unpause_all_deployments(
+        &self,
+    ) -> Result<Vec<(String, Result<(), UnpauseDeploymentError>)>, GetDeploymentError> {
+        let deployments = self.list_deployments().await?;
+
+        let unpauses = deployments.into_iter().map(|deployment| async move {
+            let result = self
+                .docker
+                .unpause_container(&deployment.container_id)
+                .await
+                .map_err(|e| UnpauseDeploymentError::ContainerUnpause(e.to_string()));
+
+            (deployment.name.unwrap_or(deployment.container_id), result)
+        });
+
+        Ok(future::join_all(unpauses).await)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::docker::DockerError;
-    use bollard::{models::ContainerInspectResponse, query_parameters::InspectContainerOptions};
+    use bollard::{
+        models::{ContainerInspectResponse, ContainerSummary},
+        query_parameters::{InspectContainerOptions, ListContainersOptions},
+    };
     use mockall::mock;
 
     mock! {
@@ -52,9 +82,23 @@ mod tests {
                 options: Option<InspectContainerOptions>,
             ) -> Result<ContainerInspectResponse, DockerError>;
         }
+
+        impl DockerListContainers for Docker {
+            async fn list_containers(
+                &self,
+                options: Option<ListContainersOptions>,
+            ) -> Result<Vec<ContainerSummary>, DockerError>;
+        }
     }
 
     fn create_test_container_inspect_response() -> ContainerInspectResponse {
+        create_container_inspect_response("test_container_id", "test-deployment")
+    }
+
+    fn create_container_inspect_response(
+        container_id: &str,
+        name: &str,
+    ) -> ContainerInspectResponse {
         use bollard::models::{ContainerConfig, ContainerState, ContainerStateStatusEnum};
         use std::collections::HashMap;
 
@@ -66,8 +110,8 @@ mod tests {
         let env_vars = vec!["TOOL=ATLASCLI".to_string()];
 
         ContainerInspectResponse {
-            id: Some("test_container_id".to_string()),
-            name: Some("/test-deployment".to_string()),
+            id: Some(container_id.to_string()),
+            name: Some(format!("/{name}")),
             config: Some(ContainerConfig {
                 labels: Some(labels),
                 env: Some(env_vars),
@@ -163,4 +207,78 @@ mod tests {
             UnpauseDeploymentError::ContainerUnpause(_)
         ));
     }
+
+    #[tokio::test]
+    async fn test_unpause_all_deployments() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    ContainerSummary {
+                        id: Some("container_1".to_string()),
+                        ..Default::default()
+                    },
+                    ContainerSummary {
+                        id: Some("container_2".to_string()),
+                        ..Default::default()
+                    },
+                ])
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|container_id, _| {
+                let name = container_id.replace("container", "deployment");
+                Ok(create_container_inspect_response(container_id, &name))
+            });
+
+        // One of the deployments fails to unpause, the other one is still unpaused
+        mock_docker
+            .expect_unpause_container()
+            .with(mockall::predicate::eq("container_1"))
+            .times(1)
+            .returning(|_| Err(DockerError::ServerError));
+        mock_docker
+            .expect_unpause_container()
+            .with(mockall::predicate::eq("container_2"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let results = client.unpause_all_deployments().await.unwrap();
+
+        // Assert
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "deployment_1");
+        assert!(matches!(
+            results[0].1,
+            Err(UnpauseDeploymentError::ContainerUnpause(_))
+        ));
+        assert_eq!(results[1].0, "deployment_2");
+        assert!(results[1].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unpause_all_deployments_list_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_list_containers()
+            .times(1)
+            .returning(|_| Err(DockerError::ServerError));
+        mock_docker.expect_unpause_container().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.unpause_all_deployments().await;
+
+        // Assert
+        assert!(result.is_err());
+    }
 }
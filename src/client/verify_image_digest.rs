@@ -0,0 +1,310 @@
+use crate::{
+    client::Client,
+    docker::{DockerError, DockerInspectContainer, DockerInspectImage},
+    models::Deployment,
+};
+
+use super::GetDeploymentError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyImageDigestError {
+    #[error("Failed to get deployment: {0}")]
+    GetDeployment(#[from] GetDeploymentError),
+    #[error("Container {0} does not reference an image")]
+    MissingImage(String),
+    #[error("Failed to inspect image: {0}")]
+    ImageInspect(DockerError),
+    #[error("Image digest mismatch, expected {expected} but found {actual:?}")]
+    DigestMismatch {
+        expected: String,
+        actual: Vec<String>,
+    },
+}
+
+impl<D: DockerInspectContainer + DockerInspectImage> Client<D> {
+    /// Verifies that a deployment runs the image with the expected digest.
+    ///
+    /// The expected digest (e.g. `sha256:...`) is compared to the image ID and to the
+    /// registry digests of the image, a full `repository@sha256:...` reference is accepted as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id_or_name` - The ID or name of the deployment to verify.
+    /// * `expected` - The pinned image digest.
+    pub async fn verify_image_digest(
+        &self,
+        container_id_or_name: &str,
+        expected: &str,
+    ) -> Result<(), VerifyImageDigestError> {
+        let container_inspect_response = self
+            .inspect_deployment_container(container_id_or_name)
+            .await?;
+
+        // Only verify local Atlas deployments
+        Deployment::try_from(container_inspect_response.clone())
+            .map_err(GetDeploymentError::from)?;
+
+        // The container references its image by image ID
+        let image_id = container_inspect_response.image.ok_or_else(|| {
+            VerifyImageDigestError::MissingImage(container_id_or_name.to_string())
+        })?;
+
+        let image_inspect = self
+            .docker
+            .inspect_image(&image_id)
+            .await
+            .map_err(VerifyImageDigestError::ImageInspect)?;
+
+        // Collect every digest the image is known by
+        let mut actual = vec![image_id];
+        actual.extend(image_inspect.repo_digests.unwrap_or_default());
+
+        let matches = actual.iter().any(|digest| {
+            digest == expected
+                || digest
+                    .split_once('@')
+                    .is_some_and(|(_, digest)| digest == expected)
+        });
+
+        if matches {
+            Ok(())
+        } else {
+            Err(VerifyImageDigestError::DigestMismatch {
+                expected: expected.to_string(),
+                actual,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::{
+        models::{
+            ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+            ImageInspect,
+        },
+        query_parameters::InspectContainerOptions,
+    };
+    use maplit::hashmap;
+    use mockall::{mock, predicate::eq};
+
+    const IMAGE_ID: &str =
+        "sha256:1111111111111111111111111111111111111111111111111111111111111111";
+    const REPO_DIGEST: &str =
+        "sha256:2222222222222222222222222222222222222222222222222222222222222222";
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+
+        impl DockerInspectImage for Docker {
+            async fn inspect_image(&self, image: &str) -> Result<ImageInspect, DockerError>;
+        }
+    }
+
+    fn create_test_container_inspect_response() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("test_container_id".to_string()),
+            name: Some("/test-deployment".to_string()),
+            image: Some(IMAGE_ID.to_string()),
+            config: Some(ContainerConfig {
+                labels: Some(hashmap! {
+                    "mongodb-atlas-local".to_string() => "container".to_string(),
+                    "version".to_string() => "8.0.0".to_string(),
+                    "mongodb-type".to_string() => "community".to_string(),
+                }),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn create_mock_docker() -> MockDocker {
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(eq("test-deployment"), eq(None::<InspectContainerOptions>))
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_inspect_image()
+            .with(eq(IMAGE_ID))
+            .times(1)
+            .returning(|_| {
+                Ok(ImageInspect {
+                    id: Some(IMAGE_ID.to_string()),
+                    repo_digests: Some(vec![format!(
+                        "quay.io/mongodb/mongodb-atlas-local@{REPO_DIGEST}"
+                    )]),
+                    ..Default::default()
+                })
+            });
+
+        mock_docker
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_matches_repo_digest() {
+        // Arrange
+        let client = Client::new(create_mock_docker());
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", REPO_DIGEST)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_matches_full_reference() {
+        // Arrange
+        let client = Client::new(create_mock_docker());
+
+        // Act
+        let result = client
+            .verify_image_digest(
+                "test-deployment",
+                &format!("quay.io/mongodb/mongodb-atlas-local@{REPO_DIGEST}"),
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_matches_image_id() {
+        // Arrange
+        let client = Client::new(create_mock_docker());
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", IMAGE_ID)
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_mismatch() {
+        // Arrange
+        let client = Client::new(create_mock_docker());
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", "sha256:3333")
+            .await;
+
+        // Assert
+        match result.unwrap_err() {
+            VerifyImageDigestError::DigestMismatch { expected, actual } => {
+                assert_eq!(expected, "sha256:3333");
+                assert_eq!(actual.len(), 2);
+            }
+            other => panic!("Expected DigestMismatch error, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_image_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        mock_docker
+            .expect_inspect_image()
+            .times(1)
+            .returning(|_| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", REPO_DIGEST)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyImageDigestError::ImageInspect(DockerError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_deployment_not_found() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+        mock_docker.expect_inspect_image().never();
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", REPO_DIGEST)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyImageDigestError::GetDeployment(GetDeploymentError::NotFound { name })
+                if name == "test-deployment"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_image_digest_not_a_local_deployment() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                Ok(ContainerInspectResponse {
+                    id: Some("test_container_id".to_string()),
+                    image: Some(IMAGE_ID.to_string()),
+                    ..Default::default()
+                })
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .verify_image_digest("test-deployment", REPO_DIGEST)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            VerifyImageDigestError::GetDeployment(GetDeploymentError::IntoDeployment(_))
+        ));
+    }
+}
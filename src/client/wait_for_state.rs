@@ -0,0 +1,225 @@
+use bollard::query_parameters::InspectContainerOptions;
+use tokio::time;
+
+use crate::{
+    client::{Client, DEFAULT_POLL_INTERVAL},
+    docker::{DockerError, DockerInspectContainer},
+    models::{GetStateError, State, WatchOptions},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WatchStateError {
+    #[error("Failed to inspect container: {0}")]
+    ContainerInspect(#[from] DockerError),
+    #[error("Failed to get the state of container {deployment_name}: {source}")]
+    GetState {
+        deployment_name: String,
+        source: GetStateError,
+    },
+    #[error("Timeout while waiting for container {deployment_name} to be {target}")]
+    Timeout {
+        deployment_name: String,
+        target: State,
+    },
+}
+
+impl<D: DockerInspectContainer> Client<D> {
+    /// Waits for the container of a deployment to reach a state, e.g. [`State::Exited`] after stopping it.
+    ///
+    /// The container is inspected every poll interval of the options until its state matches `target`, or until the deadline
+    /// or timeout of the options is reached. Without either in the options, the client default is used
+    /// (see [`Client::with_default_watch_timeout`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `deployment_name` - The name or ID of the container to watch
+    /// * `target` - The state to wait for
+    /// * `options` - Configuration options including timeout duration
+    pub async fn wait_for_state(
+        &self,
+        deployment_name: &str,
+        target: State,
+        options: WatchOptions,
+    ) -> Result<(), WatchStateError> {
+        let deadline = self.watch_deadline(&options);
+        let poll_interval = options.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+        time::timeout_at(
+            deadline,
+            self.wait_for_state_inner(deployment_name, target, poll_interval),
+        )
+        .await
+        .map_err(|_| WatchStateError::Timeout {
+            deployment_name: deployment_name.to_string(),
+            target,
+        })?
+    }
+
+    /// Waits for the container of a deployment to exit, see [`Client::wait_for_state`].
+    pub async fn wait_for_stopped(
+        &self,
+        deployment_name: &str,
+        options: WatchOptions,
+    ) -> Result<(), WatchStateError> {
+        self.wait_for_state(deployment_name, State::Exited, options)
+            .await
+    }
+
+    async fn wait_for_state_inner(
+        &self,
+        deployment_name: &str,
+        target: State,
+        poll_interval: time::Duration,
+    ) -> Result<(), WatchStateError> {
+        loop {
+            let container_inspect_response = self
+                .docker
+                .inspect_container(deployment_name, None::<InspectContainerOptions>)
+                .await?;
+
+            let state = State::try_from(&container_inspect_response).map_err(|source| {
+                WatchStateError::GetState {
+                    deployment_name: deployment_name.to_string(),
+                    source,
+                }
+            })?;
+            if state == target {
+                return Ok(());
+            }
+
+            time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ContainerInspectResponse, ContainerState, ContainerStateStatusEnum};
+    use mockall::{Sequence, mock};
+
+    mock! {
+        Docker {}
+
+        impl DockerInspectContainer for Docker {
+            async fn inspect_container(
+                &self,
+                container_id: &str,
+                options: Option<InspectContainerOptions>,
+            ) -> Result<ContainerInspectResponse, DockerError>;
+        }
+    }
+
+    fn container_inspect_response(status: ContainerStateStatusEnum) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                status: Some(status),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn options() -> WatchOptions {
+        WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(10))
+            .build()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_state_immediate_match() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .withf(|container_id, _| container_id == "test-deployment")
+            .times(1)
+            .returning(|_, _| Ok(container_inspect_response(ContainerStateStatusEnum::EXITED)));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_state("test-deployment", State::Exited, options())
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_stopped_after_retries() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response(
+                    ContainerStateStatusEnum::RUNNING,
+                ))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(container_inspect_response(ContainerStateStatusEnum::EXITED)));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client.wait_for_stopped("test-deployment", options()).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_state_timeout() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker.expect_inspect_container().returning(|_, _| {
+            Ok(container_inspect_response(
+                ContainerStateStatusEnum::RUNNING,
+            ))
+        });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_state("test-deployment", State::Paused, options())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchStateError::Timeout { deployment_name, target: State::Paused }
+                if deployment_name == "test-deployment"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_state_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_state("test-deployment", State::Exited, options())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchStateError::ContainerInspect(DockerError::NotFound)
+        ));
+    }
+}
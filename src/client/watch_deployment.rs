@@ -1,30 +1,72 @@
-use bollard::query_parameters::InspectContainerOptions;
-use tokio::time;
+use std::net::SocketAddr;
+
+use bollard::{models::Health, query_parameters::InspectContainerOptions};
+use futures_util::{Stream, future, stream};
+use tokio::{net::TcpStream, time};
 
 use crate::{
     client::Client,
     docker::{DockerError, DockerInspectContainer},
-    models::{ContainerHealthStatus, WatchOptions},
+    models::{ContainerHealthStatus, DeploymentStatusUpdate, GetStateError, State, WatchOptions},
 };
 
+/// How long to wait for a deployment to become healthy when no timeout is configured.
+pub const DEFAULT_WATCH_TIMEOUT: time::Duration = time::Duration::from_secs(60 * 10);
+
+/// Time between two inspections of a container when [`WatchOptions::poll_interval`] is not set.
+pub const DEFAULT_POLL_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
 #[derive(Debug, thiserror::Error)]
 pub enum WatchDeploymentError {
     #[error("Failed to inspect container: {0}")]
     ContainerInspect(#[from] DockerError),
     #[error("Timeout while waiting for container {deployment_name} to become healthy")]
     Timeout { deployment_name: String },
-    #[error("Deployment {deployment_name} is not healthy [status: {status}]")]
+    #[error(
+        "Deployment {deployment_name} is not healthy [status: {status}]{}",
+        .last_probe_output.as_deref().map(|output| format!(", last health check: {output}")).unwrap_or_default()
+    )]
     UnhealthyDeployment {
         deployment_name: String,
         status: ContainerHealthStatus,
+        /// Number of consecutive failed health checks, when the container has a health check.
+        failing_streak: Option<i64>,
+        /// Output of the most recent health check, e.g. why it failed.
+        last_probe_output: Option<String>,
+    },
+    #[error("No deployments to wait for")]
+    NoDeployments,
+    #[error("Timeout while waiting for {address} to accept connections")]
+    TcpConnectTimeout { address: SocketAddr },
+    #[error("Deployment {deployment_name} is still starting after {polls} polls")]
+    MaxPollsReached { deployment_name: String, polls: u32 },
+    #[error("Failed to get the state of container {deployment_name}: {source}")]
+    GetState {
+        deployment_name: String,
+        source: GetStateError,
     },
 }
 
+/// Polls a TCP connection to `address` until it succeeds or `timeout_duration` elapses.
+pub(crate) async fn wait_for_tcp_connect(
+    address: SocketAddr,
+    timeout_duration: time::Duration,
+) -> Result<(), WatchDeploymentError> {
+    time::timeout(timeout_duration, async {
+        while TcpStream::connect(address).await.is_err() {
+            time::sleep(time::Duration::from_millis(250)).await;
+        }
+    })
+    .await
+    .map_err(|_| WatchDeploymentError::TcpConnectTimeout { address })
+}
+
 impl<D: DockerInspectContainer> Client<D> {
     /// Waits for a deployment to become healthy.
     ///
     /// This method polls the container's health status until it becomes healthy,
-    /// or until the timeout specified in the options is reached.
+    /// or until the timeout specified in the options is reached. Without a timeout in the options,
+    /// the client default is used (see [`Client::with_default_watch_timeout`]).
     ///
     /// # Arguments
     ///
@@ -58,17 +100,170 @@ impl<D: DockerInspectContainer> Client<D> {
         deployment_name: &str,
         options: WatchOptions,
     ) -> Result<(), WatchDeploymentError> {
-        let timeout_duration = options
-            .timeout_duration
-            .unwrap_or(time::Duration::from_secs(60) * 10);
-        time::timeout(
-            timeout_duration,
-            self.wait_for_healthy_deployment_inner(deployment_name, options),
-        )
-        .await
-        .map_err(|_| WatchDeploymentError::Timeout {
-            deployment_name: deployment_name.to_string(),
-        })?
+        let deadline = self.watch_deadline(&options);
+        let wait = self.wait_for_healthy_deployment_inner(deployment_name, options);
+        #[cfg(feature = "tracing")]
+        let wait = tracing::Instrument::instrument(
+            wait,
+            tracing::debug_span!("wait_for_healthy_deployment", deployment = deployment_name),
+        );
+        time::timeout_at(deadline, wait)
+            .await
+            .map_err(|_| WatchDeploymentError::Timeout {
+                deployment_name: deployment_name.to_string(),
+            })?
+    }
+
+    /// Waits for a deployment to become healthy and returns how long it took.
+    ///
+    /// Behaves like [`Client::wait_for_healthy_deployment`], the elapsed time is measured
+    /// from the call until the deployment is reported healthy.
+    pub async fn wait_for_healthy_deployment_timed(
+        &self,
+        deployment_name: &str,
+        options: WatchOptions,
+    ) -> Result<time::Duration, WatchDeploymentError> {
+        let started_at = time::Instant::now();
+        self.wait_for_healthy_deployment(deployment_name, options)
+            .await?;
+        Ok(started_at.elapsed())
+    }
+
+    /// Waits for the first of several deployments to become healthy.
+    ///
+    /// All deployments are watched concurrently, as soon as one of them is healthy
+    /// the remaining waits are cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `deployment_names` - The names or IDs of the containers to watch
+    /// * `options` - Configuration options applied to every deployment
+    ///
+    /// # Returns
+    ///
+    /// Returns the name of the first deployment to become healthy, or the last error
+    /// if none of the deployments became healthy. An empty list fails with
+    /// [`WatchDeploymentError::NoDeployments`] because there is no deployment to return, unlike
+    /// [`Client::wait_for_all_healthy`] which succeeds for an empty list.
+    pub async fn wait_for_any_healthy(
+        &self,
+        deployment_names: Vec<String>,
+        options: WatchOptions,
+    ) -> Result<String, WatchDeploymentError> {
+        if deployment_names.is_empty() {
+            return Err(WatchDeploymentError::NoDeployments);
+        }
+
+        let waits = deployment_names.into_iter().map(|deployment_name| {
+            let options = options.clone();
+            Box::pin(async move {
+                self.wait_for_healthy_deployment(&deployment_name, options)
+                    .await
+                    .map(|_| deployment_name)
+            })
+        });
+
+        // Dropping the remaining futures cancels the other waits
+        let (deployment_name, _) = future::select_ok(waits).await?;
+        Ok(deployment_name)
+    }
+
+    /// Waits for every one of several deployments to become healthy.
+    ///
+    /// All deployments are watched concurrently, the first failure cancels the remaining waits.
+    ///
+    /// # Arguments
+    ///
+    /// * `deployment_names` - The names or IDs of the containers to watch
+    /// * `options` - Configuration options applied to every deployment
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once all deployments are healthy, or the first error
+    /// (including timeouts) reported by any of the deployments. An empty list succeeds right away,
+    /// all of no deployments are healthy, unlike [`Client::wait_for_any_healthy`] which fails with
+    /// [`WatchDeploymentError::NoDeployments`].
+    pub async fn wait_for_all_healthy(
+        &self,
+        deployment_names: Vec<String>,
+        options: WatchOptions,
+    ) -> Result<(), WatchDeploymentError> {
+        let waits = deployment_names.iter().map(|deployment_name| {
+            self.wait_for_healthy_deployment(deployment_name, options.clone())
+        });
+
+        future::try_join_all(waits).await?;
+        Ok(())
+    }
+
+    /// Watches the state and health of a deployment, e.g. to show it in a UI.
+    ///
+    /// The container is inspected every poll interval of the options, an update is yielded for the
+    /// first inspection and whenever the state or health changes. The stream ends after the container
+    /// exited or died, or with [`WatchDeploymentError::Timeout`] when the deadline or timeout of the
+    /// options is reached (see [`Client::wait_for_healthy_deployment`] for the defaults).
+    /// It also ends after yielding an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `deployment_name` - The name or ID of the container to watch
+    /// * `options` - Configuration options including the timeout and poll interval
+    pub fn watch_deployment_events<'a>(
+        &'a self,
+        deployment_name: &'a str,
+        options: WatchOptions,
+    ) -> impl Stream<Item = Result<DeploymentStatusUpdate, WatchDeploymentError>> + 'a {
+        let deadline = self.watch_deadline(&options);
+        let poll_interval = options.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+
+        // The last update and whether the container was inspected before, `None` once the stream ended
+        let initial: Option<(Option<DeploymentStatusUpdate>, bool)> = Some((None, false));
+        stream::unfold(initial, move |watch_state| async move {
+            let (last_update, mut polled) = watch_state?;
+            loop {
+                let sleep_first = polled;
+                polled = true;
+                let inspect = async move {
+                    if sleep_first {
+                        time::sleep(poll_interval).await;
+                    }
+                    self.docker
+                        .inspect_container(deployment_name, None::<InspectContainerOptions>)
+                        .await
+                };
+
+                let result = match time::timeout_at(deadline, inspect).await {
+                    Err(_) => Err(WatchDeploymentError::Timeout {
+                        deployment_name: deployment_name.to_string(),
+                    }),
+                    Ok(Err(err)) => Err(WatchDeploymentError::ContainerInspect(err)),
+                    Ok(Ok(container_inspect_response)) => {
+                        State::try_from(&container_inspect_response)
+                            .map(|state| DeploymentStatusUpdate {
+                                state,
+                                health: container_inspect_response
+                                    .state
+                                    .and_then(|s| s.health)
+                                    .and_then(|h| h.status)
+                                    .map(ContainerHealthStatus::from),
+                            })
+                            .map_err(|source| WatchDeploymentError::GetState {
+                                deployment_name: deployment_name.to_string(),
+                                source,
+                            })
+                    }
+                };
+
+                match result {
+                    Ok(update) if last_update.as_ref() == Some(&update) => continue,
+                    Ok(update) => {
+                        let next = (!update.is_terminal()).then(|| (Some(update.clone()), polled));
+                        return Some((Ok(update), next));
+                    }
+                    Err(err) => return Some((Err(err), None)),
+                }
+            }
+        })
     }
 
     async fn wait_for_healthy_deployment_inner(
@@ -76,20 +271,25 @@ impl<D: DockerInspectContainer> Client<D> {
         deployment_name: &str,
         options: WatchOptions,
     ) -> Result<(), WatchDeploymentError> {
+        let poll_interval = options.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+        let mut polls: u32 = 0;
+
         // Loop until the container is healthy
         loop {
-            let mut status: ContainerHealthStatus = self
+            polls = polls.saturating_add(1);
+            let health = self
                 .docker
                 .inspect_container(deployment_name, None::<InspectContainerOptions>)
                 .await
                 .map_err(WatchDeploymentError::ContainerInspect)?
                 .state
-                .and_then(|s| s.health)
+                .and_then(|s| s.health);
+            let mut status: ContainerHealthStatus = health
+                .as_ref()
                 .and_then(|h| h.status)
                 .map(ContainerHealthStatus::from)
-                .ok_or_else(|| WatchDeploymentError::UnhealthyDeployment {
-                    deployment_name: deployment_name.to_string(),
-                    status: ContainerHealthStatus::None,
+                .ok_or_else(|| {
+                    unhealthy_deployment(deployment_name, ContainerHealthStatus::None, None)
                 })?;
 
             // If allow_unhealthy_initial_state is set then we handle it as a starting state
@@ -100,31 +300,66 @@ impl<D: DockerInspectContainer> Client<D> {
             match status {
                 ContainerHealthStatus::Healthy => return Ok(()),
                 ContainerHealthStatus::Starting => {
-                    time::sleep(std::time::Duration::from_secs(1)).await;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(polls, "deployment is still starting");
+                    if options
+                        .max_polls
+                        .is_some_and(|max_polls| polls >= max_polls)
+                    {
+                        return Err(WatchDeploymentError::MaxPollsReached {
+                            deployment_name: deployment_name.to_string(),
+                            polls,
+                        });
+                    }
+                    time::sleep(poll_interval).await;
                 }
                 ContainerHealthStatus::None
                 | ContainerHealthStatus::Empty
                 | ContainerHealthStatus::Unhealthy => {
-                    return Err(WatchDeploymentError::UnhealthyDeployment {
-                        deployment_name: deployment_name.to_string(),
+                    return Err(unhealthy_deployment(
+                        deployment_name,
                         status,
-                    });
+                        health.as_ref(),
+                    ));
                 }
             }
         }
     }
 }
 
+/// Builds the error for a deployment that is not healthy, with the details of its health check.
+fn unhealthy_deployment(
+    deployment_name: &str,
+    status: ContainerHealthStatus,
+    health: Option<&Health>,
+) -> WatchDeploymentError {
+    // Docker keeps the most recent health checks, oldest first
+    let last_probe_output = health
+        .and_then(|health| health.log.as_ref())
+        .and_then(|log| log.last())
+        .and_then(|result| result.output.as_deref())
+        .map(|output| output.trim().to_string())
+        .filter(|output| !output.is_empty());
+
+    WatchDeploymentError::UnhealthyDeployment {
+        deployment_name: deployment_name.to_string(),
+        status,
+        failing_streak: health.and_then(|health| health.failing_streak),
+        last_probe_output,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::docker::DockerError;
     use bollard::models::{
         ContainerConfig, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
-        HealthStatusEnum,
+        HealthStatusEnum, HealthcheckResult,
     };
+    use futures_util::StreamExt;
     use maplit::hashmap;
-    use mockall::mock;
+    use mockall::{Sequence, mock};
     use pretty_assertions::assert_eq;
 
     mock! {
@@ -307,6 +542,72 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_wait_for_healthy_deployment_unhealthy_details() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder().build();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| {
+                let mut container_inspect_response =
+                    create_test_container_inspect_response_unhealthy();
+                if let Some(state) = container_inspect_response.state.as_mut() {
+                    state.health = Some(Health {
+                        status: Some(HealthStatusEnum::UNHEALTHY),
+                        failing_streak: Some(3),
+                        log: Some(vec![
+                            HealthcheckResult {
+                                exit_code: Some(1),
+                                output: Some("MongoServerSelectionError: timed out\n".to_string()),
+                                ..Default::default()
+                            },
+                            HealthcheckResult {
+                                exit_code: Some(1),
+                                output: Some(
+                                    "MongoServerSelectionError: connect ECONNREFUSED\n".to_string(),
+                                ),
+                                ..Default::default()
+                            },
+                        ]),
+                    });
+                }
+                Ok(container_inspect_response)
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment("test-deployment", options)
+            .await;
+
+        // Assert
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Deployment test-deployment is not healthy [status: unhealthy], last health check: MongoServerSelectionError: connect ECONNREFUSED"
+        );
+        match err {
+            WatchDeploymentError::UnhealthyDeployment {
+                status,
+                failing_streak,
+                last_probe_output,
+                ..
+            } => {
+                assert_eq!(status, ContainerHealthStatus::Unhealthy);
+                assert_eq!(failing_streak, Some(3));
+                assert_eq!(
+                    last_probe_output.as_deref(),
+                    Some("MongoServerSelectionError: connect ECONNREFUSED")
+                );
+            }
+            _ => panic!("Expected UnhealthyDeployment error"),
+        }
+    }
+
     #[tokio::test]
     async fn test_wait_for_healthy_deployment_retries() {
         // Arrange
@@ -342,6 +643,116 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_timed() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder().build();
+        let mut sequence = mockall::Sequence::new();
+
+        // Three polls report starting, each is followed by a one second sleep
+        mock_docker
+            .expect_inspect_container()
+            .times(3)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment_timed("test-deployment", options)
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), time::Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_timed_already_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment_timed("test-deployment", WatchOptions::builder().build())
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), time::Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_poll_interval() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder()
+            .poll_interval(time::Duration::from_millis(100))
+            .build();
+        let mut sequence = mockall::Sequence::new();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(3)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment_timed("test-deployment", options)
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), time::Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_max_polls() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder().max_polls(2).build();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment("test-deployment", options)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::MaxPollsReached { deployment_name, polls: 2 }
+                if deployment_name == "test-deployment"
+        ));
+    }
+
     #[tokio::test]
     async fn test_wait_for_healthy_deployment_timeout() {
         // Arrange
@@ -376,6 +787,128 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_watch_timeout_precedence() {
+        let client = Client::new(MockDocker::new());
+        let client_with_default = Client::new(MockDocker::new())
+            .with_default_watch_timeout(time::Duration::from_secs(30));
+
+        // Per-call timeout wins over the client default
+        assert_eq!(
+            client_with_default.watch_timeout(Some(time::Duration::from_secs(5))),
+            time::Duration::from_secs(5)
+        );
+        // Client default wins over the crate constant
+        assert_eq!(
+            client_with_default.watch_timeout(None),
+            time::Duration::from_secs(30)
+        );
+        // Crate constant is the last resort
+        assert_eq!(client.watch_timeout(None), DEFAULT_WATCH_TIMEOUT);
+        assert_eq!(
+            client.clone().watch_timeout(None),
+            time::Duration::from_secs(600)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_client_default_timeout() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let client =
+            Client::new(mock_docker).with_default_watch_timeout(time::Duration::from_secs(5));
+        let started_at = time::Instant::now();
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment("test-deployment", WatchOptions::builder().build())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::Timeout { .. }
+        ));
+        assert_eq!(started_at.elapsed(), time::Duration::from_secs(5));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_per_call_timeout_overrides_client_default() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let client =
+            Client::new(mock_docker).with_default_watch_timeout(time::Duration::from_secs(5));
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(2))
+            .build();
+        let started_at = time::Instant::now();
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment("test-deployment", options)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::Timeout { .. }
+        ));
+        assert_eq!(started_at.elapsed(), time::Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_healthy_deployment_deadline_overrides_timeout() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let client = Client::new(mock_docker);
+        let started_at = time::Instant::now();
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(10))
+            .deadline(started_at + time::Duration::from_secs(3))
+            .build();
+
+        // Act
+        let result = client
+            .wait_for_healthy_deployment("test-deployment", options)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::Timeout { .. }
+        ));
+        assert_eq!(started_at.elapsed(), time::Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_watch_deadline_falls_back_to_timeout() {
+        let client = Client::new(MockDocker::new());
+        let deadline = time::Instant::now() + time::Duration::from_secs(1);
+
+        let with_deadline = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(60))
+            .deadline(deadline)
+            .build();
+        assert_eq!(client.watch_deadline(&with_deadline), deadline);
+
+        let without_deadline = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(60))
+            .build();
+        assert!(client.watch_deadline(&without_deadline) > deadline);
+    }
+
     #[tokio::test]
     async fn test_wait_for_healthy_deployment_no_state() {
         // Arrange
@@ -404,9 +937,13 @@ mod tests {
             WatchDeploymentError::UnhealthyDeployment {
                 deployment_name,
                 status,
+                failing_streak,
+                last_probe_output,
             } => {
                 assert_eq!(deployment_name, "test-deployment");
                 assert_eq!(status, ContainerHealthStatus::None);
+                assert_eq!(failing_streak, None);
+                assert_eq!(last_probe_output, None);
             }
             _ => panic!("Expected UnhealthyDeployment error"),
         }
@@ -440,9 +977,13 @@ mod tests {
             WatchDeploymentError::UnhealthyDeployment {
                 deployment_name,
                 status,
+                failing_streak,
+                last_probe_output,
             } => {
                 assert_eq!(deployment_name, "test-deployment");
                 assert_eq!(status, ContainerHealthStatus::None);
+                assert_eq!(failing_streak, None);
+                assert_eq!(last_probe_output, None);
             }
             _ => panic!("Expected UnhealthyDeployment error"),
         }
@@ -477,4 +1018,407 @@ mod tests {
             WatchDeploymentError::ContainerInspect(_)
         ));
     }
+
+    async fn unused_local_address() -> SocketAddr {
+        // Bind to a random port and release it, nothing listens on it afterwards
+        tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    }
+
+    fn container_inspect_response_with_status(
+        status: ContainerStateStatusEnum,
+        health: Option<HealthStatusEnum>,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            state: Some(ContainerState {
+                status: Some(status),
+                health: health.map(|status| Health {
+                    status: Some(status),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_deployment_events_starting_to_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response_with_status(
+                    ContainerStateStatusEnum::RUNNING,
+                    Some(HealthStatusEnum::STARTING),
+                ))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response_with_status(
+                    ContainerStateStatusEnum::RUNNING,
+                    Some(HealthStatusEnum::HEALTHY),
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(10))
+            .build();
+
+        // Act
+        let updates = client
+            .watch_deployment_events("test-deployment", options)
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(updates.len(), 3);
+        assert_eq!(
+            updates[0].as_ref().unwrap(),
+            &DeploymentStatusUpdate {
+                state: State::Running,
+                health: Some(ContainerHealthStatus::Starting),
+            }
+        );
+        assert_eq!(
+            updates[1].as_ref().unwrap(),
+            &DeploymentStatusUpdate {
+                state: State::Running,
+                health: Some(ContainerHealthStatus::Healthy),
+            }
+        );
+        assert!(matches!(
+            updates[2],
+            Err(WatchDeploymentError::Timeout { .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watch_deployment_events_ends_when_exited() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let mut sequence = Sequence::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response_with_status(
+                    ContainerStateStatusEnum::RUNNING,
+                    None,
+                ))
+            });
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(container_inspect_response_with_status(
+                    ContainerStateStatusEnum::EXITED,
+                    None,
+                ))
+            });
+
+        let client = Client::new(mock_docker);
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(10))
+            .build();
+
+        // Act
+        let updates = client
+            .watch_deployment_events("test-deployment", options)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        let states = updates
+            .iter()
+            .map(|update| update.state)
+            .collect::<Vec<_>>();
+        assert_eq!(states, vec![State::Running, State::Exited]);
+        assert!(updates[1].is_terminal());
+    }
+
+    #[tokio::test]
+    async fn test_watch_deployment_events_inspect_error() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        mock_docker
+            .expect_inspect_container()
+            .times(1)
+            .returning(|_, _| Err(DockerError::NotFound));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let updates = client
+            .watch_deployment_events("test-deployment", WatchOptions::builder().build())
+            .collect::<Vec<_>>()
+            .await;
+
+        // Assert
+        assert_eq!(updates.len(), 1);
+        assert!(matches!(
+            updates[0],
+            Err(WatchDeploymentError::ContainerInspect(
+                DockerError::NotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_tcp_connect_listening() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let result = wait_for_tcp_connect(address, time::Duration::from_secs(5)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_tcp_connect_port_comes_up() {
+        let address = unused_local_address().await;
+
+        // Start listening only after a few connection attempts have been refused
+        let listener_task = tokio::spawn(async move {
+            time::sleep(time::Duration::from_millis(600)).await;
+            let listener = tokio::net::TcpListener::bind(address).await.unwrap();
+            listener.accept().await.unwrap();
+        });
+
+        let result = wait_for_tcp_connect(address, time::Duration::from_secs(5)).await;
+
+        assert!(result.is_ok());
+        listener_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_tcp_connect_timeout() {
+        let address = unused_local_address().await;
+
+        let result = wait_for_tcp_connect(address, time::Duration::from_millis(300)).await;
+
+        match result.unwrap_err() {
+            WatchDeploymentError::TcpConnectTimeout { address: a } => assert_eq!(a, address),
+            other => panic!("Expected TcpConnectTimeout error, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_any_healthy_second_becomes_healthy_first() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_secs(10))
+            .build();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::in_iter(["deployment-1", "deployment-3"]),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let deployment_2_polls = std::sync::atomic::AtomicUsize::new(0);
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("deployment-2"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .returning(move |_, _| {
+                // Starting on the first poll, healthy on the second
+                if deployment_2_polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    Ok(create_test_container_inspect_response_starting())
+                } else {
+                    Ok(create_test_container_inspect_response())
+                }
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_any_healthy(
+                vec![
+                    "deployment-1".to_string(),
+                    "deployment-2".to_string(),
+                    "deployment-3".to_string(),
+                ],
+                options,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), "deployment-2");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_any_healthy_none_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder().build();
+
+        mock_docker
+            .expect_inspect_container()
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response_unhealthy()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_any_healthy(
+                vec!["deployment-1".to_string(), "deployment-2".to_string()],
+                options,
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::UnhealthyDeployment { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_any_healthy_no_deployments() {
+        // Arrange
+        let mock_docker = MockDocker::new();
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_any_healthy(vec![], WatchOptions::builder().build())
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result.unwrap_err(),
+            WatchDeploymentError::NoDeployments
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_healthy() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder().build();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::in_iter(["deployment-1", "deployment-3"]),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(2)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        let deployment_2_polls = std::sync::atomic::AtomicUsize::new(0);
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("deployment-2"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(2)
+            .returning(move |_, _| {
+                // Starting on the first poll, healthy on the second
+                if deployment_2_polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    Ok(create_test_container_inspect_response_starting())
+                } else {
+                    Ok(create_test_container_inspect_response())
+                }
+            });
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_all_healthy(
+                vec![
+                    "deployment-1".to_string(),
+                    "deployment-2".to_string(),
+                    "deployment-3".to_string(),
+                ],
+                options,
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_healthy_one_times_out() {
+        // Arrange
+        let mut mock_docker = MockDocker::new();
+        let options = WatchOptions::builder()
+            .timeout_duration(time::Duration::from_millis(100))
+            .build();
+
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("deployment-1"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .times(1)
+            .returning(|_, _| Ok(create_test_container_inspect_response()));
+
+        // Never leaves the starting state, so this deployment times out
+        mock_docker
+            .expect_inspect_container()
+            .with(
+                mockall::predicate::eq("deployment-2"),
+                mockall::predicate::eq(None::<InspectContainerOptions>),
+            )
+            .returning(|_, _| Ok(create_test_container_inspect_response_starting()));
+
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_all_healthy(
+                vec!["deployment-1".to_string(), "deployment-2".to_string()],
+                options,
+            )
+            .await;
+
+        // Assert
+        match result.unwrap_err() {
+            WatchDeploymentError::Timeout { deployment_name } => {
+                assert_eq!(deployment_name, "deployment-2");
+            }
+            _ => panic!("Expected Timeout error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_healthy_no_deployments() {
+        // Arrange
+        let mock_docker = MockDocker::new();
+        let client = Client::new(mock_docker);
+
+        // Act
+        let result = client
+            .wait_for_all_healthy(vec![], WatchOptions::builder().build())
+            .await;
+
+        // Assert, unlike wait_for_any_healthy an empty list is not an error
+        assert!(result.is_ok());
+    }
 }
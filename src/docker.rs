@@ -1,19 +1,23 @@
 use bollard::{
-    Docker,
+    ClientVersion, Docker,
     container::LogOutput,
     exec::{CreateExecOptions, StartExecOptions, StartExecResults},
     models::{
-        ContainerCreateBody, ContainerCreateResponse, ContainerInspectResponse, ContainerSummary,
+        ContainerCreateBody, ContainerCreateResponse, ContainerInspectResponse,
+        ContainerStatsResponse, ContainerSummary, ContainerTopResponse, CreateImageInfo,
+        EventMessage, ImageInspect, SystemDataUsageResponse, SystemVersion, Volume,
+        VolumeCreateRequest, VolumeListResponse,
     },
     query_parameters::{
-        CreateContainerOptions, CreateImageOptionsBuilder, InspectContainerOptions,
-        ListContainersOptions, LogsOptions, RemoveContainerOptions, StartContainerOptions,
-        StopContainerOptions,
+        CreateContainerOptions, CreateImageOptionsBuilder, DataUsageOptions, EventsOptions,
+        InspectContainerOptions, ListContainersOptions, ListVolumesOptions, LogsOptions,
+        RemoveContainerOptions, RemoveVolumeOptions, RenameContainerOptionsBuilder,
+        StartContainerOptions, StatsOptionsBuilder, StopContainerOptions,
     },
 };
 use futures_util::{Stream, StreamExt, TryStreamExt};
 
-use crate::models::ContainerHealthStatus;
+use crate::models::{ApiVersion, ContainerHealthStatus};
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum DockerError {
@@ -31,6 +35,10 @@ pub enum DockerError {
     Conflict,
     #[error("internal server error")]
     ServerError,
+    #[error(
+        "Docker daemon is not reachable ({message}), make sure Docker (or Docker Desktop) is installed and running"
+    )]
+    DaemonUnavailable { message: String },
     #[error("docker error (status {status_code:?}): {message}")]
     Other {
         status_code: Option<u16>,
@@ -57,6 +65,9 @@ impl From<bollard::errors::Error> for DockerError {
                     message,
                 },
             },
+            _ if is_daemon_unavailable(&err) => DockerError::DaemonUnavailable {
+                message: err.to_string(),
+            },
             _ => DockerError::Other {
                 status_code: None,
                 message: err.to_string(),
@@ -65,6 +76,33 @@ impl From<bollard::errors::Error> for DockerError {
     }
 }
 
+/// Returns true when the error means the Docker daemon could not be reached at all,
+/// as opposed to the daemon answering with an error.
+fn is_daemon_unavailable(err: &bollard::errors::Error) -> bool {
+    match err {
+        bollard::errors::Error::SocketNotFoundError(_) => true,
+        bollard::errors::Error::IOError { err } => is_connection_io_error(err),
+        bollard::errors::Error::HyperLegacyError { err } => {
+            err.is_connect()
+                || std::error::Error::source(err)
+                    .and_then(|source| source.downcast_ref::<std::io::Error>())
+                    .is_some_and(is_connection_io_error)
+        }
+        _ => false,
+    }
+}
+
+fn is_connection_io_error(err: &std::io::Error) -> bool {
+    matches!(
+        err.kind(),
+        std::io::ErrorKind::ConnectionRefused
+            | std::io::ErrorKind::ConnectionReset
+            | std::io::ErrorKind::ConnectionAborted
+            | std::io::ErrorKind::NotConnected
+            | std::io::ErrorKind::NotFound
+    )
+}
+
 impl From<bollard::models::HealthStatusEnum> for ContainerHealthStatus {
     fn from(status: bollard::models::HealthStatusEnum) -> Self {
         match status {
@@ -116,27 +154,91 @@ impl DockerListContainers for Docker {
 }
 
 pub trait DockerPullImage {
-    fn pull_image(
-        &self,
-        image: &str,
-        tag: &str,
-    ) -> impl Future<Output = Result<(), DockerError>> + Send;
+    fn pull_image<'a>(
+        &'a self,
+        image: &'a str,
+        tag: &'a str,
+    ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send + 'a;
 }
 
 impl DockerPullImage for Docker {
-    async fn pull_image(&self, image: &str, tag: &str) -> Result<(), DockerError> {
+    fn pull_image<'a>(
+        &'a self,
+        image: &'a str,
+        tag: &'a str,
+    ) -> impl Stream<Item = Result<CreateImageInfo, DockerError>> + Send + 'a {
         let create_image_options = CreateImageOptionsBuilder::default()
             .from_image(image)
             .tag(tag)
             .build();
 
-        let mut stream = self.create_image(Some(create_image_options), None, None);
+        self.create_image(Some(create_image_options), None, None)
+            .map_err(DockerError::from)
+    }
+}
 
-        while let Some(result) = stream.next().await {
-            result.map_err(DockerError::from)?;
-        }
+pub trait DockerInspectImage {
+    fn inspect_image(
+        &self,
+        image: &str,
+    ) -> impl Future<Output = Result<ImageInspect, DockerError>> + Send;
+}
+
+impl DockerInspectImage for Docker {
+    async fn inspect_image(&self, image: &str) -> Result<ImageInspect, DockerError> {
+        self.inspect_image(image).await.map_err(DockerError::from)
+    }
+}
+
+pub trait DockerDiskUsage {
+    /// Gets the disk usage of the containers, images and volumes, including the individual items.
+    fn disk_usage(
+        &self,
+    ) -> impl Future<Output = Result<SystemDataUsageResponse, DockerError>> + Send;
+}
+
+impl DockerDiskUsage for Docker {
+    async fn disk_usage(&self) -> Result<SystemDataUsageResponse, DockerError> {
+        let options = DataUsageOptions {
+            verbose: true,
+            ..Default::default()
+        };
+        self.df(Some(options)).await.map_err(DockerError::from)
+    }
+}
+
+pub trait DockerNegotiateVersion {
+    /// Gets the version of the Docker daemon, including the API versions it supports.
+    fn version(&self) -> impl Future<Output = Result<SystemVersion, DockerError>> + Send;
+
+    /// Returns the API version requests are made with.
+    fn api_version(&self) -> ApiVersion;
+
+    /// Lowers the API version requests are made with to the one of the daemon, when the daemon is older.
+    fn negotiate_version(&self) -> impl Future<Output = Result<(), DockerError>> + Send;
+}
+
+impl DockerNegotiateVersion for Docker {
+    async fn version(&self) -> Result<SystemVersion, DockerError> {
+        Docker::version(self).await.map_err(DockerError::from)
+    }
+
+    fn api_version(&self) -> ApiVersion {
+        ApiVersion::from(self.client_version())
+    }
+
+    async fn negotiate_version(&self) -> Result<(), DockerError> {
+        // Clones share the API version, negotiating on a clone updates this client too
+        Docker::negotiate_version(self.clone())
+            .await
+            .map(|_| ())
+            .map_err(DockerError::from)
+    }
+}
 
-        Ok(())
+impl From<ClientVersion> for ApiVersion {
+    fn from(version: ClientVersion) -> Self {
+        ApiVersion::new(version.major_version, version.minor_version)
     }
 }
 
@@ -180,6 +282,55 @@ impl DockerRemoveContainer for Docker {
     }
 }
 
+pub trait DockerListVolumes {
+    fn list_volumes(
+        &self,
+        options: Option<ListVolumesOptions>,
+    ) -> impl Future<Output = Result<VolumeListResponse, DockerError>> + Send;
+}
+
+impl DockerListVolumes for Docker {
+    async fn list_volumes(
+        &self,
+        options: Option<ListVolumesOptions>,
+    ) -> Result<VolumeListResponse, DockerError> {
+        self.list_volumes(options).await.map_err(DockerError::from)
+    }
+}
+
+pub trait DockerRemoveVolume {
+    fn remove_volume(
+        &self,
+        volume_name: &str,
+        options: Option<RemoveVolumeOptions>,
+    ) -> impl Future<Output = Result<(), DockerError>> + Send;
+}
+
+impl DockerRemoveVolume for Docker {
+    async fn remove_volume(
+        &self,
+        volume_name: &str,
+        options: Option<RemoveVolumeOptions>,
+    ) -> Result<(), DockerError> {
+        self.remove_volume(volume_name, options)
+            .await
+            .map_err(DockerError::from)
+    }
+}
+
+pub trait DockerCreateVolume {
+    fn create_volume(
+        &self,
+        config: VolumeCreateRequest,
+    ) -> impl Future<Output = Result<Volume, DockerError>> + Send;
+}
+
+impl DockerCreateVolume for Docker {
+    async fn create_volume(&self, config: VolumeCreateRequest) -> Result<Volume, DockerError> {
+        self.create_volume(config).await.map_err(DockerError::from)
+    }
+}
+
 pub trait DockerCreateContainer {
     fn create_container(
         &self,
@@ -250,6 +401,86 @@ impl DockerUnpauseContainer for Docker {
     }
 }
 
+pub trait DockerRenameContainer {
+    fn rename_container(
+        &self,
+        container_id: &str,
+        new_name: &str,
+    ) -> impl Future<Output = Result<(), DockerError>> + Send;
+}
+
+impl DockerRenameContainer for Docker {
+    async fn rename_container(
+        &self,
+        container_id: &str,
+        new_name: &str,
+    ) -> Result<(), DockerError> {
+        let rename_container_options = RenameContainerOptionsBuilder::default()
+            .name(new_name)
+            .build();
+
+        self.rename_container(container_id, rename_container_options)
+            .await
+            .map_err(DockerError::from)
+    }
+}
+
+pub trait DockerEvents {
+    /// Streams the events of the Docker daemon as they happen, matching the filters of `options`.
+    fn events<'a>(
+        &'a self,
+        options: Option<EventsOptions>,
+    ) -> impl Stream<Item = Result<EventMessage, DockerError>> + Send + 'a;
+}
+
+impl DockerEvents for Docker {
+    fn events<'a>(
+        &'a self,
+        options: Option<EventsOptions>,
+    ) -> impl Stream<Item = Result<EventMessage, DockerError>> + Send + 'a {
+        self.events(options).map_err(DockerError::from)
+    }
+}
+
+pub trait DockerTopContainer {
+    fn top_processes(
+        &self,
+        container_id: &str,
+    ) -> impl Future<Output = Result<ContainerTopResponse, DockerError>> + Send;
+}
+
+impl DockerTopContainer for Docker {
+    async fn top_processes(&self, container_id: &str) -> Result<ContainerTopResponse, DockerError> {
+        self.top_processes(container_id, None)
+            .await
+            .map_err(DockerError::from)
+    }
+}
+
+pub trait DockerStats {
+    /// Gets a single sample of the resource usage of a container, including the previous CPU sample.
+    fn stats(
+        &self,
+        container_id: &str,
+    ) -> impl Future<Output = Result<ContainerStatsResponse, DockerError>> + Send;
+}
+
+impl DockerStats for Docker {
+    async fn stats(&self, container_id: &str) -> Result<ContainerStatsResponse, DockerError> {
+        // Not using one-shot, Docker then waits for a second sample so the CPU usage can be computed
+        let stats_options = StatsOptionsBuilder::default().stream(false).build();
+
+        let mut stats = Box::pin(self.stats(container_id, Some(stats_options)));
+        match stats.next().await {
+            Some(result) => result.map_err(DockerError::from),
+            None => Err(DockerError::Other {
+                status_code: None,
+                message: "no stats reported".to_string(),
+            }),
+        }
+    }
+}
+
 pub trait RunCommandInContainer {
     fn run_command_in_container(
         &self,
@@ -258,9 +489,13 @@ pub trait RunCommandInContainer {
     ) -> impl Future<Output = Result<CommandOutput, RunCommandInContainerError>> + Send;
 }
 
+/// The output of a command run in a container, split into lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommandOutput {
     pub stdout: Vec<String>,
     pub stderr: Vec<String>,
+    /// The exit code of the command, `None` when Docker did not report one.
+    pub exit_code: Option<i64>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -273,6 +508,11 @@ pub enum RunCommandInContainerError {
     GetOutput,
     #[error("Failed to get output: {0}")]
     GetOutputError(DockerError),
+    #[error("Failed to inspect exec: {0}")]
+    InspectExec(DockerError),
+    /// A secret file referenced by a `*_FILE` environment variable is missing or not readable in the container.
+    #[error("Secret file {path} cannot be read in the container")]
+    SecretFileUnreadable { path: String },
 }
 
 impl RunCommandInContainer for Docker {
@@ -294,7 +534,7 @@ impl RunCommandInContainer for Docker {
             .await
             .map_err(|e| RunCommandInContainerError::CreateExec(DockerError::from(e)))?;
 
-        let exec = self
+        let started_exec = self
             .start_exec(
                 &exec.id,
                 Some(StartExecOptions {
@@ -306,7 +546,7 @@ impl RunCommandInContainer for Docker {
             .await
             .map_err(|e| RunCommandInContainerError::StartExec(DockerError::from(e)))?;
 
-        let StartExecResults::Attached { mut output, .. } = exec else {
+        let StartExecResults::Attached { mut output, .. } = started_exec else {
             return Err(RunCommandInContainerError::GetOutput);
         };
 
@@ -328,9 +568,17 @@ impl RunCommandInContainer for Docker {
             }
         }
 
+        // The exit code is known once the output is drained
+        let exit_code = self
+            .inspect_exec(&exec.id)
+            .await
+            .map_err(|e| RunCommandInContainerError::InspectExec(DockerError::from(e)))?
+            .exit_code;
+
         Ok(CommandOutput {
             stdout: stdout.lines().map(str::to_string).collect(),
             stderr: stderr.lines().map(str::to_string).collect(),
+            exit_code,
         })
     }
 }
@@ -430,6 +678,79 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_docker_error_from_bollard_connection_refused() {
+        let err = bollard::errors::Error::IOError {
+            err: std::io::Error::from(std::io::ErrorKind::ConnectionRefused),
+        };
+        assert!(matches!(
+            DockerError::from(err),
+            DockerError::DaemonUnavailable { .. }
+        ));
+    }
+
+    #[test]
+    fn test_docker_error_from_bollard_socket_not_found() {
+        let err = bollard::errors::Error::SocketNotFoundError("/var/run/docker.sock".to_string());
+        let result = DockerError::from(err);
+        assert!(matches!(result, DockerError::DaemonUnavailable { .. }));
+        assert!(result.to_string().contains("/var/run/docker.sock"));
+        assert!(result.to_string().contains("installed and running"));
+    }
+
+    #[test]
+    fn test_docker_error_from_bollard_other_io_error() {
+        let err = bollard::errors::Error::IOError {
+            err: std::io::Error::from(std::io::ErrorKind::InvalidData),
+        };
+        assert!(matches!(
+            DockerError::from(err),
+            DockerError::Other {
+                status_code: None,
+                ..
+            }
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_docker_error_from_refused_connection() {
+        // Leave a socket file behind without a listener, so connecting to it is refused
+        let socket_path =
+            std::env::temp_dir().join(format!("atlas-local-refused-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        drop(std::os::unix::net::UnixListener::bind(&socket_path).unwrap());
+
+        let docker = Docker::connect_with_unix(
+            socket_path.to_str().unwrap(),
+            1,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let err = docker.ping().await.unwrap_err();
+        let _ = std::fs::remove_file(&socket_path);
+
+        assert!(matches!(
+            DockerError::from(err),
+            DockerError::DaemonUnavailable { .. }
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_docker_error_from_missing_socket() {
+        let err = Docker::connect_with_unix(
+            "/nonexistent/atlas-local/docker.sock",
+            1,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            DockerError::from(err),
+            DockerError::DaemonUnavailable { .. }
+        ));
+    }
+
     #[test]
     fn test_docker_error_display() {
         assert_eq!(
@@ -497,19 +818,20 @@ mod tests {
 }
 
 pub trait DockerLogContainer {
+    /// The stream may borrow the client but not `container_id`, so callers can pass a resolved ID they own.
     fn logs<'a>(
         &'a self,
-        container_id: &'a str,
+        container_id: &str,
         options: Option<LogsOptions>,
-    ) -> impl Stream<Item = Result<LogOutput, String>> + 'a;
+    ) -> impl Stream<Item = Result<LogOutput, String>> + use<'a, Self>;
 }
 
 impl DockerLogContainer for Docker {
     fn logs<'a>(
         &'a self,
-        container_id: &'a str,
+        container_id: &str,
         options: Option<LogsOptions>,
-    ) -> impl Stream<Item = Result<LogOutput, String>> + 'a {
+    ) -> impl Stream<Item = Result<LogOutput, String>> + use<'a> {
         self.logs(container_id, options).map_err(|e| e.to_string())
     }
 }
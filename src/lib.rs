@@ -6,6 +6,7 @@
 pub mod client;
 pub mod docker;
 pub mod models;
+pub mod mongodb_client;
 
 #[cfg(test)]
 pub mod test_utils;
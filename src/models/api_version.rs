@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter};
+
+/// Oldest Docker API version supported, the version of Docker 20.10.
+pub const MINIMUM_API_VERSION: ApiVersion = ApiVersion {
+    major: 1,
+    minor: 41,
+};
+
+/// A Docker Engine API version, e.g. `1.43`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersion {
+    pub major: usize,
+    pub minor: usize,
+}
+
+impl ApiVersion {
+    pub const fn new(major: usize, minor: usize) -> Self {
+        ApiVersion { major, minor }
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Invalid Docker API version: \"{0}\", expected <major>.<minor>")]
+pub struct ParseApiVersionError(pub String);
+
+impl TryFrom<&str> for ApiVersion {
+    type Error = ParseApiVersionError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (major, minor) = s
+            .split_once('.')
+            .ok_or_else(|| ParseApiVersionError(s.to_string()))?;
+
+        Ok(ApiVersion {
+            major: major
+                .parse()
+                .map_err(|_| ParseApiVersionError(s.to_string()))?,
+            minor: minor
+                .parse()
+                .map_err(|_| ParseApiVersionError(s.to_string()))?,
+        })
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_version_try_from() {
+        assert_eq!(ApiVersion::try_from("1.43"), Ok(ApiVersion::new(1, 43)));
+        assert_eq!(
+            ApiVersion::try_from("1"),
+            Err(ParseApiVersionError("1".to_string()))
+        );
+        assert_eq!(
+            ApiVersion::try_from("1.x"),
+            Err(ParseApiVersionError("1.x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_api_version_ordering() {
+        assert!(ApiVersion::new(1, 9) < ApiVersion::new(1, 41));
+        assert!(ApiVersion::new(2, 0) > ApiVersion::new(1, 52));
+    }
+
+    #[test]
+    fn test_api_version_display() {
+        assert_eq!(ApiVersion::new(1, 41).to_string(), "1.41");
+    }
+}
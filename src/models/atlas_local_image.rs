@@ -0,0 +1,201 @@
+use std::fmt::{Display, Formatter};
+
+use crate::models::{ATLAS_LOCAL_IMAGE, ImageTag};
+
+const DIGEST_PREFIX: &str = "sha256:";
+const DIGEST_ERROR: &str = "Invalid image digest: expected 'sha256:' followed by 64 hex characters";
+
+/// A reference to an Atlas Local image: repository, tag and optionally a digest to pin the exact image.
+///
+/// The default is the latest Atlas Local image.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::AtlasLocalImage;
+///
+/// let image = AtlasLocalImage::try_from(
+///     "quay.io/mongodb/mongodb-atlas-local:8.0.4@sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+/// )
+/// .unwrap();
+/// assert_eq!(image.repository, "quay.io/mongodb/mongodb-atlas-local");
+/// assert_eq!(image.tag.to_string(), "8.0.4");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtlasLocalImage {
+    /// The repository, including the registry, e.g. `quay.io/mongodb/mongodb-atlas-local`.
+    pub repository: String,
+    /// The tag, only informational when a digest is set.
+    pub tag: ImageTag,
+    /// The digest the image is pinned to, e.g. `sha256:9f86…`. Docker uses the digest over the tag.
+    pub digest: Option<String>,
+}
+
+impl AtlasLocalImage {
+    /// The latest image of `repository`.
+    pub fn new(repository: impl Into<String>) -> Self {
+        AtlasLocalImage {
+            repository: repository.into(),
+            tag: ImageTag::default(),
+            digest: None,
+        }
+    }
+
+    /// Uses `tag` instead of the current tag.
+    pub fn with_tag(mut self, tag: ImageTag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Pins the image to `digest`, e.g. `sha256:9f86…`.
+    pub fn with_digest(mut self, digest: impl Into<String>) -> Result<Self, String> {
+        let digest = digest.into();
+        validate_digest(&digest)?;
+        self.digest = Some(digest);
+        Ok(self)
+    }
+
+    /// The tag or digest to pull, the digest when the image is pinned.
+    pub(crate) fn pull_tag(&self) -> String {
+        self.digest.clone().unwrap_or_else(|| self.tag.to_string())
+    }
+}
+
+impl Default for AtlasLocalImage {
+    fn default() -> Self {
+        AtlasLocalImage::new(ATLAS_LOCAL_IMAGE)
+    }
+}
+
+fn validate_digest(digest: &str) -> Result<(), String> {
+    match digest.strip_prefix(DIGEST_PREFIX) {
+        Some(hex) if hex.len() == 64 && hex.bytes().all(|c| c.is_ascii_hexdigit()) => Ok(()),
+        _ => Err(DIGEST_ERROR.to_string()),
+    }
+}
+
+impl TryFrom<&str> for AtlasLocalImage {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let s = s.trim();
+        let (reference, digest) = match s.split_once('@') {
+            Some((reference, digest)) => {
+                validate_digest(digest)?;
+                (reference, Some(digest.to_string()))
+            }
+            None => (s, None),
+        };
+
+        // A colon before the last slash belongs to a registry port, e.g. `localhost:5000/image`
+        let (repository, tag) = match reference.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (
+                repository,
+                ImageTag::try_from(tag).unwrap_or_else(|_| ImageTag::Custom(tag.to_string())),
+            ),
+            _ => (reference, ImageTag::default()),
+        };
+        if repository.is_empty() {
+            return Err("Invalid image reference: missing repository".to_string());
+        }
+
+        Ok(AtlasLocalImage {
+            repository: repository.to_string(),
+            tag,
+            digest,
+        })
+    }
+}
+
+impl Display for AtlasLocalImage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.repository, self.tag)?;
+        if let Some(digest) = &self.digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    #[test]
+    fn tag_only() {
+        let image =
+            AtlasLocalImage::try_from("quay.io/mongodb/mongodb-atlas-local:preview").unwrap();
+        assert_eq!(image.repository, "quay.io/mongodb/mongodb-atlas-local");
+        assert_eq!(image.tag, ImageTag::Preview);
+        assert_eq!(image.digest, None);
+        assert_eq!(image.pull_tag(), "preview");
+        assert_eq!(
+            image.to_string(),
+            "quay.io/mongodb/mongodb-atlas-local:preview"
+        );
+    }
+
+    #[test]
+    fn digest_pinned() {
+        let reference = format!("quay.io/mongodb/mongodb-atlas-local:8.0.4@{DIGEST}");
+        let image = AtlasLocalImage::try_from(reference.as_str()).unwrap();
+        assert_eq!(image.repository, "quay.io/mongodb/mongodb-atlas-local");
+        assert_eq!(image.tag.to_string(), "8.0.4");
+        assert_eq!(image.digest.as_deref(), Some(DIGEST));
+        assert_eq!(image.pull_tag(), DIGEST);
+        assert_eq!(image.to_string(), reference);
+    }
+
+    #[test]
+    fn digest_without_tag() {
+        let image =
+            AtlasLocalImage::try_from(format!("mongodb/mongodb-atlas-local@{DIGEST}").as_str())
+                .unwrap();
+        assert_eq!(image.tag, ImageTag::Latest);
+        assert_eq!(
+            image.to_string(),
+            format!("mongodb/mongodb-atlas-local:latest@{DIGEST}")
+        );
+    }
+
+    #[test]
+    fn no_tag() {
+        let image = AtlasLocalImage::try_from("localhost:5000/atlas-local").unwrap();
+        assert_eq!(image.repository, "localhost:5000/atlas-local");
+        assert_eq!(image.tag, ImageTag::Latest);
+    }
+
+    #[test]
+    fn custom_tag() {
+        let image = AtlasLocalImage::try_from("localhost:5000/atlas-local:8.0-nightly").unwrap();
+        assert_eq!(image.tag, ImageTag::Custom("8.0-nightly".to_string()));
+        assert_eq!(image.to_string(), "localhost:5000/atlas-local:8.0-nightly");
+    }
+
+    #[test]
+    fn invalid_digest() {
+        assert!(AtlasLocalImage::try_from("mongodb/mongodb-atlas-local@sha256:abc").is_err());
+        assert!(AtlasLocalImage::try_from("mongodb/mongodb-atlas-local@md5:abc").is_err());
+        assert!(
+            AtlasLocalImage::default()
+                .with_digest("sha256:xyz")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn missing_repository() {
+        assert!(AtlasLocalImage::try_from(":latest").is_err());
+    }
+
+    #[test]
+    fn default_is_latest_atlas_local() {
+        assert_eq!(
+            AtlasLocalImage::default().to_string(),
+            "quay.io/mongodb/mongodb-atlas-local:latest"
+        );
+    }
+}
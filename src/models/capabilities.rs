@@ -0,0 +1,32 @@
+use crate::models::ApiVersion;
+
+/// What a [`crate::Client`] supports, see [`crate::Client::capabilities`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The `serde` feature is enabled, models can be serialized.
+    pub serde: bool,
+    /// The `bollard` feature is enabled, clients can connect to Docker themselves.
+    pub bollard: bool,
+    /// The `tracing` feature is enabled, client operations emit `tracing` spans and events.
+    pub tracing: bool,
+    /// The `mongodb` feature is enabled, the MongoDB driver backs [`crate::mongodb_client::MongoDbClient`]
+    /// so the driver based methods (e.g. [`crate::Client::get_deployment_id_via_driver`]) can be used.
+    pub mongodb: bool,
+    /// The Docker API version requests are made with, lowered by [`crate::Client::negotiate_api_version`]
+    /// when the daemon is older.
+    pub api_version: ApiVersion,
+}
+
+impl Capabilities {
+    /// Creates the capabilities of a client using `api_version`, the features are the ones this crate was compiled with.
+    pub fn new(api_version: ApiVersion) -> Self {
+        Capabilities {
+            serde: cfg!(feature = "serde"),
+            bollard: cfg!(feature = "bollard"),
+            tracing: cfg!(feature = "tracing"),
+            mongodb: cfg!(feature = "mongodb"),
+            api_version,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::{collections::BTreeMap, fmt::Write, net::IpAddr};
+
+use serde_json::Value;
+
+use crate::models::{
+    ATLAS_LOCAL_IMAGE, BindingType, Deployment, LOCAL_SEED_LOCATION, NetworkMode,
+    REDACTED_ENV_VALUE, UNIX_SOCKET_LOCATION,
+};
+
+/// How secrets (passwords and API keys) are written by [`Deployment::to_compose_service`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComposeSecrets {
+    /// Secrets are replaced by [`REDACTED_ENV_VALUE`].
+    #[default]
+    Redacted,
+    /// Secrets are written as is.
+    Include,
+    /// Secrets are left out of the environment.
+    Exclude,
+}
+
+impl Deployment {
+    /// Generates a docker-compose service definition for the deployment, in YAML.
+    ///
+    /// The service is keyed by the deployment name (the short ID when it has none), paste it under `services:`.
+    /// It sets the image of the deployment's MongoDB version, the MongoDB port binding, the environment
+    /// variables and the host directories mounted in the container.
+    pub fn to_compose_service(&self, secrets: ComposeSecrets) -> String {
+        let name = self.name.as_deref().unwrap_or_else(|| self.short_id());
+        let env = self
+            .env_with_secrets(|secret| match secrets {
+                ComposeSecrets::Redacted => Some(REDACTED_ENV_VALUE.to_string()),
+                ComposeSecrets::Include => Some(secret.clone()),
+                ComposeSecrets::Exclude => None,
+            })
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let volumes = [
+            (self.local_seed_location.as_ref(), LOCAL_SEED_LOCATION),
+            (self.unix_socket_location.as_ref(), UNIX_SOCKET_LOCATION),
+        ]
+        .into_iter()
+        .filter_map(|(source, target)| source.map(|source| format!("{source}:{target}")))
+        .collect::<Vec<_>>();
+
+        // Writing to a String cannot fail
+        let mut service = String::new();
+        _ = writeln!(service, "{}:", quote(name));
+        _ = writeln!(
+            service,
+            "  image: {}",
+            quote(&format!("{ATLAS_LOCAL_IMAGE}:{}", self.mongodb_version))
+        );
+        _ = writeln!(service, "  hostname: {}", quote(name));
+
+        if self.is_network_disabled() {
+            _ = writeln!(
+                service,
+                "  network_mode: {}",
+                quote(NetworkMode::None.as_str())
+            );
+        }
+
+        if let Some(port) = self.compose_port() {
+            _ = writeln!(service, "  ports:");
+            _ = writeln!(service, "    - {}", quote(&port));
+        }
+
+        // mongod only creates its socket in the mounted directory when told to
+        if self.unix_socket_location.is_some() {
+            _ = writeln!(
+                service,
+                "  command: [\"--unixSocketPrefix\", {}]",
+                quote(UNIX_SOCKET_LOCATION)
+            );
+        }
+
+        if !env.is_empty() {
+            _ = writeln!(service, "  environment:");
+            for (key, value) in &env {
+                _ = writeln!(service, "    {key}: {}", quote(value));
+            }
+        }
+
+        if !volumes.is_empty() {
+            _ = writeln!(service, "  volumes:");
+            for volume in &volumes {
+                _ = writeln!(service, "    - {}", quote(volume));
+            }
+        }
+
+        service
+    }
+
+    /// Returns the compose port mapping of the MongoDB port, an empty host port lets Docker pick one.
+    fn compose_port(&self) -> Option<String> {
+        let port_binding = self.port_bindings.as_ref()?;
+        let host_port = port_binding
+            .port
+            .map(|port| port.to_string())
+            .unwrap_or_default();
+
+        Some(match port_binding.binding_type {
+            BindingType::Loopback => format!("127.0.0.1:{host_port}:27017"),
+            BindingType::AnyInterface if host_port.is_empty() => "27017".to_string(),
+            BindingType::AnyInterface => format!("{host_port}:27017"),
+            BindingType::Specific { ip: IpAddr::V6(ip) } => format!("[{ip}]:{host_port}:27017"),
+            BindingType::Specific { ip } => format!("{ip}:{host_port}:27017"),
+        })
+    }
+}
+
+/// Quotes a value as a YAML double-quoted scalar, a JSON string literal is one.
+fn quote(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreationSource, MongoDBPortBinding, MongodbType, State};
+    use semver::Version;
+
+    fn create_test_deployment() -> Deployment {
+        Deployment {
+            container_id: "0123456789abcdef".to_string(),
+            name: Some("local-dev".to_string()),
+            state: State::Running,
+            health: None,
+            port_bindings: Some(MongoDBPortBinding::new(Some(27018), BindingType::Loopback)),
+            unix_socket_location: None,
+            network_mode: None,
+            mongodb_type: MongodbType::Community,
+            mongodb_version: Version::new(8, 0, 4),
+            replica_set_name: None,
+            creation_source: Some(CreationSource::AtlasCLI),
+            created_by_version: None,
+            local_seed_location: Some("/host/seed-data".to_string()),
+            mongodb_initdb_database: None,
+            mongodb_initdb_root_password_file: None,
+            mongodb_initdb_root_password: Some("password123".to_string()),
+            mongodb_initdb_root_username_file: None,
+            mongodb_initdb_root_username: Some("admin".to_string()),
+            mongodb_initdb_root_roles: None,
+            mongodb_load_sample_data: None,
+            voyage_api_key: None,
+            mongot_log_file: None,
+            runner_log_file: None,
+            do_not_track: false,
+            telemetry_base_url: None,
+        }
+    }
+
+    #[test]
+    fn test_to_compose_service() {
+        let service = create_test_deployment().to_compose_service(ComposeSecrets::Redacted);
+
+        assert_eq!(
+            service,
+            r#""local-dev":
+  image: "quay.io/mongodb/mongodb-atlas-local:8.0.4"
+  hostname: "local-dev"
+  ports:
+    - "127.0.0.1:27018:27017"
+  environment:
+    MONGODB_INITDB_ROOT_PASSWORD: "********"
+    MONGODB_INITDB_ROOT_USERNAME: "admin"
+    TOOL: "ATLASCLI"
+  volumes:
+    - "/host/seed-data:/docker-entrypoint-initdb.d"
+"#
+        );
+    }
+
+    #[test]
+    fn test_to_compose_service_secrets() {
+        let deployment = create_test_deployment();
+
+        let included = deployment.to_compose_service(ComposeSecrets::Include);
+        let excluded = deployment.to_compose_service(ComposeSecrets::Exclude);
+
+        assert!(included.contains(r#"    MONGODB_INITDB_ROOT_PASSWORD: "password123""#));
+        assert!(!excluded.contains("MONGODB_INITDB_ROOT_PASSWORD"));
+        assert!(excluded.contains(r#"    MONGODB_INITDB_ROOT_USERNAME: "admin""#));
+    }
+
+    #[test]
+    fn test_to_compose_service_minimal() {
+        let deployment = Deployment {
+            name: None,
+            port_bindings: None,
+            creation_source: None,
+            local_seed_location: None,
+            mongodb_initdb_root_password: None,
+            mongodb_initdb_root_username: None,
+            ..create_test_deployment()
+        };
+
+        let service = deployment.to_compose_service(ComposeSecrets::Redacted);
+
+        assert_eq!(
+            service,
+            r#""0123456789ab":
+  image: "quay.io/mongodb/mongodb-atlas-local:8.0.4"
+  hostname: "0123456789ab"
+"#
+        );
+    }
+
+    #[test]
+    fn test_to_compose_service_unix_socket_location() {
+        let deployment = Deployment {
+            unix_socket_location: Some("/host/sockets".to_string()),
+            ..create_test_deployment()
+        };
+
+        let service = deployment.to_compose_service(ComposeSecrets::Redacted);
+
+        assert!(service.contains("  command: [\"--unixSocketPrefix\", \"/var/run/mongodb\"]\n"));
+        assert!(service.contains("    - \"/host/sockets:/var/run/mongodb\"\n"));
+    }
+
+    #[test]
+    fn test_to_compose_service_network_disabled() {
+        let deployment = Deployment {
+            network_mode: Some("none".to_string()),
+            port_bindings: None,
+            ..create_test_deployment()
+        };
+
+        let service = deployment.to_compose_service(ComposeSecrets::Redacted);
+
+        assert!(service.contains("  network_mode: \"none\"\n"));
+        assert!(!service.contains("ports:"));
+    }
+
+    #[test]
+    fn test_compose_port() {
+        let with_binding = |port, binding_type| Deployment {
+            port_bindings: Some(MongoDBPortBinding::new(port, binding_type)),
+            ..create_test_deployment()
+        };
+
+        assert_eq!(
+            with_binding(None, BindingType::Loopback).compose_port(),
+            Some("127.0.0.1::27017".to_string())
+        );
+        assert_eq!(
+            with_binding(Some(27018), BindingType::AnyInterface).compose_port(),
+            Some("27018:27017".to_string())
+        );
+        assert_eq!(
+            with_binding(None, BindingType::AnyInterface).compose_port(),
+            Some("27017".to_string())
+        );
+        assert_eq!(
+            with_binding(
+                Some(27018),
+                BindingType::Specific {
+                    ip: "192.168.1.100".parse().unwrap()
+                }
+            )
+            .compose_port(),
+            Some("192.168.1.100:27018:27017".to_string())
+        );
+        assert_eq!(
+            with_binding(
+                Some(27018),
+                BindingType::Specific {
+                    ip: "fd00::1".parse().unwrap()
+                }
+            )
+            .compose_port(),
+            Some("[fd00::1]:27018:27017".to_string())
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+/// Options for building the connection string of a deployment.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::GetConnectionStringOptions;
+///
+/// // Reach a deployment whose port is tunneled to another machine
+/// let options = GetConnectionStringOptions::builder()
+///     .host_override("jump-host.internal")
+///     .build();
+///
+/// // Identify the application and prefer reading from secondaries
+/// let options = GetConnectionStringOptions::builder()
+///     .extra_params(vec![
+///         ("appName".to_string(), "my-tool".to_string()),
+///         ("readPreference".to_string(), "secondaryPreferred".to_string()),
+///     ])
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, typed_builder::TypedBuilder)]
+#[builder(doc)]
+pub struct GetConnectionStringOptions {
+    /// Host to use instead of the one derived from the port binding, the port and credentials are kept.
+    #[builder(default, setter(strip_option, into))]
+    pub host_override: Option<String>,
+    /// How drivers connect to the deployment, see [`ConnectionMode`].
+    #[builder(default)]
+    pub mode: ConnectionMode,
+    /// Value of the `directConnection` option, defaults to `true` in [`ConnectionMode::Direct`]
+    /// and to leaving the option out in [`ConnectionMode::ReplicaSet`].
+    #[builder(default, setter(strip_option))]
+    pub direct_connection: Option<bool>,
+    /// Additional options appended to the query string in order, e.g. `appName` or `tls`. They are URL-encoded.
+    #[builder(default, setter(strip_option))]
+    pub extra_params: Option<Vec<(String, String)>>,
+    /// Adds `appName=atlas-local-<major>.<minor>` with the deployment's MongoDB version, unless
+    /// `extra_params` already sets an `appName`.
+    #[builder(default = false)]
+    pub include_app_name: bool,
+    /// How long [`crate::Client::get_verified_connection_string`] waits for the deployment to answer,
+    /// 10 seconds when not set.
+    #[builder(default, setter(strip_option))]
+    pub verify_timeout: Option<Duration>,
+}
+
+/// How a driver connects to the deployment, selects the options of the connection string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConnectionMode {
+    /// Connects to the node directly (`directConnection=true`).
+    #[default]
+    Direct,
+    /// Connects to the replica set, named after the deployment's `replica_set_name` when it has one.
+    ReplicaSet,
+}
@@ -1,5 +1,10 @@
 use std::fmt;
 
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ContainerHealthStatus {
     Empty,
@@ -1,22 +1,50 @@
 use bollard::{
-    models::{ContainerCreateBody, HostConfig, PortBinding},
+    models::{ContainerCreateBody, HostConfig, PortBinding, VolumeCreateRequest},
     query_parameters::CreateContainerOptions,
 };
 use maplit::hashmap;
 use rand::RngExt;
-use std::{time::Duration, vec};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    time::Duration,
+    vec,
+};
 
 use crate::models::{
-    CreationSource, ENV_VAR_DO_NOT_TRACK, ENV_VAR_MONGODB_INITDB_DATABASE,
-    ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD, ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE,
+    AtlasLocalImage, MongoDBPortBinding, NetworkMode, PortExposure, RestartPolicy, RootRole,
+    deployment::{CONFIG_FILE_LOCATION, DATA_DIRECTORY, LOCAL_SEED_LOCATION, UNIX_SOCKET_LOCATION},
+};
+use crate::models::{
+    CREATED_BY_VERSION_LABEL_KEY, CreationSource, ENV_VAR_DO_NOT_TRACK,
+    ENV_VAR_MONGODB_INITDB_DATABASE, ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD,
+    ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE, ENV_VAR_MONGODB_INITDB_ROOT_ROLES,
     ENV_VAR_MONGODB_INITDB_ROOT_USERNAME, ENV_VAR_MONGODB_INITDB_ROOT_USERNAME_FILE,
-    ENV_VAR_MONGODB_LOAD_SAMPLE_DATA, ENV_VAR_MONGOT_LOG_FILE, ENV_VAR_RUNNER_LOG_FILE,
-    ENV_VAR_TELEMETRY_BASE_URL, ENV_VAR_TOOL, ENV_VAR_VOYAGE_API_KEY, ImageTag,
-    LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE,
+    ENV_VAR_MONGODB_LOAD_SAMPLE_DATA, ENV_VAR_MONGODB_REPLICA_SET_NAME, ENV_VAR_MONGOT_LOG_FILE,
+    ENV_VAR_RUNNER_LOG_FILE, ENV_VAR_TELEMETRY_BASE_URL, ENV_VAR_TOOL, ENV_VAR_VOYAGE_API_KEY,
+    ImageTag, LOCAL_DEPLOYMENT_LABEL_KEY, LOCAL_DEPLOYMENT_LABEL_VALUE,
+    MONGODB_CONTAINER_PORT_LABEL_KEY, MONGODB_INTERNAL_PORT, Telemetry, WaitStrategy,
 };
-use crate::models::{MongoDBPortBinding, deployment::LOCAL_SEED_LOCATION};
 pub const ATLAS_LOCAL_IMAGE: &str = "quay.io/mongodb/mongodb-atlas-local";
 
+/// The environment variables set from [`CreateDeploymentOptions`], the image sets others such as `PATH`.
+pub(crate) const OPTION_ENV_VARS: [&str; 14] = [
+    ENV_VAR_TOOL,
+    ENV_VAR_RUNNER_LOG_FILE,
+    ENV_VAR_MONGODB_INITDB_ROOT_USERNAME,
+    ENV_VAR_MONGODB_INITDB_ROOT_USERNAME_FILE,
+    ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD,
+    ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE,
+    ENV_VAR_MONGODB_INITDB_DATABASE,
+    ENV_VAR_MONGODB_INITDB_ROOT_ROLES,
+    ENV_VAR_MONGODB_LOAD_SAMPLE_DATA,
+    ENV_VAR_VOYAGE_API_KEY,
+    ENV_VAR_MONGODB_REPLICA_SET_NAME,
+    ENV_VAR_MONGOT_LOG_FILE,
+    ENV_VAR_DO_NOT_TRACK,
+    ENV_VAR_TELEMETRY_BASE_URL,
+];
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct CreateDeploymentOptions {
@@ -27,11 +55,17 @@ pub struct CreateDeploymentOptions {
     pub image: Option<String>,
     pub skip_pull_image: Option<bool>,
     pub image_tag: Option<ImageTag>,
+    /// The image to create the deployment from, e.g. pinned to a digest. Takes precedence over `image` and `image_tag`.
+    pub atlas_local_image: Option<AtlasLocalImage>,
 
     // Creation Options
     pub wait_until_healthy: Option<bool>,
+    /// Takes precedence over `wait_until_healthy` when set.
+    pub wait_strategy: Option<WaitStrategy>,
     pub wait_until_healthy_timeout: Option<Duration>,
     pub creation_source: Option<CreationSource>,
+    /// Skips the label recording the version of this crate on the created deployment.
+    pub skip_version_label: Option<bool>,
 
     // Initial database configuration
     pub local_seed_location: Option<String>,
@@ -40,19 +74,375 @@ pub struct CreateDeploymentOptions {
     pub mongodb_initdb_root_password: Option<String>,
     pub mongodb_initdb_root_username_file: Option<String>,
     pub mongodb_initdb_root_username: Option<String>,
+    /// Roles granted to the root user, emitted as a comma separated list.
+    pub mongodb_initdb_root_roles: Option<Vec<RootRole>>,
     pub voyage_api_key: Option<String>,
     pub load_sample_data: Option<bool>,
+    /// Name of the replica set mongod is initialized with, the image default is used when not set.
+    pub replica_set_name: Option<String>,
 
     // Logging
     pub mongot_log_file: Option<String>,
     pub runner_log_file: Option<String>,
 
     // Telemetry
+    /// Takes precedence over `do_not_track` and `telemetry_base_url` when set.
+    pub telemetry: Option<Telemetry>,
+    #[deprecated(note = "use `telemetry` instead")]
     pub do_not_track: Option<bool>,
+    #[deprecated(note = "use `telemetry` instead")]
     pub telemetry_base_url: Option<String>,
 
     // Port configuration
     pub mongodb_port_binding: Option<MongoDBPortBinding>,
+    /// Set to [`PortExposure::None`] to not publish the MongoDB port, `mongodb_port_binding` is then ignored.
+    pub port_exposure: Option<PortExposure>,
+    /// Host directory to mount as the mongod unix socket directory (`/var/run/mongodb` in the container),
+    /// the socket is then reachable from the host, see [`crate::Client::get_socket_path`].
+    pub unix_socket_location: Option<String>,
+
+    // Container configuration
+    /// [`NetworkMode::None`] detaches the container from every network, the MongoDB port is then not published.
+    pub network_mode: Option<NetworkMode>,
+    /// Name of a user-defined Docker network to attach the container to, e.g. to reach the deployment
+    /// from application containers by its name. Takes precedence over `network_mode` when set.
+    pub network: Option<String>,
+    /// Named Docker volume to mount as the MongoDB data directory (`/data/db`), the data then outlives the container.
+    /// A volume that does not exist yet is created with the local deployment label, see
+    /// [`crate::Client::prune_deployment_volumes`]. An existing volume is used as is.
+    pub data_volume: Option<String>,
+    /// Host path of a `mongod.conf` to start mongod with, mounted read-only at `/etc/mongod.conf`.
+    /// The file must exist, [`crate::Client::create_deployment`] fails otherwise.
+    pub config_file: Option<PathBuf>,
+    /// Adjusts the OOM killer score of the container, negative values make mongod less likely to be killed.
+    pub oom_score_adj: Option<i64>,
+    /// Size of `/dev/shm` in bytes, Docker defaults to 64MB when not set.
+    pub shm_size: Option<i64>,
+    /// Cgroup to place the container in, the default cgroup of the Docker daemon is used when not set.
+    pub cgroup_parent: Option<String>,
+    /// Lets Docker remove the container when it exits, e.g. for throwaway test deployments.
+    ///
+    /// A deployment that fails its health check while waiting for it to become healthy can be removed
+    /// before it is inspected again, waiting then fails with a not found error instead of reporting it unhealthy.
+    /// The data is removed with the container unless `data_volume` is set.
+    pub auto_remove: Option<bool>,
+    /// When Docker restarts the container, Docker's default (never) applies when not set.
+    /// Docker rejects a restart policy other than [`RestartPolicy::No`] together with `auto_remove`.
+    pub restart_policy: Option<RestartPolicy>,
+    /// Additional environment variables for images that read variables these options do not model.
+    /// They are added after the variables set by the options, which always take precedence:
+    /// extra variables named like one of them (e.g. `TOOL`) are ignored, even when the option is not set.
+    pub extra_env: Option<HashMap<String, String>>,
+}
+
+impl CreateDeploymentOptions {
+    /// Returns the wait strategy to use, `wait_strategy` takes precedence over `wait_until_healthy`.
+    pub fn effective_wait_strategy(&self) -> WaitStrategy {
+        match (self.wait_strategy, self.wait_until_healthy) {
+            (Some(wait_strategy), _) => wait_strategy,
+            (None, Some(false)) => WaitStrategy::None,
+            (None, _) => WaitStrategy::Healthy,
+        }
+    }
+
+    /// Returns the image to use, `atlas_local_image` takes precedence over `image` and `image_tag`.
+    pub fn effective_image(&self) -> AtlasLocalImage {
+        if let Some(atlas_local_image) = &self.atlas_local_image {
+            return atlas_local_image.clone();
+        }
+
+        AtlasLocalImage::new(
+            self.image
+                .clone()
+                .unwrap_or_else(|| ATLAS_LOCAL_IMAGE.to_string()),
+        )
+        .with_tag(self.image_tag.clone().unwrap_or_default())
+    }
+
+    /// Returns the request creating `data_volume` with the local deployment label, so that
+    /// [`crate::Client::prune_deployment_volumes`] can remove it once no deployment uses it.
+    ///
+    /// `None` without a data volume, or when `data_volume` is a host path Docker bind mounts instead.
+    pub(crate) fn data_volume_create_request(&self) -> Option<VolumeCreateRequest> {
+        let data_volume = self.data_volume.as_ref()?;
+        if data_volume.contains(['/', '\\']) {
+            return None;
+        }
+
+        Some(VolumeCreateRequest {
+            name: Some(data_volume.clone()),
+            labels: Some(hashmap! {
+                LOCAL_DEPLOYMENT_LABEL_KEY.to_string() => LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+            }),
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CreateDeploymentOptionsError {
+    #[error("Deployment name must not be empty")]
+    EmptyName,
+    #[error("Only one of the root username and the root username file can be set")]
+    ConflictingRootUsername,
+    #[error("Only one of the root password and the root password file can be set")]
+    ConflictingRootPassword,
+}
+
+impl CreateDeploymentOptions {
+    /// Creates a builder that validates the options when they are built.
+    pub fn builder() -> CreateDeploymentOptionsBuilder {
+        CreateDeploymentOptionsBuilder::default()
+    }
+
+    /// Checks that the options do not contradict each other.
+    ///
+    /// The name must not be empty (or only whitespace) when set, and the root username and password
+    /// can each be given either as a value or as a file, not both.
+    pub fn validate(&self) -> Result<(), CreateDeploymentOptionsError> {
+        if self
+            .name
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            return Err(CreateDeploymentOptionsError::EmptyName);
+        }
+        if self.mongodb_initdb_root_username.is_some()
+            && self.mongodb_initdb_root_username_file.is_some()
+        {
+            return Err(CreateDeploymentOptionsError::ConflictingRootUsername);
+        }
+        if self.mongodb_initdb_root_password.is_some()
+            && self.mongodb_initdb_root_password_file.is_some()
+        {
+            return Err(CreateDeploymentOptionsError::ConflictingRootPassword);
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds [`CreateDeploymentOptions`], validating them with [`CreateDeploymentOptions::validate`].
+///
+/// Every setter sets the field of the same name, fields that are not set keep their default.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::{CreateDeploymentOptions, ImageTag};
+///
+/// let options = CreateDeploymentOptions::builder()
+///     .name("my-deployment")
+///     .image_tag(ImageTag::Latest)
+///     .mongodb_initdb_root_username("admin")
+///     .mongodb_initdb_root_password("password")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct CreateDeploymentOptionsBuilder {
+    options: CreateDeploymentOptions,
+}
+
+impl CreateDeploymentOptionsBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.options.name = Some(name.into());
+        self
+    }
+
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.options.image = Some(image.into());
+        self
+    }
+
+    pub fn skip_pull_image(mut self, skip_pull_image: bool) -> Self {
+        self.options.skip_pull_image = Some(skip_pull_image);
+        self
+    }
+
+    pub fn image_tag(mut self, image_tag: ImageTag) -> Self {
+        self.options.image_tag = Some(image_tag);
+        self
+    }
+
+    pub fn atlas_local_image(mut self, atlas_local_image: AtlasLocalImage) -> Self {
+        self.options.atlas_local_image = Some(atlas_local_image);
+        self
+    }
+
+    pub fn wait_until_healthy(mut self, wait_until_healthy: bool) -> Self {
+        self.options.wait_until_healthy = Some(wait_until_healthy);
+        self
+    }
+
+    pub fn wait_strategy(mut self, wait_strategy: WaitStrategy) -> Self {
+        self.options.wait_strategy = Some(wait_strategy);
+        self
+    }
+
+    pub fn wait_until_healthy_timeout(mut self, wait_until_healthy_timeout: Duration) -> Self {
+        self.options.wait_until_healthy_timeout = Some(wait_until_healthy_timeout);
+        self
+    }
+
+    pub fn creation_source(mut self, creation_source: CreationSource) -> Self {
+        self.options.creation_source = Some(creation_source);
+        self
+    }
+
+    pub fn skip_version_label(mut self, skip_version_label: bool) -> Self {
+        self.options.skip_version_label = Some(skip_version_label);
+        self
+    }
+
+    pub fn local_seed_location(mut self, local_seed_location: impl Into<String>) -> Self {
+        self.options.local_seed_location = Some(local_seed_location.into());
+        self
+    }
+
+    pub fn mongodb_initdb_database(mut self, mongodb_initdb_database: impl Into<String>) -> Self {
+        self.options.mongodb_initdb_database = Some(mongodb_initdb_database.into());
+        self
+    }
+
+    pub fn mongodb_initdb_root_password_file(
+        mut self,
+        mongodb_initdb_root_password_file: impl Into<String>,
+    ) -> Self {
+        self.options.mongodb_initdb_root_password_file =
+            Some(mongodb_initdb_root_password_file.into());
+        self
+    }
+
+    pub fn mongodb_initdb_root_password(
+        mut self,
+        mongodb_initdb_root_password: impl Into<String>,
+    ) -> Self {
+        self.options.mongodb_initdb_root_password = Some(mongodb_initdb_root_password.into());
+        self
+    }
+
+    pub fn mongodb_initdb_root_username_file(
+        mut self,
+        mongodb_initdb_root_username_file: impl Into<String>,
+    ) -> Self {
+        self.options.mongodb_initdb_root_username_file =
+            Some(mongodb_initdb_root_username_file.into());
+        self
+    }
+
+    pub fn mongodb_initdb_root_username(
+        mut self,
+        mongodb_initdb_root_username: impl Into<String>,
+    ) -> Self {
+        self.options.mongodb_initdb_root_username = Some(mongodb_initdb_root_username.into());
+        self
+    }
+
+    pub fn mongodb_initdb_root_roles(mut self, mongodb_initdb_root_roles: Vec<RootRole>) -> Self {
+        self.options.mongodb_initdb_root_roles = Some(mongodb_initdb_root_roles);
+        self
+    }
+
+    pub fn voyage_api_key(mut self, voyage_api_key: impl Into<String>) -> Self {
+        self.options.voyage_api_key = Some(voyage_api_key.into());
+        self
+    }
+
+    pub fn load_sample_data(mut self, load_sample_data: bool) -> Self {
+        self.options.load_sample_data = Some(load_sample_data);
+        self
+    }
+
+    pub fn replica_set_name(mut self, replica_set_name: impl Into<String>) -> Self {
+        self.options.replica_set_name = Some(replica_set_name.into());
+        self
+    }
+
+    pub fn mongot_log_file(mut self, mongot_log_file: impl Into<String>) -> Self {
+        self.options.mongot_log_file = Some(mongot_log_file.into());
+        self
+    }
+
+    pub fn runner_log_file(mut self, runner_log_file: impl Into<String>) -> Self {
+        self.options.runner_log_file = Some(runner_log_file.into());
+        self
+    }
+
+    pub fn telemetry(mut self, telemetry: Telemetry) -> Self {
+        self.options.telemetry = Some(telemetry);
+        self
+    }
+
+    pub fn mongodb_port_binding(mut self, mongodb_port_binding: MongoDBPortBinding) -> Self {
+        self.options.mongodb_port_binding = Some(mongodb_port_binding);
+        self
+    }
+
+    pub fn port_exposure(mut self, port_exposure: PortExposure) -> Self {
+        self.options.port_exposure = Some(port_exposure);
+        self
+    }
+
+    pub fn unix_socket_location(mut self, unix_socket_location: impl Into<String>) -> Self {
+        self.options.unix_socket_location = Some(unix_socket_location.into());
+        self
+    }
+
+    pub fn network_mode(mut self, network_mode: NetworkMode) -> Self {
+        self.options.network_mode = Some(network_mode);
+        self
+    }
+
+    pub fn network(mut self, network: impl Into<String>) -> Self {
+        self.options.network = Some(network.into());
+        self
+    }
+
+    pub fn data_volume(mut self, data_volume: impl Into<String>) -> Self {
+        self.options.data_volume = Some(data_volume.into());
+        self
+    }
+
+    pub fn config_file(mut self, config_file: impl Into<PathBuf>) -> Self {
+        self.options.config_file = Some(config_file.into());
+        self
+    }
+
+    pub fn oom_score_adj(mut self, oom_score_adj: i64) -> Self {
+        self.options.oom_score_adj = Some(oom_score_adj);
+        self
+    }
+
+    pub fn shm_size(mut self, shm_size: i64) -> Self {
+        self.options.shm_size = Some(shm_size);
+        self
+    }
+
+    pub fn cgroup_parent(mut self, cgroup_parent: impl Into<String>) -> Self {
+        self.options.cgroup_parent = Some(cgroup_parent.into());
+        self
+    }
+
+    pub fn auto_remove(mut self, auto_remove: bool) -> Self {
+        self.options.auto_remove = Some(auto_remove);
+        self
+    }
+
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.options.restart_policy = Some(restart_policy);
+        self
+    }
+
+    pub fn extra_env(mut self, extra_env: HashMap<String, String>) -> Self {
+        self.options.extra_env = Some(extra_env);
+        self
+    }
+
+    pub fn build(self) -> Result<CreateDeploymentOptions, CreateDeploymentOptionsError> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
 }
 
 impl From<&CreateDeploymentOptions> for CreateContainerOptions {
@@ -71,6 +461,13 @@ impl From<&CreateDeploymentOptions> for CreateContainerOptions {
 
 impl From<&CreateDeploymentOptions> for ContainerCreateBody {
     fn from(deployment_options: &CreateDeploymentOptions) -> Self {
+        // The port MongoDB listens on inside the container
+        let container_port = deployment_options
+            .mongodb_port_binding
+            .as_ref()
+            .map_or(MONGODB_INTERNAL_PORT, |binding| binding.container_port);
+        let port_map_key = MongoDBPortBinding::port_map_key(container_port);
+
         // Get the port bindings if available, otherwise default to binding a random avaiable port on 127.0.0.1
         let port_binding = deployment_options
             .mongodb_port_binding
@@ -81,18 +478,70 @@ impl From<&CreateDeploymentOptions> for ContainerCreateBody {
                 host_port: None,
             });
 
-        let port_bindings_map = Some(hashmap! {
-            "27017/tcp".to_string() => Some(vec![port_binding])
+        // A user-defined network takes precedence over the network mode, Docker's default is used when neither is set
+        let network_mode = deployment_options.network.clone().or_else(|| {
+            deployment_options
+                .network_mode
+                .map(|network_mode| network_mode.to_string())
+        });
+
+        // Ports cannot be published on a container without a network
+        let publish_port = deployment_options.port_exposure.unwrap_or_default()
+            == PortExposure::Published
+            && network_mode.as_deref() != Some(NetworkMode::None.as_str());
+        // The image only exposes the default port, other ports have to be exposed to be published
+        let exposed_ports = (publish_port && container_port != MONGODB_INTERNAL_PORT)
+            .then(|| vec![port_map_key.clone()]);
+        let port_bindings_map = publish_port.then(|| {
+            hashmap! {
+                port_map_key => Some(vec![port_binding])
+            }
         });
 
-        // Set up volume bindings if a local seed location is provided
-        let volume_bindings_map =
+        // Set up volume bindings if a local seed location, unix socket location, data volume or config file is provided
+        let volume_bindings = [
             deployment_options
                 .local_seed_location
                 .as_ref()
                 .map(|local_seed_location| {
-                    vec![format!("{local_seed_location}:{LOCAL_SEED_LOCATION}:rw")]
-                });
+                    format!("{local_seed_location}:{LOCAL_SEED_LOCATION}:rw")
+                }),
+            deployment_options
+                .unix_socket_location
+                .as_ref()
+                .map(|unix_socket_location| {
+                    format!("{unix_socket_location}:{UNIX_SOCKET_LOCATION}:rw")
+                }),
+            deployment_options
+                .data_volume
+                .as_ref()
+                .map(|data_volume| format!("{data_volume}:{DATA_DIRECTORY}:rw")),
+            deployment_options
+                .config_file
+                .as_ref()
+                .map(|config_file| format!("{}:{CONFIG_FILE_LOCATION}:ro", config_file.display())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+
+        // Only set binds if we have any to set, otherwise leave it as None
+        let volume_bindings_map = if volume_bindings.is_empty() {
+            None
+        } else {
+            Some(volume_bindings)
+        };
+
+        // Resolve the telemetry settings, the deprecated fields are only used when `telemetry` is not set
+        #[allow(deprecated)]
+        let (do_not_track, telemetry_base_url) = match &deployment_options.telemetry {
+            Some(Telemetry::Disabled) => (Some(true.to_string()), None),
+            Some(Telemetry::Enabled { base_url }) => (None, base_url.clone()),
+            None => (
+                deployment_options.do_not_track.map(|b| b.to_string()),
+                deployment_options.telemetry_base_url.clone(),
+            ),
+        };
 
         // Set environment variables if they are provided in the deployment options
         let mut env_vars = [
@@ -124,6 +573,14 @@ impl From<&CreateDeploymentOptions> for ContainerCreateBody {
                 ENV_VAR_MONGODB_INITDB_DATABASE,
                 deployment_options.mongodb_initdb_database.as_ref(),
             ),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_ROLES,
+                deployment_options
+                    .mongodb_initdb_root_roles
+                    .as_deref()
+                    .map(RootRole::format_list)
+                    .as_ref(),
+            ),
             (
                 ENV_VAR_MONGODB_LOAD_SAMPLE_DATA,
                 deployment_options
@@ -137,21 +594,15 @@ impl From<&CreateDeploymentOptions> for ContainerCreateBody {
                 deployment_options.voyage_api_key.as_ref(),
             ),
             (
-                ENV_VAR_MONGOT_LOG_FILE,
-                deployment_options.mongot_log_file.as_ref(),
-            ),
-            (
-                ENV_VAR_DO_NOT_TRACK,
-                deployment_options
-                    .do_not_track
-                    .as_ref()
-                    .map(|b| b.to_string())
-                    .as_ref(),
+                ENV_VAR_MONGODB_REPLICA_SET_NAME,
+                deployment_options.replica_set_name.as_ref(),
             ),
             (
-                ENV_VAR_TELEMETRY_BASE_URL,
-                deployment_options.telemetry_base_url.as_ref(),
+                ENV_VAR_MONGOT_LOG_FILE,
+                deployment_options.mongot_log_file.as_ref(),
             ),
+            (ENV_VAR_DO_NOT_TRACK, do_not_track.as_ref()),
+            (ENV_VAR_TELEMETRY_BASE_URL, telemetry_base_url.as_ref()),
         ]
         .into_iter()
         .filter_map(|(env_key, value_opt)| {
@@ -163,6 +614,19 @@ impl From<&CreateDeploymentOptions> for ContainerCreateBody {
             env_vars.push(format!("{ENV_VAR_TOOL}={source}"));
         }
 
+        // Add the extra variables last, sorted to keep the container config stable
+        if let Some(extra_env) = deployment_options.extra_env.as_ref() {
+            let extra_env = extra_env
+                .iter()
+                .filter(|(key, _)| !OPTION_ENV_VARS.contains(&key.as_str()))
+                .collect::<BTreeMap<_, _>>();
+            env_vars.extend(
+                extra_env
+                    .into_iter()
+                    .map(|(key, value)| format!("{key}={value}")),
+            );
+        }
+
         // Only set env if we have any to set, otherwise leave it as None
         let env = if env_vars.is_empty() {
             None
@@ -170,32 +634,60 @@ impl From<&CreateDeploymentOptions> for ContainerCreateBody {
             Some(env_vars)
         };
 
-        // Get the image and tag
-        let image_string = deployment_options
-            .image
-            .clone()
-            .unwrap_or(ATLAS_LOCAL_IMAGE.to_string());
-
-        let tag = deployment_options
-            .image_tag
-            .as_ref()
-            .map(ToString::to_string)
-            .unwrap_or_else(|| "latest".to_string());
-
-        let image = Some(format!("{image_string}:{tag}"));
+        // Get the image reference, including the digest when the image is pinned
+        let image = Some(deployment_options.effective_image().to_string());
 
         // Get labels
-        let labels = Some(hashmap! {
+        let mut labels = hashmap! {
             LOCAL_DEPLOYMENT_LABEL_KEY.to_string() => LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
-        });
+        };
+        if container_port != MONGODB_INTERNAL_PORT {
+            labels.insert(
+                MONGODB_CONTAINER_PORT_LABEL_KEY.to_string(),
+                container_port.to_string(),
+            );
+        }
+        if !deployment_options.skip_version_label.unwrap_or(false) {
+            labels.insert(
+                CREATED_BY_VERSION_LABEL_KEY.to_string(),
+                env!("CARGO_PKG_VERSION").to_string(),
+            );
+        }
+        let labels = Some(labels);
+
+        // Point mongod at the mounted config file and socket directory
+        let mongod_args = [
+            deployment_options
+                .config_file
+                .as_ref()
+                .map(|_| ["--config", CONFIG_FILE_LOCATION]),
+            deployment_options
+                .unix_socket_location
+                .as_ref()
+                .map(|_| ["--unixSocketPrefix", UNIX_SOCKET_LOCATION]),
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+        let cmd = (!mongod_args.is_empty()).then_some(mongod_args);
 
         ContainerCreateBody {
             image,
             labels,
             env,
+            cmd,
+            exposed_ports,
             host_config: Some(HostConfig {
                 port_bindings: port_bindings_map,
+                network_mode,
                 binds: volume_bindings_map,
+                oom_score_adj: deployment_options.oom_score_adj,
+                shm_size: deployment_options.shm_size,
+                cgroup_parent: deployment_options.cgroup_parent.clone(),
+                auto_remove: deployment_options.auto_remove,
+                restart_policy: deployment_options.restart_policy.map(Into::into),
                 ..Default::default()
             }),
             ..Default::default()
@@ -211,6 +703,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_into_container_create_body_full() {
         // Create a full CreateDeploymentOptions with all fields set
         let create_deployment_options = CreateDeploymentOptions {
@@ -218,22 +711,46 @@ mod tests {
             image: Some(ATLAS_LOCAL_IMAGE.to_string()),
             skip_pull_image: Some(false),
             image_tag: Some(ImageTag::Latest),
+            atlas_local_image: None,
             wait_until_healthy: Some(true),
+            wait_strategy: Some(WaitStrategy::Healthy),
             wait_until_healthy_timeout: Some(Duration::from_secs(60)),
             creation_source: Some(CreationSource::Container),
+            skip_version_label: Some(false),
             local_seed_location: Some("/host/seed-data".to_string()),
             mongodb_initdb_database: Some("testdb".to_string()),
             mongodb_initdb_root_password_file: Some("/run/secrets/password".to_string()),
             mongodb_initdb_root_password: Some("password123".to_string()),
             mongodb_initdb_root_username_file: Some("/run/secrets/username".to_string()),
             mongodb_initdb_root_username: Some("admin".to_string()),
+            mongodb_initdb_root_roles: Some(vec![
+                RootRole::new("root", None),
+                RootRole::new("readWrite", Some("app".to_string())),
+            ]),
             voyage_api_key: Some("voyage-api-key".to_string()),
             load_sample_data: Some(true),
+            replica_set_name: Some("rs-app".to_string()),
             mongot_log_file: Some("/tmp/mongot.log".to_string()),
             runner_log_file: Some("/tmp/runner.log".to_string()),
+            telemetry: None,
             do_not_track: Some(false),
             telemetry_base_url: Some("https://telemetry.example.com".to_string()),
             mongodb_port_binding: Some(MongoDBPortBinding::new(Some(50000), BindingType::Loopback)),
+            port_exposure: Some(PortExposure::Published),
+            unix_socket_location: Some("/host/sockets".to_string()),
+            network_mode: Some(NetworkMode::Bridge),
+            network: None,
+            data_volume: Some("atlas-local-data".to_string()),
+            config_file: Some(PathBuf::from("/host/mongod.conf")),
+            oom_score_adj: Some(-500),
+            shm_size: Some(256 * 1024 * 1024),
+            cgroup_parent: Some("/atlas-local".to_string()),
+            auto_remove: Some(false),
+            restart_policy: Some(RestartPolicy::OnFailure { max_retries: 5 }),
+            extra_env: Some(HashMap::from([(
+                "MONGOT_INDEX_DEFINITIONS".to_string(),
+                "/etc/mongot/indexes.json".to_string(),
+            )])),
         };
 
         // Convert to ContainerCreateBody
@@ -248,10 +765,18 @@ mod tests {
         assert_eq!(
             container_create_body
                 .labels
+                .as_ref()
                 .unwrap()
                 .get(LOCAL_DEPLOYMENT_LABEL_KEY),
             Some(&LOCAL_DEPLOYMENT_LABEL_VALUE.to_string())
         );
+        assert_eq!(
+            container_create_body
+                .labels
+                .unwrap()
+                .get(CREATED_BY_VERSION_LABEL_KEY),
+            Some(&env!("CARGO_PKG_VERSION").to_string())
+        );
 
         // Check Creation Options
         assert_eq!(create_deployment_options.wait_until_healthy, Some(true));
@@ -278,6 +803,10 @@ mod tests {
             ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE
         )));
         assert!(env_vars.contains(&format!("{}=testdb", ENV_VAR_MONGODB_INITDB_DATABASE)));
+        assert!(env_vars.contains(&format!(
+            "{}=root,readWrite@app",
+            ENV_VAR_MONGODB_INITDB_ROOT_ROLES
+        )));
         assert!(env_vars.contains(&format!("{}=true", ENV_VAR_MONGODB_LOAD_SAMPLE_DATA)));
         assert!(env_vars.contains(&format!("{}=/tmp/mongot.log", ENV_VAR_MONGOT_LOG_FILE)));
         assert!(env_vars.contains(&format!("{}=false", ENV_VAR_DO_NOT_TRACK)));
@@ -286,7 +815,11 @@ mod tests {
             ENV_VAR_TELEMETRY_BASE_URL
         )));
         assert!(env_vars.contains(&format!("{}=voyage-api-key", ENV_VAR_VOYAGE_API_KEY)));
-        assert_eq!(env_vars.len(), 12);
+        assert!(env_vars.contains(&format!("{}=rs-app", ENV_VAR_MONGODB_REPLICA_SET_NAME)));
+        assert!(
+            env_vars.contains(&"MONGOT_INDEX_DEFINITIONS=/etc/mongot/indexes.json".to_string())
+        );
+        assert_eq!(env_vars.len(), 15);
 
         let host_config = container_create_body.host_config.unwrap();
         let port_bindings = host_config.port_bindings.unwrap();
@@ -300,12 +833,46 @@ mod tests {
         assert_eq!(port_binding.host_ip, Some("127.0.0.1".to_string()));
         assert_eq!(port_binding.host_port, Some("50000".to_string()));
 
+        assert_eq!(host_config.network_mode, Some("bridge".to_string()));
+        assert_eq!(host_config.oom_score_adj, Some(-500));
+        assert_eq!(host_config.shm_size, Some(256 * 1024 * 1024));
+        assert_eq!(host_config.cgroup_parent, Some("/atlas-local".to_string()));
+        assert_eq!(host_config.auto_remove, Some(false));
+        assert_eq!(
+            host_config.restart_policy,
+            Some(bollard::models::RestartPolicy {
+                name: Some(bollard::models::RestartPolicyNameEnum::ON_FAILURE),
+                maximum_retry_count: Some(5),
+            })
+        );
+
         let volumn_binds = host_config.binds.unwrap();
-        assert_eq!(volumn_binds.len(), 1);
+        assert_eq!(volumn_binds.len(), 4);
         assert_eq!(
             volumn_binds[0],
             format!("/host/seed-data:{}:rw", LOCAL_SEED_LOCATION)
         );
+        assert_eq!(
+            volumn_binds[1],
+            format!("/host/sockets:{}:rw", UNIX_SOCKET_LOCATION)
+        );
+        assert_eq!(
+            volumn_binds[2],
+            format!("atlas-local-data:{}:rw", DATA_DIRECTORY)
+        );
+        assert_eq!(
+            volumn_binds[3],
+            format!("/host/mongod.conf:{}:ro", CONFIG_FILE_LOCATION)
+        );
+        assert_eq!(
+            container_create_body.cmd,
+            Some(vec![
+                "--config".to_string(),
+                CONFIG_FILE_LOCATION.to_string(),
+                "--unixSocketPrefix".to_string(),
+                UNIX_SOCKET_LOCATION.to_string()
+            ])
+        );
     }
 
     #[test]
@@ -337,6 +904,12 @@ mod tests {
 
         assert_eq!(port_binding.host_ip, Some("127.0.0.1".to_string()));
         assert!(port_binding.host_port.is_none());
+        assert!(host_config.oom_score_adj.is_none());
+        assert!(host_config.shm_size.is_none());
+        assert!(host_config.cgroup_parent.is_none());
+        assert!(host_config.auto_remove.is_none());
+        assert!(host_config.restart_policy.is_none());
+        assert!(host_config.network_mode.is_none());
 
         assert_eq!(
             container_create_body
@@ -348,6 +921,465 @@ mod tests {
         assert!(container_create_body.exposed_ports.is_none());
     }
 
+    #[test]
+    fn test_into_container_create_body_skip_version_label() {
+        let create_deployment_options = CreateDeploymentOptions {
+            skip_version_label: Some(true),
+            ..Default::default()
+        };
+
+        let container_create_body: ContainerCreateBody =
+            ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.labels,
+            Some(hashmap! {
+                LOCAL_DEPLOYMENT_LABEL_KEY.to_string() => LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_unix_socket_location() {
+        let create_deployment_options = CreateDeploymentOptions {
+            unix_socket_location: Some("/host/sockets".to_string()),
+            ..Default::default()
+        };
+
+        let container_create_body: ContainerCreateBody =
+            ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.host_config.unwrap().binds,
+            Some(vec![format!("/host/sockets:{}:rw", UNIX_SOCKET_LOCATION)])
+        );
+        assert_eq!(
+            container_create_body.cmd,
+            Some(vec![
+                "--unixSocketPrefix".to_string(),
+                UNIX_SOCKET_LOCATION.to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_network_mode_none() {
+        let create_deployment_options = CreateDeploymentOptions {
+            network_mode: Some(NetworkMode::None),
+            port_exposure: Some(PortExposure::None),
+            mongodb_port_binding: Some(MongoDBPortBinding::new(Some(50000), BindingType::Loopback)),
+            ..Default::default()
+        };
+
+        let host_config = ContainerCreateBody::from(&create_deployment_options)
+            .host_config
+            .unwrap();
+
+        assert_eq!(host_config.network_mode, Some("none".to_string()));
+        assert_eq!(host_config.port_bindings, None);
+    }
+
+    #[test]
+    fn test_into_container_create_body_network() {
+        let create_deployment_options = CreateDeploymentOptions {
+            network: Some("app-network".to_string()),
+            network_mode: Some(NetworkMode::None),
+            ..Default::default()
+        };
+
+        let host_config = ContainerCreateBody::from(&create_deployment_options)
+            .host_config
+            .unwrap();
+
+        assert_eq!(host_config.network_mode, Some("app-network".to_string()));
+        assert!(host_config.port_bindings.is_some());
+    }
+
+    #[test]
+    fn test_into_container_create_body_network_mode_none_skips_port_bindings() {
+        let create_deployment_options = CreateDeploymentOptions {
+            network_mode: Some(NetworkMode::None),
+            ..Default::default()
+        };
+
+        let host_config = ContainerCreateBody::from(&create_deployment_options)
+            .host_config
+            .unwrap();
+
+        assert_eq!(host_config.network_mode, Some("none".to_string()));
+        assert_eq!(host_config.port_bindings, None);
+    }
+
+    #[test]
+    fn test_into_container_create_body_free_loopback_port() {
+        let mongodb_port_binding =
+            MongoDBPortBinding::free_loopback(crate::models::DEFAULT_FREE_PORT_RANGE).unwrap();
+        let port = mongodb_port_binding.port.unwrap();
+        let create_deployment_options = CreateDeploymentOptions {
+            mongodb_port_binding: Some(mongodb_port_binding),
+            ..Default::default()
+        };
+
+        let port_bindings = ContainerCreateBody::from(&create_deployment_options)
+            .host_config
+            .unwrap()
+            .port_bindings
+            .unwrap();
+        let port_binding = port_bindings
+            .get("27017/tcp")
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .first()
+            .unwrap();
+
+        // The chosen port is bound explicitly instead of letting Docker pick one
+        assert_eq!(port_binding.host_ip, Some("127.0.0.1".to_string()));
+        assert_eq!(port_binding.host_port, Some(port.to_string()));
+    }
+
+    #[test]
+    fn test_into_container_create_body_custom_container_port() {
+        let create_deployment_options = CreateDeploymentOptions {
+            mongodb_port_binding: Some(
+                MongoDBPortBinding::new(Some(27018), BindingType::Loopback)
+                    .with_container_port(28017),
+            ),
+            ..Default::default()
+        };
+
+        let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.exposed_ports,
+            Some(vec!["28017/tcp".to_string()])
+        );
+        assert_eq!(
+            container_create_body
+                .labels
+                .as_ref()
+                .unwrap()
+                .get(MONGODB_CONTAINER_PORT_LABEL_KEY),
+            Some(&"28017".to_string())
+        );
+        assert_eq!(
+            container_create_body.host_config.unwrap().port_bindings,
+            Some(hashmap! {
+                "28017/tcp".to_string() => Some(vec![PortBinding {
+                    host_ip: Some("127.0.0.1".to_string()),
+                    host_port: Some("27018".to_string()),
+                }])
+            })
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_port_exposure_none() {
+        let create_deployment_options = CreateDeploymentOptions {
+            port_exposure: Some(PortExposure::None),
+            ..Default::default()
+        };
+
+        let host_config = ContainerCreateBody::from(&create_deployment_options)
+            .host_config
+            .unwrap();
+
+        assert_eq!(host_config.network_mode, None);
+        assert_eq!(host_config.port_bindings, None);
+    }
+
+    #[test]
+    fn test_into_container_create_body_extra_env() {
+        let create_deployment_options = CreateDeploymentOptions {
+            creation_source: Some(CreationSource::Container),
+            mongodb_initdb_root_username: Some("admin".to_string()),
+            extra_env: Some(HashMap::from([
+                (
+                    "MONGOT_INDEX_DEFINITIONS".to_string(),
+                    "/etc/mongot/indexes.json".to_string(),
+                ),
+                ("FEATURE_FLAG".to_string(), "on".to_string()),
+                (ENV_VAR_TOOL.to_string(), "OVERRIDE".to_string()),
+            ])),
+            ..Default::default()
+        };
+
+        let env_vars = ContainerCreateBody::from(&create_deployment_options)
+            .env
+            .unwrap();
+
+        // The known variables come first, then the extra ones sorted by name, the TOOL override is ignored
+        assert_eq!(
+            env_vars,
+            vec![
+                format!("{}=admin", ENV_VAR_MONGODB_INITDB_ROOT_USERNAME),
+                format!("{}=CONTAINER", ENV_VAR_TOOL),
+                "FEATURE_FLAG=on".to_string(),
+                "MONGOT_INDEX_DEFINITIONS=/etc/mongot/indexes.json".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_extra_env_cannot_set_known_variables() {
+        let create_deployment_options = CreateDeploymentOptions {
+            extra_env: Some(HashMap::from([(
+                ENV_VAR_TOOL.to_string(),
+                "OVERRIDE".to_string(),
+            )])),
+            ..Default::default()
+        };
+
+        let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(container_create_body.env, None);
+    }
+
+    #[test]
+    fn test_into_container_create_body_oom_score_adj() {
+        let create_deployment_options = CreateDeploymentOptions {
+            oom_score_adj: Some(-1000),
+            ..Default::default()
+        };
+
+        let container_create_body: ContainerCreateBody =
+            ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.host_config.unwrap().oom_score_adj,
+            Some(-1000)
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_shm_size() {
+        let create_deployment_options = CreateDeploymentOptions {
+            shm_size: Some(1024 * 1024 * 1024),
+            ..Default::default()
+        };
+
+        let container_create_body: ContainerCreateBody =
+            ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.host_config.unwrap().shm_size,
+            Some(1024 * 1024 * 1024)
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_cgroup_parent() {
+        let create_deployment_options = CreateDeploymentOptions {
+            cgroup_parent: Some("tenant-a.slice".to_string()),
+            ..Default::default()
+        };
+
+        let container_create_body: ContainerCreateBody =
+            ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.host_config.unwrap().cgroup_parent,
+            Some("tenant-a.slice".to_string())
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_creation_source_round_trip() {
+        for creation_source in [
+            CreationSource::AtlasCLI,
+            CreationSource::Container,
+            CreationSource::MCPServer,
+            CreationSource::AtlasLocal,
+        ] {
+            let create_deployment_options = CreateDeploymentOptions {
+                creation_source: Some(creation_source.clone()),
+                ..Default::default()
+            };
+
+            // Read the environment back the way a deployment is read from its container
+            let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+            let container_inspect_response = bollard::models::ContainerInspectResponse {
+                config: Some(bollard::models::ContainerConfig {
+                    env: container_create_body.env,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let environment_variables =
+                crate::models::EnvironmentVariables::from(&container_inspect_response);
+
+            assert_eq!(environment_variables.tool, Some(creation_source));
+        }
+    }
+
+    #[test]
+    fn test_into_container_create_body_load_sample_data_round_trip() {
+        for load_sample_data in [Some(true), Some(false), None] {
+            let create_deployment_options = CreateDeploymentOptions {
+                load_sample_data,
+                ..Default::default()
+            };
+
+            let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+            let env = container_create_body.env.clone().unwrap_or_default();
+            assert_eq!(
+                env.iter()
+                    .find(|var| var.starts_with(&format!("{ENV_VAR_MONGODB_LOAD_SAMPLE_DATA}="))),
+                load_sample_data
+                    .map(|load_sample_data| format!(
+                        "{ENV_VAR_MONGODB_LOAD_SAMPLE_DATA}={load_sample_data}"
+                    ))
+                    .as_ref()
+            );
+
+            // Read the deployment back from a container created with this body, the image adds the version labels
+            let mut labels = container_create_body.labels.unwrap_or_default();
+            labels.insert("version".to_string(), "8.0.0".to_string());
+            labels.insert("mongodb-type".to_string(), "community".to_string());
+            let container_inspect_response = bollard::models::ContainerInspectResponse {
+                id: Some("test_container_id".to_string()),
+                config: Some(bollard::models::ContainerConfig {
+                    labels: Some(labels),
+                    env: container_create_body.env,
+                    ..Default::default()
+                }),
+                state: Some(bollard::models::ContainerState {
+                    status: Some(bollard::models::ContainerStateStatusEnum::RUNNING),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let deployment = crate::models::Deployment::try_from(container_inspect_response);
+
+            assert_eq!(
+                deployment.unwrap().mongodb_load_sample_data,
+                load_sample_data
+            );
+        }
+    }
+
+    #[test]
+    fn test_into_container_create_body_voyage_api_key_round_trip() {
+        for voyage_api_key in [Some("voyage-api-key".to_string()), None] {
+            let create_deployment_options = CreateDeploymentOptions {
+                voyage_api_key: voyage_api_key.clone(),
+                ..Default::default()
+            };
+
+            let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+            let container_inspect_response = bollard::models::ContainerInspectResponse {
+                config: Some(bollard::models::ContainerConfig {
+                    env: container_create_body.env,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let environment_variables =
+                crate::models::EnvironmentVariables::from(&container_inspect_response);
+
+            assert_eq!(environment_variables.voyage_api_key, voyage_api_key);
+        }
+    }
+
+    #[test]
+    fn test_into_container_create_body_auto_remove() {
+        let create_deployment_options = CreateDeploymentOptions {
+            auto_remove: Some(true),
+            ..Default::default()
+        };
+
+        let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+        assert_eq!(
+            container_create_body.host_config.unwrap().auto_remove,
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_effective_wait_strategy() {
+        let options = CreateDeploymentOptions::default();
+        assert_eq!(options.effective_wait_strategy(), WaitStrategy::Healthy);
+
+        let options = CreateDeploymentOptions {
+            wait_until_healthy: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(options.effective_wait_strategy(), WaitStrategy::None);
+
+        let options = CreateDeploymentOptions {
+            wait_until_healthy: Some(false),
+            wait_strategy: Some(WaitStrategy::TcpConnect),
+            ..Default::default()
+        };
+        assert_eq!(options.effective_wait_strategy(), WaitStrategy::TcpConnect);
+    }
+
+    #[test]
+    fn test_into_container_create_body_telemetry_disabled() {
+        let create_deployment_options = CreateDeploymentOptions {
+            telemetry: Some(Telemetry::Disabled),
+            ..Default::default()
+        };
+
+        let env_vars = ContainerCreateBody::from(&create_deployment_options)
+            .env
+            .unwrap();
+
+        assert_eq!(env_vars, vec![format!("{}=true", ENV_VAR_DO_NOT_TRACK)]);
+    }
+
+    #[test]
+    fn test_into_container_create_body_telemetry_enabled_with_url() {
+        let create_deployment_options = CreateDeploymentOptions {
+            telemetry: Some(Telemetry::Enabled {
+                base_url: Some("https://telemetry.example.com".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let env_vars = ContainerCreateBody::from(&create_deployment_options)
+            .env
+            .unwrap();
+
+        assert_eq!(
+            env_vars,
+            vec![format!(
+                "{}=https://telemetry.example.com",
+                ENV_VAR_TELEMETRY_BASE_URL
+            )]
+        );
+    }
+
+    #[test]
+    fn test_into_container_create_body_telemetry_enabled_without_url() {
+        let create_deployment_options = CreateDeploymentOptions {
+            telemetry: Some(Telemetry::Enabled { base_url: None }),
+            ..Default::default()
+        };
+
+        let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+        assert!(container_create_body.env.is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_into_container_create_body_telemetry_overrides_deprecated_fields() {
+        let create_deployment_options = CreateDeploymentOptions {
+            telemetry: Some(Telemetry::Disabled),
+            do_not_track: Some(false),
+            telemetry_base_url: Some("https://telemetry.example.com".to_string()),
+            ..Default::default()
+        };
+
+        let env_vars = ContainerCreateBody::from(&create_deployment_options)
+            .env
+            .unwrap();
+
+        assert_eq!(env_vars, vec![format!("{}=true", ENV_VAR_DO_NOT_TRACK)]);
+    }
+
     #[test]
     fn test_into_create_container_options_minimal() {
         // Create a minimal CreateDeploymentOptions with only name set
@@ -378,6 +1410,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_create_deployment_options_default() {
         let options = CreateDeploymentOptions::default();
 
@@ -385,7 +1418,9 @@ mod tests {
         assert!(options.name.is_none());
         assert!(options.image.is_none());
         assert!(options.image_tag.is_none());
+        assert!(options.atlas_local_image.is_none());
         assert!(options.wait_until_healthy.is_none());
+        assert!(options.wait_strategy.is_none());
         assert!(options.wait_until_healthy_timeout.is_none());
         assert!(options.creation_source.is_none());
         assert!(options.local_seed_location.is_none());
@@ -398,9 +1433,13 @@ mod tests {
         assert!(options.load_sample_data.is_none());
         assert!(options.mongot_log_file.is_none());
         assert!(options.runner_log_file.is_none());
+        assert!(options.telemetry.is_none());
         assert!(options.do_not_track.is_none());
         assert!(options.telemetry_base_url.is_none());
         assert!(options.mongodb_port_binding.is_none());
+        assert!(options.oom_score_adj.is_none());
+        assert!(options.shm_size.is_none());
+        assert!(options.cgroup_parent.is_none());
     }
 
     #[test]
@@ -418,4 +1457,178 @@ mod tests {
             Some(format!("{ATLAS_LOCAL_IMAGE}:preview"))
         );
     }
+
+    #[test]
+    fn test_into_container_create_body_pinned_tags() {
+        for (image_tag, expected_tag) in [
+            (ImageTag::try_from("8.0").unwrap(), "8.0"),
+            (ImageTag::Version(semver::Version::new(7, 0, 12)), "7.0.12"),
+            (ImageTag::Custom("8.0-nightly".to_string()), "8.0-nightly"),
+        ] {
+            let create_deployment_options = CreateDeploymentOptions {
+                image_tag: Some(image_tag),
+                ..Default::default()
+            };
+
+            let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+            assert_eq!(
+                container_create_body.image,
+                Some(format!("{ATLAS_LOCAL_IMAGE}:{expected_tag}"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_into_container_create_body_atlas_local_image() {
+        let digest = "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        for (atlas_local_image, expected_image) in [
+            (
+                AtlasLocalImage::default().with_tag(ImageTag::Preview),
+                format!("{ATLAS_LOCAL_IMAGE}:preview"),
+            ),
+            (
+                AtlasLocalImage::new("localhost:5000/atlas-local")
+                    .with_tag(ImageTag::Version(semver::Version::new(8, 0, 4)))
+                    .with_digest(digest)
+                    .unwrap(),
+                format!("localhost:5000/atlas-local:8.0.4@{digest}"),
+            ),
+        ] {
+            // The typed image takes precedence over the raw fields
+            let create_deployment_options = CreateDeploymentOptions {
+                image: Some("mongodb/other".to_string()),
+                image_tag: Some(ImageTag::Latest),
+                atlas_local_image: Some(atlas_local_image),
+                ..Default::default()
+            };
+
+            let container_create_body = ContainerCreateBody::from(&create_deployment_options);
+
+            assert_eq!(container_create_body.image, Some(expected_image));
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let options = CreateDeploymentOptions::builder()
+            .name("deployment_name")
+            .image_tag(ImageTag::Latest)
+            .mongodb_port_binding(MongoDBPortBinding::new(Some(27018), BindingType::Loopback))
+            .mongodb_initdb_root_username("admin")
+            .mongodb_initdb_root_password_file("/run/secrets/password")
+            .wait_until_healthy_timeout(Duration::from_secs(30))
+            .config_file("/host/mongod.conf")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            options,
+            CreateDeploymentOptions {
+                name: Some("deployment_name".to_string()),
+                image_tag: Some(ImageTag::Latest),
+                mongodb_port_binding: Some(MongoDBPortBinding::new(
+                    Some(27018),
+                    BindingType::Loopback
+                )),
+                mongodb_initdb_root_username: Some("admin".to_string()),
+                mongodb_initdb_root_password_file: Some("/run/secrets/password".to_string()),
+                wait_until_healthy_timeout: Some(Duration::from_secs(30)),
+                config_file: Some(PathBuf::from("/host/mongod.conf")),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        assert_eq!(
+            CreateDeploymentOptions::builder().build(),
+            Ok(CreateDeploymentOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_empty_name() {
+        assert_eq!(
+            CreateDeploymentOptions::builder().name("").build(),
+            Err(CreateDeploymentOptionsError::EmptyName)
+        );
+        assert_eq!(
+            CreateDeploymentOptions::builder().name("  ").build(),
+            Err(CreateDeploymentOptionsError::EmptyName)
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_conflicting_root_username() {
+        let result = CreateDeploymentOptions::builder()
+            .mongodb_initdb_root_username("admin")
+            .mongodb_initdb_root_username_file("/run/secrets/username")
+            .build();
+
+        assert_eq!(
+            result,
+            Err(CreateDeploymentOptionsError::ConflictingRootUsername)
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_conflicting_root_password() {
+        let result = CreateDeploymentOptions::builder()
+            .mongodb_initdb_root_password("password123")
+            .mongodb_initdb_root_password_file("/run/secrets/password")
+            .build();
+
+        assert_eq!(
+            result,
+            Err(CreateDeploymentOptionsError::ConflictingRootPassword)
+        );
+    }
+
+    #[test]
+    fn test_data_volume_create_request_named_volume() {
+        let create_deployment_options = CreateDeploymentOptions {
+            data_volume: Some("atlas-local-data".to_string()),
+            ..Default::default()
+        };
+
+        let volume_create_request = create_deployment_options
+            .data_volume_create_request()
+            .unwrap();
+
+        assert_eq!(
+            volume_create_request.name,
+            Some("atlas-local-data".to_string())
+        );
+        assert_eq!(
+            volume_create_request.labels,
+            Some(hashmap! {
+                LOCAL_DEPLOYMENT_LABEL_KEY.to_string() => LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_data_volume_create_request_host_path() {
+        let create_deployment_options = CreateDeploymentOptions {
+            data_volume: Some("/host/data".to_string()),
+            ..Default::default()
+        };
+
+        assert!(
+            create_deployment_options
+                .data_volume_create_request()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_data_volume_create_request_no_data_volume() {
+        assert!(
+            CreateDeploymentOptions::default()
+                .data_volume_create_request()
+                .is_none()
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{convert::Infallible, fmt::Display, str::FromStr};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CreationSource {
@@ -21,6 +21,15 @@ impl From<&str> for CreationSource {
     }
 }
 
+impl FromStr for CreationSource {
+    type Err = Infallible;
+
+    /// Parses the value of the `TOOL` environment variable, unknown values are kept as [`CreationSource::Unknown`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(CreationSource::from(s))
+    }
+}
+
 impl Display for CreationSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -56,7 +65,9 @@ impl<'de> serde::Deserialize<'de> for CreationSource {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "serde")]
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "serde")]
     use serde_json::json;
 
     use super::*;
@@ -94,6 +105,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_creation_source_round_trip() {
+        for source in [
+            CreationSource::AtlasCLI,
+            CreationSource::Container,
+            CreationSource::MCPServer,
+            CreationSource::AtlasLocal,
+            CreationSource::Unknown("custom_source".to_string()),
+        ] {
+            assert_eq!(CreationSource::from(source.to_string().as_str()), source);
+            assert_eq!(source.to_string().parse::<CreationSource>(), Ok(source));
+        }
+    }
+
     #[test]
     fn test_creation_source_to_string_atlascli() {
         let source = CreationSource::AtlasCLI;
@@ -124,6 +149,7 @@ mod tests {
         assert_eq!(source.to_string(), "custom_source");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_serialization() {
         #[derive(Serialize)]
@@ -137,6 +163,7 @@ mod tests {
         assert_eq!(json, json!({"source": "ATLASCLI"}));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_deserialization() {
         #[derive(Debug, Deserialize, PartialEq, Eq)]
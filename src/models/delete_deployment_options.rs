@@ -0,0 +1,23 @@
+/// Options for deleting a deployment, see [`crate::Client::delete_deployment_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::DeleteDeploymentOptions;
+///
+/// let options = DeleteDeploymentOptions::builder()
+///     .force(true)
+///     .remove_volumes(true)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, typed_builder::TypedBuilder)]
+#[builder(doc)]
+pub struct DeleteDeploymentOptions {
+    /// Removes the container even when stopping it failed, Docker kills it if it is still running.
+    #[builder(default = false)]
+    pub force: bool,
+
+    /// Also removes the anonymous volumes of the container, e.g. the data of a deployment without a named volume.
+    #[builder(default = false)]
+    pub remove_volumes: bool,
+}
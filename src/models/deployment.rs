@@ -1,13 +1,37 @@
+use std::collections::HashMap;
+
 use bollard::models::ContainerInspectResponse;
 use semver::Version;
 
 use crate::models::{
-    CreationSource, EnvironmentVariables, GetLocalDeploymentLabelsError,
-    GetMongoDBPortBindingError, GetStateError, LocalDeploymentLabels, MongoDBPortBinding,
-    MongodbType, State,
+    ContainerHealthStatus, CreationSource, ENV_VAR_DO_NOT_TRACK, ENV_VAR_MONGODB_INITDB_DATABASE,
+    ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD, ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE,
+    ENV_VAR_MONGODB_INITDB_ROOT_ROLES, ENV_VAR_MONGODB_INITDB_ROOT_USERNAME,
+    ENV_VAR_MONGODB_INITDB_ROOT_USERNAME_FILE, ENV_VAR_MONGODB_LOAD_SAMPLE_DATA,
+    ENV_VAR_MONGODB_REPLICA_SET_NAME, ENV_VAR_MONGOT_LOG_FILE, ENV_VAR_RUNNER_LOG_FILE,
+    ENV_VAR_TELEMETRY_BASE_URL, ENV_VAR_TOOL, ENV_VAR_VOYAGE_API_KEY, EnvironmentVariables,
+    GetLocalDeploymentLabelsError, GetMongoDBPortBindingError, GetStateError,
+    LocalDeploymentLabels, MongoDBPortBinding, MongodbType, NetworkMode, RootRole, State,
 };
 
 pub const LOCAL_SEED_LOCATION: &str = "/docker-entrypoint-initdb.d";
+/// Directory in which mongod stores its data.
+pub const DATA_DIRECTORY: &str = "/data/db";
+/// Path at which a custom mongod configuration file is mounted.
+pub const CONFIG_FILE_LOCATION: &str = "/etc/mongod.conf";
+/// Directory in which mongod creates its unix domain socket when it is mounted from the host.
+pub const UNIX_SOCKET_LOCATION: &str = "/var/run/mongodb";
+
+/// Returns the file name of the mongod unix domain socket, mongod names it after the port it listens on.
+pub fn unix_socket_file_name(container_port: u16) -> String {
+    format!("mongodb-{container_port}.sock")
+}
+
+/// Number of characters in a short container ID.
+const SHORT_ID_LENGTH: usize = 12;
+
+/// Value used in place of secrets by [`Deployment::redacted_env`].
+pub const REDACTED_ENV_VALUE: &str = "********";
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -18,14 +42,24 @@ pub struct Deployment {
 
     // Docker specific
     pub state: State,
+    /// Status of the container health check, `None` when the container has no health check.
+    pub health: Option<ContainerHealthStatus>,
     pub port_bindings: Option<MongoDBPortBinding>,
+    /// Host directory mounted as the mongod unix socket directory.
+    pub unix_socket_location: Option<String>,
+    /// Docker network mode of the container, e.g. `bridge` or `none`.
+    pub network_mode: Option<String>,
 
     // MongoDB details (MongoD)
     pub mongodb_type: MongodbType,
     pub mongodb_version: Version,
+    /// Name of the replica set, `None` when the image default is used.
+    pub replica_set_name: Option<String>,
 
     // Creation source
     pub creation_source: Option<CreationSource>,
+    /// Version of this crate that created the deployment.
+    pub created_by_version: Option<String>,
 
     // Initial database configuration
     pub local_seed_location: Option<String>,
@@ -34,6 +68,7 @@ pub struct Deployment {
     pub mongodb_initdb_root_password: Option<String>,
     pub mongodb_initdb_root_username_file: Option<String>,
     pub mongodb_initdb_root_username: Option<String>,
+    pub mongodb_initdb_root_roles: Option<Vec<RootRole>>,
     pub mongodb_load_sample_data: Option<bool>,
     pub voyage_api_key: Option<String>,
 
@@ -81,13 +116,25 @@ impl TryFrom<ContainerInspectResponse> for Deployment {
         let container_labels = LocalDeploymentLabels::try_from(&value)?;
         let container_environment_variables = EnvironmentVariables::from(&value);
         let local_seed_location = extract_local_seed_location(&value);
+        let unix_socket_location = extract_mount_source(&value, UNIX_SOCKET_LOCATION);
         let port_bindings = MongoDBPortBinding::try_from(&value)?;
+        let network_mode = value
+            .host_config
+            .as_ref()
+            .and_then(|host_config| host_config.network_mode.clone());
         let state = State::try_from(&value)?;
+        let health = value
+            .state
+            .as_ref()
+            .and_then(|state| state.health.as_ref())
+            .and_then(|health| health.status)
+            .map(ContainerHealthStatus::from);
 
         // Deconstruct the labels and environment variables
         let LocalDeploymentLabels {
             mongodb_version,
             mongodb_type,
+            created_by_version,
         } = container_labels;
 
         let EnvironmentVariables {
@@ -98,11 +145,13 @@ impl TryFrom<ContainerInspectResponse> for Deployment {
             mongodb_initdb_root_password,
             mongodb_initdb_root_password_file,
             mongodb_initdb_database,
+            mongodb_initdb_root_roles,
             mongodb_load_sample_data,
             mongot_log_file,
             do_not_track,
             telemetry_base_url,
             voyage_api_key,
+            replica_set_name,
         } = container_environment_variables;
 
         Ok(Self {
@@ -112,14 +161,19 @@ impl TryFrom<ContainerInspectResponse> for Deployment {
 
             // Docker specific
             state,
+            health,
             port_bindings,
+            unix_socket_location,
+            network_mode,
 
             // MongoDB details (MongoD)
             mongodb_type,
             mongodb_version,
+            replica_set_name,
 
             // Creation source
             creation_source: tool,
+            created_by_version,
 
             // Initial database configuration
             local_seed_location,
@@ -128,6 +182,9 @@ impl TryFrom<ContainerInspectResponse> for Deployment {
             mongodb_initdb_root_password,
             mongodb_initdb_root_username_file,
             mongodb_initdb_root_username,
+            mongodb_initdb_root_roles: mongodb_initdb_root_roles
+                .as_deref()
+                .map(RootRole::parse_list),
             mongodb_load_sample_data: mongodb_load_sample_data.map(is_seeding_true),
             voyage_api_key,
 
@@ -143,15 +200,118 @@ impl TryFrom<ContainerInspectResponse> for Deployment {
     }
 }
 
+impl Deployment {
+    /// Returns the short container ID, the first 12 characters like the Docker CLI displays.
+    ///
+    /// IDs that are already shorter are returned as is.
+    pub fn short_id(&self) -> &str {
+        match self.container_id.char_indices().nth(SHORT_ID_LENGTH) {
+            Some((end, _)) => &self.container_id[..end],
+            None => &self.container_id,
+        }
+    }
+
+    /// Returns true when the health check reports the container as unhealthy,
+    /// or when the container has stopped running ([`State::Exited`] or [`State::Dead`]).
+    pub fn is_unhealthy(&self) -> bool {
+        self.health == Some(ContainerHealthStatus::Unhealthy)
+            || matches!(self.state, State::Exited | State::Dead)
+    }
+
+    /// Returns true when the container is not attached to any network ([`NetworkMode::None`]),
+    /// the deployment can then not be connected to from the host.
+    pub fn is_network_disabled(&self) -> bool {
+        self.network_mode.as_deref() == Some(NetworkMode::None.as_str())
+    }
+
+    /// Returns the environment variables of the deployment with secrets masked.
+    ///
+    /// Passwords and API keys are replaced by [`REDACTED_ENV_VALUE`], all other variables are kept as is.
+    /// Secret files (`*_FILE` variables) are reported by path only, their contents are never read.
+    pub fn redacted_env(&self) -> HashMap<String, String> {
+        self.env_with_secrets(|_| Some(REDACTED_ENV_VALUE.to_string()))
+    }
+
+    /// Returns the environment variables of the deployment, `secret` maps the value of every secret,
+    /// secrets for which it returns `None` are omitted.
+    pub(crate) fn env_with_secrets(
+        &self,
+        secret: impl Fn(&String) -> Option<String>,
+    ) -> HashMap<String, String> {
+        [
+            (
+                ENV_VAR_TOOL,
+                self.creation_source.as_ref().map(ToString::to_string),
+            ),
+            (ENV_VAR_RUNNER_LOG_FILE, self.runner_log_file.clone()),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_USERNAME,
+                self.mongodb_initdb_root_username.clone(),
+            ),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_USERNAME_FILE,
+                self.mongodb_initdb_root_username_file.clone(),
+            ),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD,
+                self.mongodb_initdb_root_password.as_ref().and_then(&secret),
+            ),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE,
+                self.mongodb_initdb_root_password_file.clone(),
+            ),
+            (
+                ENV_VAR_MONGODB_INITDB_DATABASE,
+                self.mongodb_initdb_database.clone(),
+            ),
+            (
+                ENV_VAR_MONGODB_INITDB_ROOT_ROLES,
+                self.mongodb_initdb_root_roles
+                    .as_deref()
+                    .map(RootRole::format_list),
+            ),
+            (
+                ENV_VAR_MONGODB_LOAD_SAMPLE_DATA,
+                self.mongodb_load_sample_data.map(|b| b.to_string()),
+            ),
+            (
+                ENV_VAR_VOYAGE_API_KEY,
+                self.voyage_api_key.as_ref().and_then(&secret),
+            ),
+            (ENV_VAR_MONGOT_LOG_FILE, self.mongot_log_file.clone()),
+            (
+                ENV_VAR_DO_NOT_TRACK,
+                self.do_not_track.then(|| "true".to_string()),
+            ),
+            (ENV_VAR_TELEMETRY_BASE_URL, self.telemetry_base_url.clone()),
+            (
+                ENV_VAR_MONGODB_REPLICA_SET_NAME,
+                self.replica_set_name.clone(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+        .collect()
+    }
+}
+
 fn extract_local_seed_location(
     container_inspect_response: &ContainerInspectResponse,
 ) -> Option<String> {
-    // Go through the mounts and find the one that has the local seed location (mounted at /docker-entrypoint-initdb.d)
+    // The local seed location is mounted at /docker-entrypoint-initdb.d
+    extract_mount_source(container_inspect_response, LOCAL_SEED_LOCATION)
+}
+
+fn extract_mount_source(
+    container_inspect_response: &ContainerInspectResponse,
+    destination: &str,
+) -> Option<String> {
+    // Go through the mounts and find the one mounted at the destination
     let mount = container_inspect_response
         .mounts
         .as_ref()?
         .iter()
-        .find(|m| m.destination.as_deref() == Some(LOCAL_SEED_LOCATION))?;
+        .find(|m| m.destination.as_deref() == Some(destination))?;
 
     // Return the source of the mount
     mount.source.clone()
@@ -177,8 +337,8 @@ fn is_seeding_true(value: impl AsRef<str>) -> bool {
 mod tests {
     use super::*;
     use bollard::models::{
-        ContainerConfig, ContainerState, ContainerStateStatusEnum, MountPoint, NetworkSettings,
-        PortBinding,
+        ContainerConfig, ContainerState, ContainerStateStatusEnum, Health, HealthStatusEnum,
+        HostConfig, MountPoint, NetworkSettings, PortBinding,
     };
     use std::collections::HashMap;
 
@@ -189,6 +349,7 @@ mod tests {
         labels.insert("mongodb-atlas-local".to_string(), "container".to_string());
         labels.insert("version".to_string(), "8.0.0".to_string());
         labels.insert("mongodb-type".to_string(), "community".to_string());
+        labels.insert("atlas-local-lib-version".to_string(), "0.1.0".to_string());
 
         // Create environment variables
         let env_vars = vec![
@@ -198,6 +359,7 @@ mod tests {
             "MONGODB_INITDB_ROOT_PASSWORD=password123".to_string(),
             "MONGODB_INITDB_ROOT_PASSWORD_FILE=/run/secrets/password".to_string(),
             "MONGODB_INITDB_DATABASE=testdb".to_string(),
+            "MONGODB_INITDB_ROOT_ROLES=root,readWrite@app".to_string(),
             "RUNNER_LOG_FILE=/tmp/runner.log".to_string(),
             "MONGOT_LOG_FILE=/tmp/mongot.log".to_string(),
             "TELEMETRY_BASE_URL=https://telemetry.example.com".to_string(),
@@ -241,6 +403,10 @@ mod tests {
             mounts: Some(vec![mount]),
             state: Some(container_state),
             network_settings: Some(network_settings),
+            host_config: Some(HostConfig {
+                network_mode: Some("bridge".to_string()),
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -250,6 +416,9 @@ mod tests {
         assert_eq!(deployment.container_id, "container_id");
         assert_eq!(deployment.name, Some("test-deployment".to_string()));
         assert_eq!(deployment.state, State::Running);
+        assert_eq!(deployment.health, None);
+        assert_eq!(deployment.network_mode, Some("bridge".to_string()));
+        assert!(!deployment.is_network_disabled());
         assert!(deployment.port_bindings.is_some());
         let port_binding = deployment.port_bindings.unwrap();
         assert_eq!(port_binding.port, Some(27017));
@@ -258,6 +427,7 @@ mod tests {
             crate::models::BindingType::Loopback
         );
         assert_eq!(deployment.creation_source, Some(CreationSource::AtlasCLI));
+        assert_eq!(deployment.created_by_version, Some("0.1.0".to_string()));
         assert_eq!(deployment.mongodb_type, MongodbType::Community);
         assert_eq!(deployment.mongodb_version, Version::new(8, 0, 0));
         assert_eq!(
@@ -284,6 +454,13 @@ mod tests {
             deployment.mongodb_initdb_root_password_file,
             Some("/run/secrets/password".to_string())
         );
+        assert_eq!(
+            deployment.mongodb_initdb_root_roles,
+            Some(vec![
+                RootRole::new("root", None),
+                RootRole::new("readWrite", Some("app".to_string())),
+            ])
+        );
         assert_eq!(
             deployment.runner_log_file,
             Some("/tmp/runner.log".to_string())
@@ -304,6 +481,298 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_root_roles_round_trip() {
+        let roles = vec![
+            RootRole::new("root", None),
+            RootRole::new("readWrite", Some("app".to_string())),
+        ];
+        let create_deployment_options = crate::models::CreateDeploymentOptions {
+            mongodb_initdb_root_roles: Some(roles.clone()),
+            ..Default::default()
+        };
+
+        // Create the container from the options, then read the deployment back
+        let container_create_body =
+            bollard::models::ContainerCreateBody::from(&create_deployment_options);
+        let container_inspect_response = ContainerInspectResponse {
+            id: Some("container_id".to_string()),
+            config: Some(ContainerConfig {
+                env: container_create_body.env,
+                labels: Some(HashMap::from([
+                    ("mongodb-atlas-local".to_string(), "container".to_string()),
+                    ("version".to_string(), "8.0.0".to_string()),
+                    ("mongodb-type".to_string(), "community".to_string()),
+                ])),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let deployment = Deployment::try_from(container_inspect_response).unwrap();
+
+        assert_eq!(deployment.mongodb_initdb_root_roles, Some(roles));
+        assert_eq!(
+            deployment
+                .redacted_env()
+                .get(ENV_VAR_MONGODB_INITDB_ROOT_ROLES),
+            Some(&"root,readWrite@app".to_string())
+        );
+    }
+
+    fn create_deployment_with_secrets() -> Deployment {
+        Deployment {
+            container_id: "container_id".to_string(),
+            name: Some("test-deployment".to_string()),
+            state: State::Running,
+            health: None,
+            port_bindings: None,
+            unix_socket_location: None,
+            network_mode: None,
+            mongodb_type: MongodbType::Community,
+            mongodb_version: Version::new(8, 0, 0),
+            replica_set_name: None,
+            creation_source: Some(CreationSource::AtlasCLI),
+            created_by_version: None,
+            local_seed_location: None,
+            mongodb_initdb_database: Some("testdb".to_string()),
+            mongodb_initdb_root_password_file: Some("/run/secrets/password".to_string()),
+            mongodb_initdb_root_password: Some("password123".to_string()),
+            mongodb_initdb_root_username_file: None,
+            mongodb_initdb_root_username: Some("admin".to_string()),
+            mongodb_initdb_root_roles: None,
+            mongodb_load_sample_data: Some(true),
+            voyage_api_key: Some("voyage-api-key".to_string()),
+            mongot_log_file: None,
+            runner_log_file: Some("/tmp/runner.log".to_string()),
+            do_not_track: true,
+            telemetry_base_url: None,
+        }
+    }
+
+    #[test]
+    fn test_into_deployment_health() {
+        let container_inspect_response = |status| ContainerInspectResponse {
+            id: Some("container_id".to_string()),
+            config: Some(ContainerConfig {
+                labels: Some(HashMap::from([
+                    ("mongodb-atlas-local".to_string(), "container".to_string()),
+                    ("version".to_string(), "8.0.0".to_string()),
+                    ("mongodb-type".to_string(), "community".to_string()),
+                ])),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                health: Some(Health {
+                    status: Some(status),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        for (status, expected_health) in [
+            (HealthStatusEnum::HEALTHY, ContainerHealthStatus::Healthy),
+            (HealthStatusEnum::STARTING, ContainerHealthStatus::Starting),
+            (
+                HealthStatusEnum::UNHEALTHY,
+                ContainerHealthStatus::Unhealthy,
+            ),
+        ] {
+            let deployment = Deployment::try_from(container_inspect_response(status)).unwrap();
+
+            assert_eq!(deployment.health, Some(expected_health));
+        }
+    }
+
+    #[test]
+    fn test_replica_set_name_round_trip() {
+        let create_deployment_options = crate::models::CreateDeploymentOptions {
+            replica_set_name: Some("rs-app".to_string()),
+            ..Default::default()
+        };
+
+        // Create the container from the options, then read the deployment back
+        let container_create_body =
+            bollard::models::ContainerCreateBody::from(&create_deployment_options);
+        let container_inspect_response = ContainerInspectResponse {
+            id: Some("container_id".to_string()),
+            config: Some(ContainerConfig {
+                env: container_create_body.env,
+                labels: Some(HashMap::from([
+                    ("mongodb-atlas-local".to_string(), "container".to_string()),
+                    ("version".to_string(), "8.0.0".to_string()),
+                    ("mongodb-type".to_string(), "community".to_string()),
+                ])),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                status: Some(ContainerStateStatusEnum::RUNNING),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let deployment = Deployment::try_from(container_inspect_response).unwrap();
+
+        assert_eq!(deployment.replica_set_name, Some("rs-app".to_string()));
+        assert_eq!(
+            deployment
+                .redacted_env()
+                .get(ENV_VAR_MONGODB_REPLICA_SET_NAME),
+            Some(&"rs-app".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let deployment = Deployment {
+            container_id: "container_id".to_string(),
+            name: Some("test-deployment".to_string()),
+            state: State::Running,
+            health: Some(ContainerHealthStatus::Healthy),
+            port_bindings: Some(MongoDBPortBinding::new(
+                Some(27017),
+                crate::models::BindingType::Specific {
+                    ip: "192.168.1.10".parse().unwrap(),
+                },
+            )),
+            unix_socket_location: Some("/host/sockets".to_string()),
+            network_mode: Some("bridge".to_string()),
+            mongodb_type: MongodbType::Enterprise,
+            mongodb_version: Version::parse("8.0.4-rc1").unwrap(),
+            replica_set_name: Some("rs-app".to_string()),
+            creation_source: Some(CreationSource::Unknown("MY_TOOL".to_string())),
+            created_by_version: Some("0.1.0".to_string()),
+            local_seed_location: Some("/host/seed-data".to_string()),
+            mongodb_initdb_database: Some("testdb".to_string()),
+            mongodb_initdb_root_password_file: None,
+            mongodb_initdb_root_password: Some("password123".to_string()),
+            mongodb_initdb_root_username_file: None,
+            mongodb_initdb_root_username: Some("admin".to_string()),
+            mongodb_initdb_root_roles: Some(vec![RootRole::new(
+                "readWrite",
+                Some("app".to_string()),
+            )]),
+            mongodb_load_sample_data: Some(true),
+            voyage_api_key: None,
+            mongot_log_file: Some("/tmp/mongot.log".to_string()),
+            runner_log_file: None,
+            do_not_track: true,
+            telemetry_base_url: None,
+        };
+
+        let json = serde_json::to_value(&deployment).unwrap();
+
+        assert_eq!(json["state"], "running");
+        assert_eq!(json["mongodb_version"], "8.0.4-rc1");
+        assert_eq!(json["creation_source"], "MY_TOOL");
+        assert_eq!(
+            serde_json::from_value::<Deployment>(json).unwrap(),
+            deployment
+        );
+    }
+
+    #[test]
+    fn test_is_unhealthy() {
+        let with = |state, health| Deployment {
+            state,
+            health,
+            ..create_deployment_with_secrets()
+        };
+
+        assert!(!with(State::Running, None).is_unhealthy());
+        assert!(!with(State::Running, Some(ContainerHealthStatus::Healthy)).is_unhealthy());
+        assert!(!with(State::Running, Some(ContainerHealthStatus::Starting)).is_unhealthy());
+        assert!(with(State::Running, Some(ContainerHealthStatus::Unhealthy)).is_unhealthy());
+        assert!(with(State::Exited, None).is_unhealthy());
+        assert!(with(State::Dead, None).is_unhealthy());
+    }
+
+    #[test]
+    fn test_short_id() {
+        let deployment = Deployment {
+            container_id: "4f66ad9a0b2e4b1c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c"
+                .to_string(),
+            ..create_deployment_with_secrets()
+        };
+
+        assert_eq!(deployment.short_id(), "4f66ad9a0b2e");
+    }
+
+    #[test]
+    fn test_short_id_already_short() {
+        let deployment = Deployment {
+            container_id: "4f66ad9a".to_string(),
+            ..create_deployment_with_secrets()
+        };
+        assert_eq!(deployment.short_id(), "4f66ad9a");
+
+        let deployment = Deployment {
+            container_id: "4f66ad9a0b2e".to_string(),
+            ..create_deployment_with_secrets()
+        };
+        assert_eq!(deployment.short_id(), "4f66ad9a0b2e");
+    }
+
+    #[test]
+    fn test_redacted_env_masks_secrets() {
+        let env = create_deployment_with_secrets().redacted_env();
+
+        assert_eq!(
+            env.get(ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD),
+            Some(&REDACTED_ENV_VALUE.to_string())
+        );
+        assert_eq!(
+            env.get(ENV_VAR_VOYAGE_API_KEY),
+            Some(&REDACTED_ENV_VALUE.to_string())
+        );
+        assert!(
+            !env.values()
+                .any(|v| v == "password123" || v == "voyage-api-key")
+        );
+    }
+
+    #[test]
+    fn test_redacted_env_keeps_safe_values() {
+        let env = create_deployment_with_secrets().redacted_env();
+
+        assert_eq!(env.get(ENV_VAR_TOOL), Some(&"ATLASCLI".to_string()));
+        assert_eq!(
+            env.get(ENV_VAR_MONGODB_INITDB_ROOT_USERNAME),
+            Some(&"admin".to_string())
+        );
+        assert_eq!(
+            env.get(ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE),
+            Some(&"/run/secrets/password".to_string())
+        );
+        assert_eq!(
+            env.get(ENV_VAR_MONGODB_INITDB_DATABASE),
+            Some(&"testdb".to_string())
+        );
+        assert_eq!(
+            env.get(ENV_VAR_MONGODB_LOAD_SAMPLE_DATA),
+            Some(&"true".to_string())
+        );
+        assert_eq!(
+            env.get(ENV_VAR_RUNNER_LOG_FILE),
+            Some(&"/tmp/runner.log".to_string())
+        );
+        assert_eq!(env.get(ENV_VAR_DO_NOT_TRACK), Some(&"true".to_string()));
+
+        // Unset variables are omitted
+        assert!(!env.contains_key(ENV_VAR_MONGOT_LOG_FILE));
+        assert!(!env.contains_key(ENV_VAR_TELEMETRY_BASE_URL));
+        assert_eq!(env.len(), 9);
+    }
+
     #[test]
     fn test_extract_local_seed_location_no_mounts() {
         let container_inspect_response = ContainerInspectResponse {
@@ -365,6 +834,28 @@ mod tests {
         assert_eq!(result, Some("/host/seed-data".to_string()));
     }
 
+    #[test]
+    fn test_extract_unix_socket_location() {
+        let seed_mount = MountPoint {
+            destination: Some(LOCAL_SEED_LOCATION.to_string()),
+            source: Some("/host/seed-data".to_string()),
+            ..Default::default()
+        };
+        let socket_mount = MountPoint {
+            destination: Some(UNIX_SOCKET_LOCATION.to_string()),
+            source: Some("/host/sockets".to_string()),
+            ..Default::default()
+        };
+
+        let container_inspect_response = ContainerInspectResponse {
+            mounts: Some(vec![seed_mount, socket_mount]),
+            ..Default::default()
+        };
+
+        let result = extract_mount_source(&container_inspect_response, UNIX_SOCKET_LOCATION);
+        assert_eq!(result, Some("/host/sockets".to_string()));
+    }
+
     #[test]
     fn test_is_seeding_true() {
         // True values
@@ -0,0 +1,212 @@
+use std::collections::BTreeSet;
+
+use bollard::models::PortBinding;
+
+use crate::models::{Deployment, MongoDBPortBinding};
+
+/// A single field that differs between two deployments.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Name of the field, environment variables are reported as `env.<NAME>`.
+    pub field: String,
+    /// Value on the first deployment, `None` when not set.
+    pub left: Option<String>,
+    /// Value on the second deployment, `None` when not set.
+    pub right: Option<String>,
+}
+
+/// The field-level differences between two deployments.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeploymentDiff {
+    pub differences: Vec<FieldDiff>,
+}
+
+impl DeploymentDiff {
+    /// Returns true when no differences were found.
+    pub fn is_identical(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    fn compare(&mut self, field: impl Into<String>, left: Option<String>, right: Option<String>) {
+        if left != right {
+            self.differences.push(FieldDiff {
+                field: field.into(),
+                left,
+                right,
+            });
+        }
+    }
+}
+
+impl Deployment {
+    /// Compares the configuration of two deployments field by field.
+    ///
+    /// The identity (container ID and name) and the runtime state are not compared.
+    /// Secrets are compared in their redacted form, see [`Deployment::redacted_env`].
+    pub fn diff(&self, other: &Deployment) -> DeploymentDiff {
+        let mut diff = DeploymentDiff::default();
+
+        diff.compare(
+            "mongodb_type",
+            Some(self.mongodb_type.to_string()),
+            Some(other.mongodb_type.to_string()),
+        );
+        diff.compare(
+            "mongodb_version",
+            Some(self.mongodb_version.to_string()),
+            Some(other.mongodb_version.to_string()),
+        );
+        diff.compare(
+            "port_bindings",
+            self.port_bindings.as_ref().map(format_port_binding),
+            other.port_bindings.as_ref().map(format_port_binding),
+        );
+        diff.compare(
+            "unix_socket_location",
+            self.unix_socket_location.clone(),
+            other.unix_socket_location.clone(),
+        );
+        diff.compare(
+            "network_mode",
+            self.network_mode.clone(),
+            other.network_mode.clone(),
+        );
+        diff.compare(
+            "local_seed_location",
+            self.local_seed_location.clone(),
+            other.local_seed_location.clone(),
+        );
+        diff.compare(
+            "created_by_version",
+            self.created_by_version.clone(),
+            other.created_by_version.clone(),
+        );
+
+        // Compare the environment variables in a stable order
+        let mut left_env = self.redacted_env();
+        let mut right_env = other.redacted_env();
+        let keys = left_env
+            .keys()
+            .chain(right_env.keys())
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        for key in keys {
+            diff.compare(
+                format!("env.{key}"),
+                left_env.remove(&key),
+                right_env.remove(&key),
+            );
+        }
+
+        diff
+    }
+}
+
+fn format_port_binding(port_binding: &MongoDBPortBinding) -> String {
+    let PortBinding { host_ip, host_port } = PortBinding::from(port_binding);
+
+    format!(
+        "{}:{}",
+        host_ip.unwrap_or_default(),
+        host_port.unwrap_or_default()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::*;
+    use crate::models::{BindingType, CreationSource, MongodbType, State};
+
+    fn create_test_deployment() -> Deployment {
+        Deployment {
+            container_id: "container1".to_string(),
+            name: Some("deployment1".to_string()),
+            state: State::Running,
+            health: None,
+            port_bindings: Some(MongoDBPortBinding::new(Some(27017), BindingType::Loopback)),
+            unix_socket_location: None,
+            network_mode: None,
+            mongodb_type: MongodbType::Community,
+            mongodb_version: Version::new(8, 0, 0),
+            replica_set_name: None,
+            creation_source: Some(CreationSource::AtlasCLI),
+            created_by_version: None,
+            local_seed_location: None,
+            mongodb_initdb_database: None,
+            mongodb_initdb_root_password_file: None,
+            mongodb_initdb_root_password: Some("password123".to_string()),
+            mongodb_initdb_root_username_file: None,
+            mongodb_initdb_root_username: Some("admin".to_string()),
+            mongodb_initdb_root_roles: None,
+            mongodb_load_sample_data: None,
+            voyage_api_key: None,
+            mongot_log_file: None,
+            runner_log_file: None,
+            do_not_track: false,
+            telemetry_base_url: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let left = create_test_deployment();
+        let right = Deployment {
+            container_id: "container2".to_string(),
+            name: Some("deployment2".to_string()),
+            state: State::Exited,
+            mongodb_initdb_root_password: Some("another-password".to_string()),
+            ..create_test_deployment()
+        };
+
+        let diff = left.diff(&right);
+
+        assert!(diff.is_identical());
+    }
+
+    #[test]
+    fn test_diff_differences() {
+        let left = create_test_deployment();
+        let right = Deployment {
+            port_bindings: Some(MongoDBPortBinding::new(
+                Some(27018),
+                BindingType::AnyInterface,
+            )),
+            mongodb_version: Version::new(7, 0, 12),
+            mongodb_initdb_root_username: None,
+            mongodb_initdb_database: Some("testdb".to_string()),
+            ..create_test_deployment()
+        };
+
+        let diff = left.diff(&right);
+
+        assert_eq!(
+            diff.differences,
+            vec![
+                FieldDiff {
+                    field: "mongodb_version".to_string(),
+                    left: Some("8.0.0".to_string()),
+                    right: Some("7.0.12".to_string()),
+                },
+                FieldDiff {
+                    field: "port_bindings".to_string(),
+                    left: Some("127.0.0.1:27017".to_string()),
+                    right: Some("0.0.0.0:27018".to_string()),
+                },
+                FieldDiff {
+                    field: "env.MONGODB_INITDB_DATABASE".to_string(),
+                    left: None,
+                    right: Some("testdb".to_string()),
+                },
+                FieldDiff {
+                    field: "env.MONGODB_INITDB_ROOT_USERNAME".to_string(),
+                    left: Some("admin".to_string()),
+                    right: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use bollard::models::{EventMessage, EventMessageTypeEnum};
+use chrono::{DateTime, Utc};
+
+use crate::models::ContainerHealthStatus;
+
+/// A Docker event about the container of a deployment, see [`crate::Client::subscribe_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentEvent {
+    pub container_id: String,
+    pub action: DeploymentEventAction,
+    /// When Docker emitted the event.
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// What happened to the container of a deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentEventAction {
+    Create,
+    Start,
+    Restart,
+    Pause,
+    Unpause,
+    Stop,
+    Kill,
+    /// The main process of the container exited.
+    Die,
+    /// The container was removed.
+    Destroy,
+    /// The health check of the container reported a new status.
+    HealthStatus(ContainerHealthStatus),
+    /// Any other action Docker reports for containers, e.g. `exec_start: sh`.
+    Other(String),
+}
+
+impl From<&str> for DeploymentEventAction {
+    fn from(action: &str) -> Self {
+        match action {
+            "create" => DeploymentEventAction::Create,
+            "start" => DeploymentEventAction::Start,
+            "restart" => DeploymentEventAction::Restart,
+            "pause" => DeploymentEventAction::Pause,
+            "unpause" => DeploymentEventAction::Unpause,
+            "stop" => DeploymentEventAction::Stop,
+            "kill" => DeploymentEventAction::Kill,
+            "die" => DeploymentEventAction::Die,
+            "destroy" => DeploymentEventAction::Destroy,
+            "health_status: healthy" => {
+                DeploymentEventAction::HealthStatus(ContainerHealthStatus::Healthy)
+            }
+            "health_status: unhealthy" => {
+                DeploymentEventAction::HealthStatus(ContainerHealthStatus::Unhealthy)
+            }
+            "health_status: starting" => {
+                DeploymentEventAction::HealthStatus(ContainerHealthStatus::Starting)
+            }
+            other => DeploymentEventAction::Other(other.to_string()),
+        }
+    }
+}
+
+impl DeploymentEvent {
+    /// Converts a Docker event, `None` when it is not about a container.
+    pub(crate) fn from_event_message(event_message: EventMessage) -> Option<Self> {
+        if event_message.typ != Some(EventMessageTypeEnum::CONTAINER) {
+            return None;
+        }
+
+        let container_id = event_message.actor?.id?;
+        let action = DeploymentEventAction::from(event_message.action.as_deref()?);
+        let time = event_message
+            .time_nano
+            .map(DateTime::from_timestamp_nanos)
+            .or_else(|| {
+                event_message
+                    .time
+                    .and_then(|time| DateTime::from_timestamp(time, 0))
+            });
+
+        Some(DeploymentEvent {
+            container_id,
+            action,
+            time,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::EventActor;
+
+    #[test]
+    fn test_deployment_event_action_from_str() {
+        assert_eq!(
+            DeploymentEventAction::from("start"),
+            DeploymentEventAction::Start
+        );
+        assert_eq!(
+            DeploymentEventAction::from("health_status: unhealthy"),
+            DeploymentEventAction::HealthStatus(ContainerHealthStatus::Unhealthy)
+        );
+        assert_eq!(
+            DeploymentEventAction::from("exec_start: sh"),
+            DeploymentEventAction::Other("exec_start: sh".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_event_message() {
+        let event_message = EventMessage {
+            typ: Some(EventMessageTypeEnum::CONTAINER),
+            action: Some("die".to_string()),
+            actor: Some(EventActor {
+                id: Some("container_id".to_string()),
+                attributes: None,
+            }),
+            time: Some(1_735_689_600),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            DeploymentEvent::from_event_message(event_message),
+            Some(DeploymentEvent {
+                container_id: "container_id".to_string(),
+                action: DeploymentEventAction::Die,
+                time: DateTime::from_timestamp(1_735_689_600, 0),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_event_message_not_a_container() {
+        let event_message = EventMessage {
+            typ: Some(EventMessageTypeEnum::NETWORK),
+            action: Some("connect".to_string()),
+            actor: Some(EventActor {
+                id: Some("network_id".to_string()),
+                attributes: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(DeploymentEvent::from_event_message(event_message), None);
+    }
+}
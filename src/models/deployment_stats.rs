@@ -0,0 +1,213 @@
+use bollard::models::{ContainerCpuStats, ContainerStatsResponse};
+
+/// Resource usage of a deployment, see [`crate::Client::get_stats`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeploymentStats {
+    /// CPU usage since the previous sample, 100% is one full CPU, e.g. 250.0 when using two and a half CPUs.
+    pub cpu_percent: f64,
+    /// Memory used by the container, without the page cache it could free, like `docker stats` reports it.
+    pub memory_usage_bytes: u64,
+    /// Memory the container is allowed to use, the memory of the host when it is not limited.
+    pub memory_limit_bytes: u64,
+    /// Bytes received on all network interfaces of the container.
+    pub network_rx_bytes: u64,
+    /// Bytes sent on all network interfaces of the container.
+    pub network_tx_bytes: u64,
+}
+
+impl From<&ContainerStatsResponse> for DeploymentStats {
+    fn from(stats: &ContainerStatsResponse) -> Self {
+        let cpu_percent = match (&stats.cpu_stats, &stats.precpu_stats) {
+            (Some(cpu_stats), Some(precpu_stats)) => cpu_percent(cpu_stats, precpu_stats),
+            _ => 0.0,
+        };
+
+        let memory_stats = stats.memory_stats.as_ref();
+        // The inactive page cache can be reclaimed, cgroup v1 and v2 report it under different names
+        let inactive_file = memory_stats
+            .and_then(|memory_stats| memory_stats.stats.as_ref())
+            .and_then(|stats| {
+                stats
+                    .get("total_inactive_file")
+                    .or_else(|| stats.get("inactive_file"))
+            })
+            .copied()
+            .unwrap_or_default();
+        let memory_usage_bytes = memory_stats
+            .and_then(|memory_stats| memory_stats.usage)
+            .unwrap_or_default()
+            .saturating_sub(inactive_file);
+        let memory_limit_bytes = memory_stats
+            .and_then(|memory_stats| memory_stats.limit)
+            .unwrap_or_default();
+
+        let networks = stats.networks.iter().flatten().map(|(_, network)| network);
+        let (network_rx_bytes, network_tx_bytes) =
+            networks.fold((0, 0), |(rx_bytes, tx_bytes), network| {
+                (
+                    rx_bytes + network.rx_bytes.unwrap_or_default(),
+                    tx_bytes + network.tx_bytes.unwrap_or_default(),
+                )
+            });
+
+        DeploymentStats {
+            cpu_percent,
+            memory_usage_bytes,
+            memory_limit_bytes,
+            network_rx_bytes,
+            network_tx_bytes,
+        }
+    }
+}
+
+/// Computes the CPU usage between two samples the way `docker stats` does, 0 when there is no previous sample.
+fn cpu_percent(cpu_stats: &ContainerCpuStats, precpu_stats: &ContainerCpuStats) -> f64 {
+    let total_usage = |stats: &ContainerCpuStats| {
+        stats
+            .cpu_usage
+            .as_ref()
+            .and_then(|cpu_usage| cpu_usage.total_usage)
+            .unwrap_or_default()
+    };
+
+    // Docker leaves the previous sample empty when it only took one
+    if precpu_stats.system_cpu_usage.unwrap_or_default() == 0 {
+        return 0.0;
+    }
+
+    let cpu_delta = total_usage(cpu_stats).saturating_sub(total_usage(precpu_stats));
+    let system_delta = cpu_stats
+        .system_cpu_usage
+        .unwrap_or_default()
+        .saturating_sub(precpu_stats.system_cpu_usage.unwrap_or_default());
+    if cpu_delta == 0 || system_delta == 0 {
+        return 0.0;
+    }
+
+    // Older daemons only report the usage per CPU
+    let online_cpus = cpu_stats
+        .online_cpus
+        .map(|online_cpus| online_cpus as usize)
+        .or_else(|| {
+            cpu_stats
+                .cpu_usage
+                .as_ref()
+                .and_then(|cpu_usage| cpu_usage.percpu_usage.as_ref())
+                .map(Vec::len)
+        })
+        .filter(|online_cpus| *online_cpus > 0)
+        .unwrap_or(1);
+
+    cpu_delta as f64 / system_delta as f64 * online_cpus as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ContainerCpuUsage, ContainerMemoryStats, ContainerNetworkStats};
+    use maplit::hashmap;
+
+    fn cpu_stats(
+        total_usage: u64,
+        system_cpu_usage: u64,
+        online_cpus: Option<u32>,
+    ) -> ContainerCpuStats {
+        ContainerCpuStats {
+            cpu_usage: Some(ContainerCpuUsage {
+                total_usage: Some(total_usage),
+                percpu_usage: Some(vec![0; 4]),
+                ..Default::default()
+            }),
+            system_cpu_usage: Some(system_cpu_usage),
+            online_cpus,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cpu_percent() {
+        // 200ms of CPU time while the system used 1s over 2 CPUs is 40% of one CPU
+        assert_eq!(
+            cpu_percent(
+                &cpu_stats(1_200_000_000, 11_000_000_000, Some(2)),
+                &cpu_stats(1_000_000_000, 10_000_000_000, Some(2)),
+            ),
+            40.0
+        );
+        // Without online CPUs the number of per CPU usages is used
+        assert_eq!(
+            cpu_percent(
+                &cpu_stats(1_200_000_000, 11_000_000_000, None),
+                &cpu_stats(1_000_000_000, 10_000_000_000, None),
+            ),
+            80.0
+        );
+    }
+
+    #[test]
+    fn test_cpu_percent_without_previous_sample() {
+        assert_eq!(
+            cpu_percent(
+                &cpu_stats(1_200_000_000, 11_000_000_000, Some(2)),
+                &ContainerCpuStats::default(),
+            ),
+            0.0
+        );
+        assert_eq!(
+            cpu_percent(
+                &cpu_stats(1_000_000_000, 10_000_000_000, Some(2)),
+                &cpu_stats(1_000_000_000, 10_000_000_000, Some(2)),
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_from_container_stats_response() {
+        let stats = ContainerStatsResponse {
+            cpu_stats: Some(cpu_stats(1_500_000_000, 11_000_000_000, Some(4))),
+            precpu_stats: Some(cpu_stats(1_000_000_000, 10_000_000_000, Some(4))),
+            memory_stats: Some(ContainerMemoryStats {
+                usage: Some(300 * 1024 * 1024),
+                limit: Some(2 * 1024 * 1024 * 1024),
+                stats: Some(hashmap! {
+                    "inactive_file".to_string() => 100 * 1024 * 1024,
+                }),
+                ..Default::default()
+            }),
+            networks: Some(hashmap! {
+                "eth0".to_string() => ContainerNetworkStats {
+                    rx_bytes: Some(1000),
+                    tx_bytes: Some(2000),
+                    ..Default::default()
+                },
+                "eth1".to_string() => ContainerNetworkStats {
+                    rx_bytes: Some(10),
+                    tx_bytes: Some(20),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            DeploymentStats::from(&stats),
+            DeploymentStats {
+                cpu_percent: 200.0,
+                memory_usage_bytes: 200 * 1024 * 1024,
+                memory_limit_bytes: 2 * 1024 * 1024 * 1024,
+                network_rx_bytes: 1010,
+                network_tx_bytes: 2020,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_empty_container_stats_response() {
+        assert_eq!(
+            DeploymentStats::from(&ContainerStatsResponse::default()),
+            DeploymentStats::default()
+        );
+    }
+}
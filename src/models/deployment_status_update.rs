@@ -0,0 +1,16 @@
+use crate::models::{ContainerHealthStatus, State};
+
+/// The state and health of a deployment after one of them changed, see [`crate::Client::watch_deployment_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentStatusUpdate {
+    pub state: State,
+    /// Status of the container health check, `None` when the container has no health check.
+    pub health: Option<ContainerHealthStatus>,
+}
+
+impl DeploymentStatusUpdate {
+    /// Returns true when the container stopped, no further updates follow.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.state, State::Exited | State::Dead)
+    }
+}
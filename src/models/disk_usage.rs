@@ -0,0 +1,18 @@
+/// Disk space used by local Atlas deployments, in bytes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    /// Writable layers of the deployment containers.
+    pub containers: u64,
+    /// Atlas Local images and the images deployments run on.
+    pub images: u64,
+    /// Volumes mounted by deployments, e.g. data volumes.
+    pub volumes: u64,
+}
+
+impl DiskUsage {
+    /// Returns the disk space used by all categories together.
+    pub fn total(&self) -> u64 {
+        self.containers + self.images + self.volumes
+    }
+}
@@ -11,11 +11,13 @@ pub const ENV_VAR_MONGODB_INITDB_ROOT_USERNAME_FILE: &str = "MONGODB_INITDB_ROOT
 pub const ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD: &str = "MONGODB_INITDB_ROOT_PASSWORD";
 pub const ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE: &str = "MONGODB_INITDB_ROOT_PASSWORD_FILE";
 pub const ENV_VAR_MONGODB_INITDB_DATABASE: &str = "MONGODB_INITDB_DATABASE";
+pub const ENV_VAR_MONGODB_INITDB_ROOT_ROLES: &str = "MONGODB_INITDB_ROOT_ROLES";
 pub const ENV_VAR_MONGOT_LOG_FILE: &str = "MONGOT_LOG_FILE";
 pub const ENV_VAR_DO_NOT_TRACK: &str = "DO_NOT_TRACK";
 pub const ENV_VAR_TELEMETRY_BASE_URL: &str = "TELEMETRY_BASE_URL";
 pub const ENV_VAR_MONGODB_LOAD_SAMPLE_DATA: &str = "MONGODB_LOAD_SAMPLE_DATA";
 pub const ENV_VAR_VOYAGE_API_KEY: &str = "VOYAGE_API_KEY";
+pub const ENV_VAR_MONGODB_REPLICA_SET_NAME: &str = "MONGODB_REPLICA_SET_NAME";
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -32,6 +34,8 @@ pub struct EnvironmentVariables {
 
     pub mongodb_initdb_database: Option<String>,
 
+    pub mongodb_initdb_root_roles: Option<String>,
+
     pub mongot_log_file: Option<String>,
 
     pub do_not_track: Option<String>,
@@ -41,6 +45,8 @@ pub struct EnvironmentVariables {
     pub mongodb_load_sample_data: Option<String>,
 
     pub voyage_api_key: Option<String>,
+
+    pub replica_set_name: Option<String>,
 }
 
 impl From<&ContainerInspectResponse> for EnvironmentVariables {
@@ -75,12 +81,15 @@ impl From<&ContainerInspectResponse> for EnvironmentVariables {
             get_value(&env, ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE);
         environment_variables.mongodb_initdb_database =
             get_value(&env, ENV_VAR_MONGODB_INITDB_DATABASE);
+        environment_variables.mongodb_initdb_root_roles =
+            get_value(&env, ENV_VAR_MONGODB_INITDB_ROOT_ROLES);
         environment_variables.mongot_log_file = get_value(&env, ENV_VAR_MONGOT_LOG_FILE);
         environment_variables.do_not_track = get_value(&env, ENV_VAR_DO_NOT_TRACK);
         environment_variables.telemetry_base_url = get_value(&env, ENV_VAR_TELEMETRY_BASE_URL);
         environment_variables.mongodb_load_sample_data =
             get_value(&env, ENV_VAR_MONGODB_LOAD_SAMPLE_DATA);
         environment_variables.voyage_api_key = get_value(&env, ENV_VAR_VOYAGE_API_KEY);
+        environment_variables.replica_set_name = get_value(&env, ENV_VAR_MONGODB_REPLICA_SET_NAME);
 
         environment_variables
     }
@@ -120,6 +129,7 @@ mod tests {
         assert_eq!(env_vars.do_not_track, None);
         assert_eq!(env_vars.telemetry_base_url, None);
         assert_eq!(env_vars.mongodb_load_sample_data, None);
+        assert_eq!(env_vars.replica_set_name, None);
     }
 
     #[test]
@@ -147,6 +157,7 @@ mod tests {
         assert_eq!(env_vars.do_not_track, None);
         assert_eq!(env_vars.telemetry_base_url, None);
         assert_eq!(env_vars.mongodb_load_sample_data, None);
+        assert_eq!(env_vars.replica_set_name, None);
     }
 
     #[test]
@@ -166,6 +177,7 @@ mod tests {
                 ENV_VAR_MONGODB_INITDB_ROOT_PASSWORD_FILE
             ),
             format!("{}=testdb", ENV_VAR_MONGODB_INITDB_DATABASE),
+            format!("{}=root,readWrite@app", ENV_VAR_MONGODB_INITDB_ROOT_ROLES),
             format!("{}=/tmp/mongot.log", ENV_VAR_MONGOT_LOG_FILE),
             format!("{}=true", ENV_VAR_DO_NOT_TRACK),
             format!(
@@ -174,6 +186,7 @@ mod tests {
             ),
             format!("{}=true", ENV_VAR_MONGODB_LOAD_SAMPLE_DATA),
             format!("{}=voyage-api-key", ENV_VAR_VOYAGE_API_KEY),
+            format!("{}=rs-app", ENV_VAR_MONGODB_REPLICA_SET_NAME),
         ];
 
         let container_response = ContainerInspectResponse {
@@ -209,6 +222,10 @@ mod tests {
             Some("/run/secrets/password".to_string())
         );
         assert_eq!(env_vars.mongodb_initdb_database, Some("testdb".to_string()));
+        assert_eq!(
+            env_vars.mongodb_initdb_root_roles,
+            Some("root,readWrite@app".to_string())
+        );
         assert_eq!(
             env_vars.mongot_log_file,
             Some("/tmp/mongot.log".to_string())
@@ -220,6 +237,7 @@ mod tests {
         );
         assert_eq!(env_vars.mongodb_load_sample_data, Some("true".to_string()));
         assert_eq!(env_vars.voyage_api_key, Some("voyage-api-key".to_string()));
+        assert_eq!(env_vars.replica_set_name, Some("rs-app".to_string()));
     }
 
     #[test]
@@ -261,6 +279,7 @@ mod tests {
         assert_eq!(env_vars.mongot_log_file, None);
         assert_eq!(env_vars.telemetry_base_url, None);
         assert_eq!(env_vars.mongodb_load_sample_data, None);
+        assert_eq!(env_vars.replica_set_name, None);
     }
 
     #[test]
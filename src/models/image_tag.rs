@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use semver::Version;
+
 use crate::models::MongoDBVersion;
 
 const PARSE_ERROR: &str = "Invalid image tag: expected 'preview', 'latest', semver (e.g. 8.2.4), or semver+timestamp (e.g. 8.2.4-20260217T084055Z)";
@@ -36,14 +38,33 @@ impl Display for ImageTimestamp {
     }
 }
 
+/// Tag of the Atlas Local image, [`ImageTag::Latest`] when none is given.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum ImageTag {
     Preview,
+    #[default]
     Latest,
+    /// A major, major.minor or full version, e.g. `8` or `8.0` to pin a release line.
     Semver(MongoDBVersion),
     /// Semver with timestamp suffix, e.g. `8.2.4-20260217T084055Z`
     SemverTimestamp(MongoDBVersion, ImageTimestamp),
+    /// An exact version, e.g. the [`crate::models::Deployment::mongodb_version`] of an existing deployment.
+    Version(Version),
+    /// Any other tag, used as is. Never produced by parsing.
+    Custom(String),
+}
+
+impl From<MongoDBVersion> for ImageTag {
+    fn from(version: MongoDBVersion) -> Self {
+        ImageTag::Semver(version)
+    }
+}
+
+impl From<Version> for ImageTag {
+    fn from(version: Version) -> Self {
+        ImageTag::Version(version)
+    }
 }
 
 impl TryFrom<&str> for ImageTag {
@@ -75,6 +96,8 @@ impl Display for ImageTag {
             ImageTag::Latest => write!(f, "latest"),
             ImageTag::Semver(v) => write!(f, "{}", v),
             ImageTag::SemverTimestamp(version, timestamp) => write!(f, "{}-{}", version, timestamp),
+            ImageTag::Version(version) => write!(f, "{}", version),
+            ImageTag::Custom(tag) => write!(f, "{}", tag),
         }
     }
 }
@@ -137,4 +160,33 @@ mod tests {
         // Non-digit in date or time
         assert!(ImageTag::try_from("8.2.4-2026021XT084055Z").is_err());
     }
+
+    #[test]
+    fn default_is_latest() {
+        assert_eq!(ImageTag::default(), ImageTag::Latest);
+        assert_eq!(ImageTag::default().to_string(), "latest");
+    }
+
+    #[test]
+    fn major_minor() {
+        use crate::models::MongoDBVersionMajorMinor;
+        let tag = ImageTag::from(MongoDBVersion::MajorMinor(MongoDBVersionMajorMinor {
+            major: 7,
+            minor: 0,
+        }));
+        assert_eq!(tag, ImageTag::try_from("7.0").unwrap());
+        assert_eq!(tag.to_string(), "7.0");
+    }
+
+    #[test]
+    fn version() {
+        let tag = ImageTag::from(Version::new(8, 0, 4));
+        assert_eq!(tag.to_string(), "8.0.4");
+    }
+
+    #[test]
+    fn custom() {
+        let tag = ImageTag::Custom("8.0-nightly".to_string());
+        assert_eq!(tag.to_string(), "8.0-nightly");
+    }
 }
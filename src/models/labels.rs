@@ -9,11 +9,20 @@ pub const LOCAL_DEPLOYMENT_LABEL_VALUE: &str = "container";
 pub const MONGODB_TYPE_LABEL_KEY: &str = "mongodb-type";
 pub const MONGODB_VERSION_LABEL_KEY: &str = "version";
 
+/// Label set on created deployments with the version of this crate, see [`crate::models::CreateDeploymentOptions::skip_version_label`].
+pub const CREATED_BY_VERSION_LABEL_KEY: &str = "atlas-local-lib-version";
+
+/// Label set on created deployments whose MongoDB does not listen on the default port inside the container,
+/// see [`crate::models::MongoDBPortBinding::container_port`].
+pub const MONGODB_CONTAINER_PORT_LABEL_KEY: &str = "mongodb-container-port";
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LocalDeploymentLabels {
     pub mongodb_version: Version,
     pub mongodb_type: MongodbType,
+    /// Version of this crate that created the deployment, `None` for deployments created otherwise.
+    pub created_by_version: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -72,9 +81,13 @@ impl TryFrom<&ContainerInspectResponse> for LocalDeploymentLabels {
             .ok_or(GetLocalDeploymentLabelsError::MissingMongodbType)?;
         let mongodb_type: MongodbType = mongodb_type_string.parse()?;
 
+        // Only set on deployments created by this crate
+        let created_by_version = container_config.get(CREATED_BY_VERSION_LABEL_KEY).cloned();
+
         Ok(LocalDeploymentLabels {
             mongodb_version,
             mongodb_type,
+            created_by_version,
         })
     }
 }
@@ -243,10 +256,7 @@ mod tests {
             LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
         );
         labels.insert(MONGODB_VERSION_LABEL_KEY.to_string(), "7.0.0".to_string());
-        labels.insert(
-            MONGODB_TYPE_LABEL_KEY.to_string(),
-            "invalid-type".to_string(),
-        );
+        labels.insert(MONGODB_TYPE_LABEL_KEY.to_string(), "".to_string());
 
         let container_inspect_response = ContainerInspectResponse {
             config: Some(ContainerConfig {
@@ -262,6 +272,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn unknown_mongodb_type() {
+        use std::collections::HashMap;
+
+        let mut labels = HashMap::new();
+        labels.insert(
+            LOCAL_DEPLOYMENT_LABEL_KEY.to_string(),
+            LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+        );
+        labels.insert(MONGODB_VERSION_LABEL_KEY.to_string(), "7.0.0".to_string());
+        labels.insert(
+            MONGODB_TYPE_LABEL_KEY.to_string(),
+            "future-edition".to_string(),
+        );
+
+        let container_inspect_response = ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                labels: Some(labels),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let labels = LocalDeploymentLabels::try_from(&container_inspect_response).unwrap();
+        assert_eq!(
+            labels.mongodb_type,
+            MongodbType::Unknown("future-edition".to_string())
+        );
+    }
+
     #[test]
     fn successful_parse() {
         use std::collections::HashMap;
@@ -287,5 +326,60 @@ mod tests {
         let labels = result.unwrap();
         assert_eq!(labels.mongodb_version, Version::parse("7.0.0").unwrap());
         assert_eq!(labels.mongodb_type, MongodbType::Community);
+        assert_eq!(labels.created_by_version, None);
+    }
+
+    #[test]
+    fn enterprise_mongodb_type() {
+        use std::collections::HashMap;
+
+        let mut labels = HashMap::new();
+        labels.insert(
+            LOCAL_DEPLOYMENT_LABEL_KEY.to_string(),
+            LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+        );
+        labels.insert(MONGODB_VERSION_LABEL_KEY.to_string(), "8.0.0".to_string());
+        labels.insert(
+            MONGODB_TYPE_LABEL_KEY.to_string(),
+            MongodbType::Enterprise.to_string(),
+        );
+
+        let container_inspect_response = ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                labels: Some(labels),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let labels = LocalDeploymentLabels::try_from(&container_inspect_response).unwrap();
+        assert_eq!(labels.mongodb_type, MongodbType::Enterprise);
+        assert_eq!(labels.mongodb_type.to_string(), "enterprise");
+    }
+
+    #[test]
+    fn created_by_version() {
+        use std::collections::HashMap;
+
+        let mut labels = HashMap::new();
+        labels.insert(
+            LOCAL_DEPLOYMENT_LABEL_KEY.to_string(),
+            LOCAL_DEPLOYMENT_LABEL_VALUE.to_string(),
+        );
+        labels.insert(MONGODB_VERSION_LABEL_KEY.to_string(), "7.0.0".to_string());
+        labels.insert(MONGODB_TYPE_LABEL_KEY.to_string(), "community".to_string());
+        labels.insert(
+            CREATED_BY_VERSION_LABEL_KEY.to_string(),
+            "0.1.0".to_string(),
+        );
+
+        let container_inspect_response = ContainerInspectResponse {
+            config: Some(ContainerConfig {
+                labels: Some(labels),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let labels = LocalDeploymentLabels::try_from(&container_inspect_response).unwrap();
+        assert_eq!(labels.created_by_version, Some("0.1.0".to_string()));
     }
 }
@@ -0,0 +1,101 @@
+use crate::models::{Deployment, State};
+
+/// Filters for [`crate::Client::list_deployments_filtered`], every filter that is set must match.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::ListDeploymentsFilter;
+///
+/// let filter = ListDeploymentsFilter {
+///     name_prefix: Some("test-".to_string()),
+///     running_only: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListDeploymentsFilter {
+    /// Only list deployments in this state.
+    pub state: Option<State>,
+    /// Only list deployments whose name starts with this prefix.
+    pub name_prefix: Option<String>,
+    /// Only list running deployments.
+    pub running_only: bool,
+}
+
+impl ListDeploymentsFilter {
+    /// The container statuses to let Docker filter on, empty to list containers in any state.
+    pub(crate) fn statuses(&self) -> Vec<String> {
+        let mut statuses = Vec::new();
+        if let Some(state) = self.state {
+            statuses.push(state.to_string());
+        }
+        if self.running_only && self.state != Some(State::Running) {
+            statuses.push(State::Running.to_string());
+        }
+        statuses
+    }
+
+    /// Returns true when one of the container names (with or without the leading slash) starts with the name prefix.
+    pub(crate) fn matches_names<S: AsRef<str>>(&self, names: &[S]) -> bool {
+        let Some(name_prefix) = self.name_prefix.as_deref() else {
+            return true;
+        };
+
+        names.iter().any(|name| {
+            let name = name.as_ref();
+            name.strip_prefix('/')
+                .unwrap_or(name)
+                .starts_with(name_prefix)
+        })
+    }
+
+    /// Returns true when the deployment matches every filter that is set.
+    pub fn matches(&self, deployment: &Deployment) -> bool {
+        self.state.is_none_or(|state| deployment.state == state)
+            && (!self.running_only || deployment.state == State::Running)
+            && self.matches_names(deployment.name.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statuses() {
+        assert!(ListDeploymentsFilter::default().statuses().is_empty());
+        assert_eq!(
+            ListDeploymentsFilter {
+                state: Some(State::Running),
+                running_only: true,
+                ..Default::default()
+            }
+            .statuses(),
+            vec!["running".to_string()]
+        );
+        assert_eq!(
+            ListDeploymentsFilter {
+                state: Some(State::Paused),
+                running_only: true,
+                ..Default::default()
+            }
+            .statuses(),
+            vec!["paused".to_string(), "running".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_matches_names() {
+        let filter = ListDeploymentsFilter {
+            name_prefix: Some("test-".to_string()),
+            ..Default::default()
+        };
+
+        assert!(filter.matches_names(&["/test-deployment"]));
+        assert!(filter.matches_names(&["test-deployment"]));
+        assert!(!filter.matches_names(&["/other-test-deployment"]));
+        assert!(!filter.matches_names::<&str>(&[]));
+        assert!(ListDeploymentsFilter::default().matches_names::<&str>(&[]));
+    }
+}
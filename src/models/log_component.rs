@@ -0,0 +1,145 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Lowest log verbosity level, a component at this level inherits the verbosity of its parent.
+pub const MIN_LOG_VERBOSITY: i32 = -1;
+/// Highest log verbosity level (debug level 5).
+pub const MAX_LOG_VERBOSITY: i32 = 5;
+
+/// A mongod log component whose verbosity can be changed, see [`crate::Client::set_log_verbosity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogComponent {
+    /// The verbosity of every component that does not set its own.
+    Default,
+    AccessControl,
+    Command,
+    Control,
+    Ftdc,
+    Geo,
+    Index,
+    Network,
+    Query,
+    Replication,
+    ReplicationElection,
+    ReplicationHeartbeats,
+    ReplicationInitialSync,
+    ReplicationRollback,
+    Recovery,
+    Sharding,
+    Storage,
+    StorageJournal,
+    Transaction,
+    Write,
+}
+
+const LOG_COMPONENTS: [LogComponent; 20] = [
+    LogComponent::Default,
+    LogComponent::AccessControl,
+    LogComponent::Command,
+    LogComponent::Control,
+    LogComponent::Ftdc,
+    LogComponent::Geo,
+    LogComponent::Index,
+    LogComponent::Network,
+    LogComponent::Query,
+    LogComponent::Replication,
+    LogComponent::ReplicationElection,
+    LogComponent::ReplicationHeartbeats,
+    LogComponent::ReplicationInitialSync,
+    LogComponent::ReplicationRollback,
+    LogComponent::Recovery,
+    LogComponent::Sharding,
+    LogComponent::Storage,
+    LogComponent::StorageJournal,
+    LogComponent::Transaction,
+    LogComponent::Write,
+];
+
+impl LogComponent {
+    /// The name mongod uses for the component, subcomponents are separated by dots, e.g. `replication.heartbeats`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogComponent::Default => "default",
+            LogComponent::AccessControl => "accessControl",
+            LogComponent::Command => "command",
+            LogComponent::Control => "control",
+            LogComponent::Ftdc => "ftdc",
+            LogComponent::Geo => "geo",
+            LogComponent::Index => "index",
+            LogComponent::Network => "network",
+            LogComponent::Query => "query",
+            LogComponent::Replication => "replication",
+            LogComponent::ReplicationElection => "replication.election",
+            LogComponent::ReplicationHeartbeats => "replication.heartbeats",
+            LogComponent::ReplicationInitialSync => "replication.initialSync",
+            LogComponent::ReplicationRollback => "replication.rollback",
+            LogComponent::Recovery => "recovery",
+            LogComponent::Sharding => "sharding",
+            LogComponent::Storage => "storage",
+            LogComponent::StorageJournal => "storage.journal",
+            LogComponent::Transaction => "transaction",
+            LogComponent::Write => "write",
+        }
+    }
+
+    /// The JavaScript expression reading the verbosity of the component from `db.getLogComponents()`.
+    pub(crate) fn verbosity_expression(&self) -> String {
+        match self {
+            LogComponent::Default => "db.getLogComponents().verbosity".to_string(),
+            component => format!("db.getLogComponents().{}.verbosity", component.name()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Invalid log component: \"{0}\"")]
+pub struct ParseLogComponentError(pub String);
+
+impl FromStr for LogComponent {
+    type Err = ParseLogComponentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LOG_COMPONENTS
+            .into_iter()
+            .find(|component| component.name() == s)
+            .ok_or_else(|| ParseLogComponentError(s.to_string()))
+    }
+}
+
+impl Display for LogComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for component in LOG_COMPONENTS {
+            assert_eq!(component.name().parse::<LogComponent>(), Ok(component));
+        }
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!(
+            "queries".parse::<LogComponent>(),
+            Err(ParseLogComponentError("queries".to_string()))
+        );
+        assert!("Query".parse::<LogComponent>().is_err());
+    }
+
+    #[test]
+    fn test_verbosity_expression() {
+        assert_eq!(
+            LogComponent::Default.verbosity_expression(),
+            "db.getLogComponents().verbosity"
+        );
+        assert_eq!(
+            LogComponent::ReplicationHeartbeats.verbosity_expression(),
+            "db.getLogComponents().replication.heartbeats.verbosity"
+        );
+    }
+}
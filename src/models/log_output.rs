@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 
 /// Represents a single log output entry from a container.
 ///
@@ -114,6 +115,78 @@ impl std::fmt::Display for LogOutput {
     }
 }
 
+/// A piece of log output received while following the logs of a container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogChunk {
+    /// The stream the chunk was written to and its raw bytes, timestamps included.
+    pub output: LogOutput,
+    /// The timestamp Docker prefixed the chunk with, only set when timestamps were requested.
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl LogChunk {
+    /// Creates a chunk, parsing the leading RFC 3339 timestamp when `timestamps` is set.
+    pub fn new(output: LogOutput, timestamps: bool) -> Self {
+        let timestamp = timestamps
+            .then(|| {
+                output
+                    .as_str_lossy()
+                    .split_once(' ')
+                    .map(|(t, _)| t.to_string())
+            })
+            .flatten()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(&timestamp).ok())
+            .map(|timestamp| timestamp.with_timezone(&Utc));
+
+        LogChunk { output, timestamp }
+    }
+}
+
+/// An event of a log stream that survives restarts, see [`crate::Client::tail_logs_resilient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TailLogsEvent {
+    /// A piece of log output, always timestamped.
+    Log(LogChunk),
+    /// The log stream ended (e.g. because the container restarted) and was subscribed to again.
+    Reconnected,
+}
+
+/// The process of an Atlas Local container a log line was most likely written by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogSource {
+    Mongod,
+    Mongot,
+    /// Neither process could be recognized, e.g. for the output of the entrypoint script.
+    Unknown,
+}
+
+impl LogSource {
+    /// Guesses which process wrote a log line from its content.
+    ///
+    /// Both processes write structured JSON logs: mongot lines carry its service name (`"svc":"MONGOT"`)
+    /// or a `com.xgen.mongot` logger, mongod lines carry a `$date` timestamp and a component (`"c":`).
+    /// The guess is best-effort, anything else is [`LogSource::Unknown`].
+    pub fn detect(line: &str) -> Self {
+        if line.contains("\"svc\":\"MONGOT\"") || line.contains("com.xgen.mongot") {
+            LogSource::Mongot
+        } else if line.contains("\"t\":{\"$date\"") && line.contains("\"c\":") {
+            LogSource::Mongod
+        } else {
+            LogSource::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for LogSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogSource::Mongod => write!(f, "mongod"),
+            LogSource::Mongot => write!(f, "mongot"),
+            LogSource::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +275,59 @@ mod tests {
         let lossy = output.as_str_lossy();
         assert!(!lossy.is_empty());
     }
+
+    #[test]
+    fn test_log_chunk_with_timestamp() {
+        let output = LogOutput::StdErr {
+            message: Bytes::from("2024-01-02T03:04:05.123456789Z starting mongod\n"),
+        };
+
+        let chunk = LogChunk::new(output.clone(), true);
+
+        assert_eq!(chunk.output, output);
+        assert_eq!(
+            chunk.timestamp,
+            Some(
+                DateTime::parse_from_rfc3339("2024-01-02T03:04:05.123456789Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            )
+        );
+    }
+
+    #[test]
+    fn test_log_chunk_without_timestamp() {
+        let output = LogOutput::StdOut {
+            message: Bytes::from("2024-01-02T03:04:05Z not requested\n"),
+        };
+        assert_eq!(LogChunk::new(output, false).timestamp, None);
+
+        let output = LogOutput::StdOut {
+            message: Bytes::from("no timestamp here\n"),
+        };
+        assert_eq!(LogChunk::new(output, true).timestamp, None);
+    }
+
+    #[test]
+    fn test_log_source_detect() {
+        assert_eq!(
+            LogSource::detect(
+                r#"{"t":{"$date":"2025-01-01T00:00:00.000+00:00"},"s":"I","c":"NETWORK","id":23016,"ctx":"listener","msg":"Waiting for connections"}"#
+            ),
+            LogSource::Mongod
+        );
+        assert_eq!(
+            LogSource::detect(
+                r#"{"t":"2025-01-01T00:00:00.000Z","s":"INFO","svc":"MONGOT","ctx":"main","n":"com.xgen.mongot.server.MongotServer","msg":"Started"}"#
+            ),
+            LogSource::Mongot
+        );
+        assert_eq!(
+            LogSource::detect(
+                "2025-01-01 00:00:00 INFO com.xgen.mongot.index.IndexManager - ready"
+            ),
+            LogSource::Mongot
+        );
+        assert_eq!(LogSource::detect("Starting runner..."), LogSource::Unknown);
+    }
 }
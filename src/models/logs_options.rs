@@ -109,6 +109,53 @@ impl From<LogsOptions> for bollard::query_parameters::LogsOptions {
     }
 }
 
+/// Options for following the logs of a container as they are written.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::{FollowLogsOptions, Tail};
+///
+/// let options = FollowLogsOptions::builder()
+///     .stdout(true)
+///     .stderr(true)
+///     .tail(Tail::Number(0))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, typed_builder::TypedBuilder)]
+#[builder(doc)]
+pub struct FollowLogsOptions {
+    /// Return logs from stdout
+    #[builder(default = false)]
+    pub stdout: bool,
+    /// Return logs from stderr
+    #[builder(default = false)]
+    pub stderr: bool,
+    /// Return logs from the given timestamp
+    #[builder(default, setter(strip_option))]
+    pub since: Option<DateTime<Utc>>,
+    /// Add timestamps to every log line, they are parsed into [`LogChunk::timestamp`](crate::models::LogChunk::timestamp)
+    #[builder(default = false)]
+    pub timestamps: bool,
+    /// Start with this number of lines at the tail of the existing logs
+    #[builder(default, setter(strip_option, into))]
+    pub tail: Option<Tail>,
+}
+
+impl From<FollowLogsOptions> for bollard::query_parameters::LogsOptions {
+    fn from(options: FollowLogsOptions) -> Self {
+        bollard::query_parameters::LogsOptions {
+            follow: true,
+            stdout: options.stdout,
+            stderr: options.stderr,
+            since: options.since.map(|t| t.timestamp() as i32).unwrap_or(0),
+            until: 0,
+            timestamps: options.timestamps,
+            tail: options.tail.map(|t| t.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +182,26 @@ mod tests {
         assert!(!bollard_options.follow);
     }
 
+    #[test]
+    fn test_follow_logs_options_into_bollard() {
+        let options = FollowLogsOptions::builder()
+            .stdout(true)
+            .since(DateTime::from_timestamp(1234567890, 0).unwrap())
+            .timestamps(true)
+            .tail(10u64)
+            .build();
+
+        let bollard_options: bollard::query_parameters::LogsOptions = options.into();
+
+        assert!(bollard_options.follow);
+        assert!(bollard_options.stdout);
+        assert!(!bollard_options.stderr);
+        assert_eq!(bollard_options.since, 1234567890);
+        assert_eq!(bollard_options.until, 0);
+        assert!(bollard_options.timestamps);
+        assert_eq!(bollard_options.tail, "10");
+    }
+
     #[test]
     fn test_tail_display() {
         assert_eq!(Tail::All.to_string(), "all");
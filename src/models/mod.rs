@@ -1,29 +1,75 @@
+mod api_version;
+mod atlas_local_image;
+mod capabilities;
+mod compose_service;
+mod connection_string_options;
 mod container_health_status;
 mod create_deployment_options;
 mod creation_source;
+mod delete_deployment_options;
 mod deployment;
+mod deployment_diff;
+mod deployment_event;
+mod deployment_stats;
+mod deployment_status_update;
+mod disk_usage;
 mod environment_variables;
 mod image_tag;
 mod labels;
+mod list_deployments_filter;
+mod log_component;
 mod log_output;
 mod logs_options;
 mod mdb_version;
 mod mongodb_type;
+mod network_mode;
 mod port_binding;
+mod readiness_report;
+mod restart_policy;
+mod retry_policy;
+mod root_role;
+mod server_status;
+mod slow_query;
 mod state;
+mod stop_deployment_options;
+mod telemetry;
+mod wait_strategy;
 mod watch_options;
 
+pub use api_version::*;
+pub use atlas_local_image::*;
+pub use capabilities::*;
+pub use compose_service::*;
+pub use connection_string_options::*;
 pub use container_health_status::*;
 pub use create_deployment_options::*;
 pub use creation_source::*;
+pub use delete_deployment_options::*;
 pub use deployment::*;
+pub use deployment_diff::*;
+pub use deployment_event::*;
+pub use deployment_stats::*;
+pub use deployment_status_update::*;
+pub use disk_usage::*;
 pub use environment_variables::*;
 pub use image_tag::*;
 pub use labels::*;
+pub use list_deployments_filter::*;
+pub use log_component::*;
 pub use log_output::*;
 pub use logs_options::*;
 pub use mdb_version::*;
 pub use mongodb_type::*;
+pub use network_mode::*;
 pub use port_binding::*;
+pub use readiness_report::*;
+pub use restart_policy::*;
+pub use retry_policy::*;
+pub use root_role::*;
+pub use server_status::*;
+pub use slow_query::*;
 pub use state::*;
+pub use stop_deployment_options::*;
+pub use telemetry::*;
+pub use wait_strategy::*;
 pub use watch_options::*;
@@ -1,10 +1,12 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MongodbType {
     Community,
     Enterprise,
+    /// An edition this version of the library does not know about, holds the raw label value.
+    Unknown(String),
 }
 
 #[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
@@ -18,7 +20,19 @@ impl FromStr for MongodbType {
         match s {
             "community" => Ok(MongodbType::Community),
             "enterprise" => Ok(MongodbType::Enterprise),
-            _ => Err(ParseMongodbTypeError(s.to_string())),
+            // An empty label carries no edition at all, unlike an edition we don't know yet
+            "" => Err(ParseMongodbTypeError(s.to_string())),
+            unknown => Ok(MongodbType::Unknown(unknown.to_string())),
+        }
+    }
+}
+
+impl Display for MongodbType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MongodbType::Community => write!(f, "community"),
+            MongodbType::Enterprise => write!(f, "enterprise"),
+            MongodbType::Unknown(s) => write!(f, "{}", s),
         }
     }
 }
@@ -37,6 +51,28 @@ mod tests {
             MongodbType::from_str("enterprise").unwrap(),
             MongodbType::Enterprise
         );
-        assert!(MongodbType::from_str("invalid").is_err());
+        assert!(MongodbType::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_parse_mongodb_type_unknown() {
+        assert_eq!(
+            MongodbType::from_str("serverless").unwrap(),
+            MongodbType::Unknown("serverless".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mongodb_type_display_round_trip() {
+        for mongodb_type in [
+            MongodbType::Community,
+            MongodbType::Enterprise,
+            MongodbType::Unknown("serverless".to_string()),
+        ] {
+            assert_eq!(
+                MongodbType::from_str(&mongodb_type.to_string()).unwrap(),
+                mongodb_type
+            );
+        }
     }
 }
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+/// The Docker network mode of a deployment container.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NetworkMode {
+    /// Docker's default bridge network.
+    #[default]
+    Bridge,
+    /// No networking, the container only has a loopback interface.
+    /// The MongoDB port cannot be published, the deployment is only reachable from inside the container.
+    None,
+}
+
+impl NetworkMode {
+    /// Returns the value Docker uses for the network mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkMode::Bridge => "bridge",
+            NetworkMode::None => "none",
+        }
+    }
+}
+
+impl Display for NetworkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(NetworkMode::Bridge.to_string(), "bridge");
+        assert_eq!(NetworkMode::None.to_string(), "none");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(NetworkMode::default(), NetworkMode::Bridge);
+    }
+}
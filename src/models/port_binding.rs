@@ -1,13 +1,44 @@
-use std::net::IpAddr;
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener},
+    ops::RangeInclusive,
+};
 
 use bollard::models::{ContainerInspectResponse, PortBinding};
 
+use crate::models::MONGODB_CONTAINER_PORT_LABEL_KEY;
+
+/// The port MongoDB listens on inside the container by default.
+pub const MONGODB_INTERNAL_PORT: u16 = 27017;
+
+/// Host ports [`MongoDBPortBinding::free_loopback`] is typically given, the ones following the default MongoDB port.
+pub const DEFAULT_FREE_PORT_RANGE: RangeInclusive<u16> = 27018..=27117;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MongoDBPortBinding {
+    /// The port on the host, `None` to let Docker pick a free port.
     pub port: Option<u16>,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub binding_type: BindingType,
+    /// The port MongoDB listens on inside the container, the connection string uses the host `port`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_container_port",
+            skip_serializing_if = "is_default_container_port"
+        )
+    )]
+    pub container_port: u16,
+}
+
+#[cfg(feature = "serde")]
+fn default_container_port() -> u16 {
+    MONGODB_INTERNAL_PORT
+}
+
+#[cfg(feature = "serde")]
+fn is_default_container_port(container_port: &u16) -> bool {
+    *container_port == MONGODB_INTERNAL_PORT
 }
 
 #[cfg_attr(
@@ -22,6 +53,21 @@ pub enum BindingType {
     Specific { ip: IpAddr }, // Specific IP address
 }
 
+/// Whether the MongoDB port of a deployment is published on the host.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PortExposure {
+    /// Publish the MongoDB port on the host, as configured by the MongoDB port binding.
+    #[default]
+    Published,
+    /// Do not publish the MongoDB port, the deployment cannot be connected to from the host.
+    None,
+}
+
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum GetMongoDBPortBindingError {
     #[error("Multiple MongoDB ports found")]
@@ -36,78 +82,168 @@ pub enum GetMongoDBPortBindingError {
     InvalidHostIP(std::net::AddrParseError),
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("No free port on 127.0.0.1 between {start} and {end}")]
+pub struct NoFreePortError {
+    pub start: u16,
+    pub end: u16,
+}
+
 impl MongoDBPortBinding {
     pub fn new(port: Option<u16>, binding_type: BindingType) -> Self {
-        Self { port, binding_type }
+        Self {
+            port,
+            binding_type,
+            container_port: MONGODB_INTERNAL_PORT,
+        }
+    }
+
+    /// Creates a binding publishing the MongoDB port on `port` of 127.0.0.1, e.g. `MongoDBPortBinding::loopback(27018)`.
+    pub fn loopback(port: u16) -> Self {
+        Self::new(Some(port), BindingType::Loopback)
+    }
+
+    /// Creates a binding publishing the MongoDB port on `port` of every interface (0.0.0.0).
+    pub fn any_interface(port: u16) -> Self {
+        Self::new(Some(port), BindingType::AnyInterface)
+    }
+
+    /// Sets the port MongoDB listens on inside the container, for images not running mongod on 27017.
+    pub fn with_container_port(mut self, container_port: u16) -> Self {
+        self.container_port = container_port;
+        self
+    }
+
+    /// The key of the MongoDB port in a Docker port map, e.g. `27017/tcp`.
+    pub(crate) fn port_map_key(container_port: u16) -> String {
+        format!("{container_port}/tcp")
+    }
+
+    /// Gets the port MongoDB listens on inside a container, as recorded in its labels when it is not the default.
+    pub(crate) fn container_port_of(value: &ContainerInspectResponse) -> u16 {
+        value
+            .config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref())
+            .and_then(|labels| labels.get(MONGODB_CONTAINER_PORT_LABEL_KEY))
+            .and_then(|port| port.parse::<u16>().ok())
+            .unwrap_or(MONGODB_INTERNAL_PORT)
+    }
+
+    /// Creates a loopback binding on the first port of `range` that is free on 127.0.0.1.
+    ///
+    /// Unlike a binding without a port, the port is known before the deployment is created.
+    /// A port is free when it can be listened on, another process can still take it before the container starts.
+    pub fn free_loopback(range: RangeInclusive<u16>) -> Result<Self, NoFreePortError> {
+        let (start, end) = (*range.start(), *range.end());
+
+        range
+            .into_iter()
+            .find(|port| TcpListener::bind((Ipv4Addr::LOCALHOST, *port)).is_ok())
+            .map(|port| Self::new(Some(port), BindingType::Loopback))
+            .ok_or(NoFreePortError { start, end })
     }
 
     pub fn try_from(
         value: &ContainerInspectResponse,
     ) -> Result<Option<MongoDBPortBinding>, GetMongoDBPortBindingError> {
-        let Some(ports) = Self::get_mongodb_ports(value) else {
-            return Ok(None);
-        };
+        Self::try_from_internal_port(value, Self::container_port_of(value))
+    }
 
-        let ports = ports
-            .iter()
-            .map(ParsedPortBinding::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
+    /// Gets the host binding of a MongoDB listening on `internal_port` inside the container.
+    ///
+    /// Only TCP bindings (`27017/tcp` or a bare `27017`) are considered, MongoDB does not speak UDP or SCTP.
+    /// Null port maps and empty bindings are ignored.
+    pub fn try_from_internal_port(
+        value: &ContainerInspectResponse,
+        internal_port: u16,
+    ) -> Result<Option<MongoDBPortBinding>, GetMongoDBPortBindingError> {
+        let bindings = Self::try_from_all_internal_port(value, internal_port)?;
 
         // Ensure we have the expected number of port bindings
-        match ports.len() {
+        match bindings.as_slice() {
             // If there are no port bindings, we return None
-            0 => return Ok(None),
+            [] => Ok(None),
             // If there is one port binding, we can proceed
-            1 => {}
+            [binding] => Ok(Some(binding.clone())),
             // If there are multiple host IPs, they should all have the same port number and either all be loopback or all be any interface
-            // Multiple specific host IPs are not supported
-            _ => {
-                // Ensure all port bindings have the same port number
-                let port_number = ports
-                    .first()
-                    .ok_or(GetMongoDBPortBindingError::MissingPortNumber)?
-                    .host_port;
-                let all_the_same_port_number = ports.iter().all(|p| p.host_port == port_number);
-                if !all_the_same_port_number {
+            // Multiple specific host IPs are not supported, use `try_from_all` to get all of them
+            [first, rest @ ..] => {
+                let all_the_same = rest.iter().all(|binding| binding == first);
+                let collapsible = matches!(
+                    first.binding_type,
+                    BindingType::Loopback | BindingType::AnyInterface
+                );
+                if !(all_the_same && collapsible) {
                     return Err(GetMongoDBPortBindingError::MultiplePortsFound);
                 }
 
-                // Ensure all port bindings are either loopback or any interface
-                let all_loopback = ports.iter().all(|p| p.host_ip.is_loopback());
-                let all_any_interface = ports.iter().all(|p| p.host_ip.is_unspecified());
-
-                // If the port bindings are not all loopback or all any interface, we return an error
-                if !(all_loopback || all_any_interface) {
-                    return Err(GetMongoDBPortBindingError::MultiplePortsFound);
-                }
+                Ok(Some(first.clone()))
             }
         }
+    }
 
-        // It's safe to unwrap because we checked the length above
-        #[allow(clippy::unwrap_used)]
-        let port = ports.first().unwrap();
+    /// Gets every host binding of the MongoDB port, e.g. both the IPv4 and IPv6 address it is published on.
+    ///
+    /// Unlike [`MongoDBPortBinding::try_from`], bindings are not collapsed into a single one,
+    /// only bindings that are identical once parsed are reported once.
+    pub fn try_from_all(
+        value: &ContainerInspectResponse,
+    ) -> Result<Vec<MongoDBPortBinding>, GetMongoDBPortBindingError> {
+        Self::try_from_all_internal_port(value, Self::container_port_of(value))
+    }
 
-        // Get the binding type (determine if it's any interface, loopback, or specific IP address)
-        let binding_type = match port.host_ip {
-            ip if ip.is_unspecified() => BindingType::AnyInterface,
-            ip if ip.is_loopback() => BindingType::Loopback,
-            ip => BindingType::Specific { ip },
-        };
+    /// Gets every host binding of a MongoDB listening on `internal_port` inside the container,
+    /// see [`MongoDBPortBinding::try_from_all`].
+    pub fn try_from_all_internal_port(
+        value: &ContainerInspectResponse,
+        internal_port: u16,
+    ) -> Result<Vec<MongoDBPortBinding>, GetMongoDBPortBindingError> {
+        let mut ports = Self::get_mongodb_ports(value, internal_port)
+            .map(ParsedPortBinding::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(Some(MongoDBPortBinding::new(
-            Some(port.host_port),
-            binding_type,
-        )))
+        // The same binding can be reported under both `<port>/tcp` and a bare `<port>`
+        ports.sort();
+        ports.dedup();
+
+        // Both 0.0.0.0 and :: (or 127.0.0.1 and ::1) map to the same binding type
+        let mut bindings: Vec<MongoDBPortBinding> = Vec::with_capacity(ports.len());
+        for binding in ports
+            .into_iter()
+            .map(|port| MongoDBPortBinding::from(port).with_container_port(internal_port))
+        {
+            if !bindings.contains(&binding) {
+                bindings.push(binding);
+            }
+        }
+
+        Ok(bindings)
     }
 
-    fn get_mongodb_ports(value: &ContainerInspectResponse) -> Option<&Vec<PortBinding>> {
-        let network_settings = value.network_settings.as_ref()?;
-        let port = network_settings.ports.as_ref()?;
-        let ports = port.get("27017/tcp")?.as_ref()?;
-        Some(ports)
+    fn get_mongodb_ports(
+        value: &ContainerInspectResponse,
+        internal_port: u16,
+    ) -> impl Iterator<Item = &PortBinding> {
+        value
+            .network_settings
+            .as_ref()
+            .and_then(|network_settings| network_settings.ports.as_ref())
+            .into_iter()
+            .flatten()
+            // Port map keys are formatted as `<port>/<protocol>`, a missing protocol means TCP
+            .filter(move |(key, _)| {
+                let (port, protocol) = key.split_once('/').unwrap_or((key.as_str(), "tcp"));
+                protocol == "tcp" && port.parse::<u16>() == Ok(internal_port)
+            })
+            // Docker reports exposed but unpublished ports with a null binding list
+            .filter_map(|(_, bindings)| bindings.as_ref())
+            .flatten()
+            .filter(|binding| binding.host_ip.is_some() || binding.host_port.is_some())
     }
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
 struct ParsedPortBinding {
     host_ip: IpAddr,
     host_port: u16,
@@ -137,6 +273,35 @@ impl TryFrom<&PortBinding> for ParsedPortBinding {
     }
 }
 
+impl From<ParsedPortBinding> for MongoDBPortBinding {
+    fn from(value: ParsedPortBinding) -> Self {
+        // Get the binding type (determine if it's any interface, loopback, or specific IP address)
+        let binding_type = match value.host_ip {
+            ip if ip.is_unspecified() => BindingType::AnyInterface,
+            ip if ip.is_loopback() => BindingType::Loopback,
+            ip => BindingType::Specific { ip },
+        };
+
+        MongoDBPortBinding::new(Some(value.host_port), binding_type)
+    }
+}
+
+/// Loopback and unspecified addresses (IPv4 or IPv6) become [`BindingType::Loopback`] and [`BindingType::AnyInterface`],
+/// like the bindings read with [`MongoDBPortBinding::try_from`]. Port 0 lets Docker pick a free port.
+impl From<SocketAddr> for MongoDBPortBinding {
+    fn from(value: SocketAddr) -> Self {
+        let binding = MongoDBPortBinding::from(ParsedPortBinding {
+            host_ip: value.ip(),
+            host_port: value.port(),
+        });
+
+        MongoDBPortBinding {
+            port: binding.port.filter(|port| *port != 0),
+            ..binding
+        }
+    }
+}
+
 impl From<&MongoDBPortBinding> for PortBinding {
     fn from(mdb_port_binding: &MongoDBPortBinding) -> Self {
         let host_ip = match mdb_port_binding.binding_type {
@@ -154,7 +319,8 @@ impl From<&MongoDBPortBinding> for PortBinding {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bollard::models::NetworkSettings;
+    use bollard::models::{ContainerConfig, NetworkSettings};
+    #[cfg(feature = "serde")]
     use serde_json::json;
     use std::collections::HashMap;
 
@@ -424,6 +590,284 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_try_from_all_specific_ipv4_and_ipv6() {
+        let container = create_container_response_with_mongodb_ports(vec![
+            create_port_binding("192.168.1.100", "27017"),
+            create_port_binding("fd00::1", "27017"),
+        ]);
+
+        let result = MongoDBPortBinding::try_from_all(&container);
+        assert_eq!(
+            result,
+            Ok(vec![
+                MongoDBPortBinding::new(
+                    Some(27017),
+                    BindingType::Specific {
+                        ip: "192.168.1.100".parse().unwrap()
+                    }
+                ),
+                MongoDBPortBinding::new(
+                    Some(27017),
+                    BindingType::Specific {
+                        ip: "fd00::1".parse().unwrap()
+                    }
+                ),
+            ])
+        );
+
+        // The single binding API cannot represent both addresses
+        assert_eq!(
+            MongoDBPortBinding::try_from(&container),
+            Err(GetMongoDBPortBindingError::MultiplePortsFound)
+        );
+    }
+
+    #[test]
+    fn test_try_from_all_collapses_identical_bindings() {
+        let container = create_container_response_with_mongodb_ports(vec![
+            create_port_binding("0.0.0.0", "27017"),
+            create_port_binding("::", "27017"),
+            create_port_binding("127.0.0.1", "27018"),
+        ]);
+
+        let result = MongoDBPortBinding::try_from_all(&container);
+        assert_eq!(
+            result,
+            Ok(vec![
+                MongoDBPortBinding::new(Some(27017), BindingType::AnyInterface),
+                MongoDBPortBinding::new(Some(27018), BindingType::Loopback),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_try_from_all_no_bindings() {
+        let container = create_container_response_with_mongodb_ports(vec![]);
+
+        let result = MongoDBPortBinding::try_from_all(&container);
+        assert_eq!(result, Ok(vec![]));
+    }
+
+    #[test]
+    fn test_try_from_all_invalid_port_number() {
+        let container = create_container_response_with_mongodb_ports(vec![
+            create_port_binding("192.168.1.100", "27017"),
+            create_port_binding("fd00::1", "invalid_port"),
+        ]);
+
+        let result = MongoDBPortBinding::try_from_all(&container);
+        assert!(matches!(
+            result,
+            Err(GetMongoDBPortBindingError::InvalidPortNumber(_))
+        ));
+    }
+
+    fn create_container_response_with_port_map(
+        port_map: HashMap<String, Option<Vec<PortBinding>>>,
+    ) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            network_settings: Some(NetworkSettings {
+                ports: Some(port_map),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_try_from_null_mongodb_ports() {
+        let container = create_container_response_with_port_map(HashMap::from([(
+            "27017/tcp".to_string(),
+            None,
+        )]));
+
+        let result = MongoDBPortBinding::try_from(&container);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_try_from_null_entries_are_skipped() {
+        let container = create_container_response_with_mongodb_ports(vec![
+            PortBinding {
+                host_ip: None,
+                host_port: None,
+            },
+            create_port_binding("127.0.0.1", "27017"),
+            PortBinding {
+                host_ip: None,
+                host_port: None,
+            },
+        ]);
+
+        let result = MongoDBPortBinding::try_from(&container);
+        assert_eq!(
+            result,
+            Ok(Some(MongoDBPortBinding::new(
+                Some(27017),
+                BindingType::Loopback
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_other_protocol_keys() {
+        let container = create_container_response_with_port_map(HashMap::from([
+            ("27017/tcp".to_string(), None),
+            // MongoDB only speaks TCP, a UDP publish of the same port is not the MongoDB binding
+            (
+                "27017/udp".to_string(),
+                Some(vec![create_port_binding("127.0.0.1", "27019")]),
+            ),
+            (
+                "27017/sctp".to_string(),
+                Some(vec![create_port_binding("0.0.0.0", "27020")]),
+            ),
+            (
+                "27017".to_string(),
+                Some(vec![create_port_binding("192.168.1.100", "27018")]),
+            ),
+            (
+                "3000/tcp".to_string(),
+                Some(vec![create_port_binding("127.0.0.1", "3000")]),
+            ),
+        ]));
+
+        let result = MongoDBPortBinding::try_from(&container);
+        assert_eq!(
+            result,
+            Ok(Some(MongoDBPortBinding::new(
+                Some(27018),
+                BindingType::Specific {
+                    ip: "192.168.1.100".parse().unwrap()
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn test_try_from_internal_port() {
+        let container = create_container_response_with_port_map(HashMap::from([
+            (
+                "27017/tcp".to_string(),
+                Some(vec![create_port_binding("127.0.0.1", "27017")]),
+            ),
+            (
+                "28000/tcp".to_string(),
+                Some(vec![
+                    create_port_binding("0.0.0.0", "37017"),
+                    create_port_binding("::", "37017"),
+                ]),
+            ),
+        ]));
+
+        let custom = MongoDBPortBinding::try_from_internal_port(&container, 28000);
+        let missing = MongoDBPortBinding::try_from_internal_port(&container, 28001);
+
+        assert_eq!(
+            custom,
+            Ok(Some(
+                MongoDBPortBinding::new(Some(37017), BindingType::AnyInterface)
+                    .with_container_port(28000)
+            ))
+        );
+        assert_eq!(missing, Ok(None));
+    }
+
+    #[test]
+    fn test_try_from_container_port_label() {
+        let mut container = create_container_response_with_port_map(HashMap::from([(
+            "28017/tcp".to_string(),
+            Some(vec![create_port_binding("127.0.0.1", "27018")]),
+        )]));
+        container.config = Some(ContainerConfig {
+            labels: Some(HashMap::from([(
+                MONGODB_CONTAINER_PORT_LABEL_KEY.to_string(),
+                "28017".to_string(),
+            )])),
+            ..Default::default()
+        });
+
+        let binding = MongoDBPortBinding::try_from(&container).unwrap().unwrap();
+
+        // The host port is the one to connect to, the container port is only used to find it
+        assert_eq!(binding.port, Some(27018));
+        assert_eq!(binding.container_port, 28017);
+        assert_eq!(
+            MongoDBPortBinding::try_from_all(&container),
+            Ok(vec![binding])
+        );
+    }
+
+    #[test]
+    fn test_container_port_of_defaults_to_internal_port() {
+        let container = create_container_response_with_port_map(HashMap::new());
+
+        assert_eq!(
+            MongoDBPortBinding::container_port_of(&container),
+            MONGODB_INTERNAL_PORT
+        );
+    }
+
+    #[test]
+    fn test_loopback_constructor() {
+        let binding = MongoDBPortBinding::loopback(27018);
+
+        assert_eq!(binding.port, Some(27018));
+        assert_eq!(binding.binding_type, BindingType::Loopback);
+        assert_eq!(binding.container_port, MONGODB_INTERNAL_PORT);
+    }
+
+    #[test]
+    fn test_any_interface_constructor() {
+        let binding = MongoDBPortBinding::any_interface(27019);
+
+        assert_eq!(binding.port, Some(27019));
+        assert_eq!(binding.binding_type, BindingType::AnyInterface);
+        assert_eq!(binding.container_port, MONGODB_INTERNAL_PORT);
+    }
+
+    #[test]
+    fn test_from_socket_addr() {
+        let cases = [
+            ("127.0.0.1:27018", BindingType::Loopback),
+            ("[::1]:27018", BindingType::Loopback),
+            ("0.0.0.0:27018", BindingType::AnyInterface),
+            ("[::]:27018", BindingType::AnyInterface),
+            (
+                "192.168.1.10:27018",
+                BindingType::Specific {
+                    ip: "192.168.1.10".parse().unwrap(),
+                },
+            ),
+            (
+                "[fd00::1]:27018",
+                BindingType::Specific {
+                    ip: "fd00::1".parse().unwrap(),
+                },
+            ),
+        ];
+
+        for (address, binding_type) in cases {
+            let binding = MongoDBPortBinding::from(address.parse::<SocketAddr>().unwrap());
+            assert_eq!(
+                binding,
+                MongoDBPortBinding::new(Some(27018), binding_type),
+                "{address}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_socket_addr_port_zero() {
+        let binding = MongoDBPortBinding::from(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)));
+
+        assert_eq!(
+            binding,
+            MongoDBPortBinding::new(None, BindingType::Loopback)
+        );
+    }
+
     #[test]
     fn test_loopback_into_port_binding_vec() {
         let mdb_port_binding = MongoDBPortBinding::new(Some(27017), BindingType::Loopback);
@@ -461,6 +905,7 @@ mod tests {
         assert_eq!(port_bindings.host_port.as_deref(), None);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_serialization_loopback() {
         let port_binding = MongoDBPortBinding::new(Some(27017), BindingType::Loopback);
@@ -468,6 +913,7 @@ mod tests {
         assert_eq!(json, json!({"port": 27017, "type": "loopback"}));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_deserialization_loopback() {
         let json = json!({"port": 27017, "type": "loopback"});
@@ -478,6 +924,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_serialization_specific_ip() {
         let specific_ip: IpAddr = "128.128.128.128".parse().unwrap();
@@ -490,6 +937,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_json_deserialization_specific_ip() {
         let specific_ip: IpAddr = "128.128.128.128".parse().unwrap();
@@ -500,4 +948,56 @@ mod tests {
             MongoDBPortBinding::new(Some(27017), BindingType::Specific { ip: specific_ip })
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_container_port() {
+        let port_binding =
+            MongoDBPortBinding::new(Some(27018), BindingType::Loopback).with_container_port(28017);
+
+        let json = serde_json::to_value(&port_binding).unwrap();
+        assert_eq!(
+            json,
+            json!({"port": 27018, "type": "loopback", "container_port": 28017})
+        );
+        assert_eq!(
+            serde_json::from_value::<MongoDBPortBinding>(json).unwrap(),
+            port_binding
+        );
+    }
+
+    #[test]
+    fn test_free_loopback() {
+        let binding = MongoDBPortBinding::free_loopback(DEFAULT_FREE_PORT_RANGE).unwrap();
+
+        assert_eq!(binding.binding_type, BindingType::Loopback);
+        let port = binding.port.unwrap();
+        assert!(DEFAULT_FREE_PORT_RANGE.contains(&port));
+        // The port is free
+        assert!(TcpListener::bind((Ipv4Addr::LOCALHOST, port)).is_ok());
+        // And bound explicitly when creating the container
+        assert_eq!(
+            PortBinding::from(&binding),
+            PortBinding {
+                host_ip: Some("127.0.0.1".to_string()),
+                host_port: Some(port.to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_free_loopback_skips_ports_in_use() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port_in_use = listener.local_addr().unwrap().port();
+
+        let result = MongoDBPortBinding::free_loopback(port_in_use..=port_in_use);
+
+        assert_eq!(
+            result,
+            Err(NoFreePortError {
+                start: port_in_use,
+                end: port_in_use,
+            })
+        );
+    }
 }
@@ -0,0 +1,65 @@
+/// Outcome of one of the checks of a [`ReadinessReport`].
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "status", rename_all = "lowercase")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Failed {
+        reason: String,
+    },
+    /// The check could not run because an earlier one failed, e.g. mongod cannot be reached when the container is stopped.
+    Skipped,
+}
+
+impl CheckStatus {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, CheckStatus::Passed)
+    }
+}
+
+/// Whether a local Atlas deployment is usable, see [`crate::Client::check_ready`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadinessReport {
+    /// The container is running and its health check, when it has one, reports it as healthy.
+    pub container_health: CheckStatus,
+    /// The replica set of the deployment has a primary.
+    pub replica_set_primary: CheckStatus,
+    /// mongod answers a ping.
+    pub ping: CheckStatus,
+}
+
+impl ReadinessReport {
+    /// Returns true when every check passed.
+    pub fn is_ready(&self) -> bool {
+        self.container_health.is_passed()
+            && self.replica_set_primary.is_passed()
+            && self.ping.is_passed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ready() {
+        let report = ReadinessReport {
+            container_health: CheckStatus::Passed,
+            replica_set_primary: CheckStatus::Passed,
+            ping: CheckStatus::Passed,
+        };
+        assert!(report.is_ready());
+
+        let report = ReadinessReport {
+            replica_set_primary: CheckStatus::Failed {
+                reason: "no primary".to_string(),
+            },
+            ..report
+        };
+        assert!(!report.is_ready());
+    }
+}
@@ -0,0 +1,66 @@
+use bollard::models::RestartPolicyNameEnum;
+
+/// When Docker restarts the container of a deployment, e.g. after mongod crashed.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Never restart the container.
+    No,
+    /// Restart the container when it exits with a non-zero exit code, at most `max_retries` times (0 for no limit).
+    OnFailure { max_retries: i64 },
+    /// Always restart the container, also when the Docker daemon starts.
+    Always,
+    /// Like [`RestartPolicy::Always`], except when the container was stopped before the Docker daemon stopped.
+    UnlessStopped,
+}
+
+impl From<RestartPolicy> for bollard::models::RestartPolicy {
+    fn from(value: RestartPolicy) -> Self {
+        let (name, maximum_retry_count) = match value {
+            RestartPolicy::No => (RestartPolicyNameEnum::NO, None),
+            RestartPolicy::OnFailure { max_retries } => {
+                (RestartPolicyNameEnum::ON_FAILURE, Some(max_retries))
+            }
+            RestartPolicy::Always => (RestartPolicyNameEnum::ALWAYS, None),
+            RestartPolicy::UnlessStopped => (RestartPolicyNameEnum::UNLESS_STOPPED, None),
+        };
+
+        bollard::models::RestartPolicy {
+            name: Some(name),
+            maximum_retry_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_bollard_restart_policy() {
+        let cases = [
+            (RestartPolicy::No, RestartPolicyNameEnum::NO, None),
+            (
+                RestartPolicy::OnFailure { max_retries: 3 },
+                RestartPolicyNameEnum::ON_FAILURE,
+                Some(3),
+            ),
+            (RestartPolicy::Always, RestartPolicyNameEnum::ALWAYS, None),
+            (
+                RestartPolicy::UnlessStopped,
+                RestartPolicyNameEnum::UNLESS_STOPPED,
+                None,
+            ),
+        ];
+
+        for (restart_policy, name, maximum_retry_count) in cases {
+            let restart_policy = bollard::models::RestartPolicy::from(restart_policy);
+            assert_eq!(restart_policy.name, Some(name));
+            assert_eq!(restart_policy.maximum_retry_count, maximum_retry_count);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+/// How Docker requests are retried when the daemon reports a transient error, see [`crate::Client::with_retry_policy`].
+///
+/// Only server errors (5xx) are retried, client errors such as a missing (404) or conflicting (409)
+/// container fail on the first attempt. The delay doubles after every attempt.
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let retry_policy = RetryPolicy {
+///     max_attempts: 5,
+///     base_delay: Duration::from_millis(200),
+///     jitter: true,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the second attempt.
+    pub base_delay: Duration,
+    /// Randomizes every delay between half and the full delay, so clients don't retry in lockstep.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay after a failed attempt, `attempt` being 1 for the first attempt.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)));
+        if self.jitter {
+            delay.mul_f64(rand::random_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles() {
+        let retry_policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+            jitter: false,
+        };
+
+        assert_eq!(retry_policy.delay(1), Duration::from_millis(100));
+        assert_eq!(retry_policy.delay(2), Duration::from_millis(200));
+        assert_eq!(retry_policy.delay(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_delay_jitter() {
+        let retry_policy = RetryPolicy {
+            jitter: true,
+            ..RetryPolicy::default()
+        };
+
+        for _ in 0..100 {
+            let delay = retry_policy.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+}
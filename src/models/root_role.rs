@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+/// A role granted to the initial root user, written as `role` or `role@db`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RootRole {
+    pub role: String,
+    /// The database the role is defined on, the `admin` database is used when not set.
+    pub db: Option<String>,
+}
+
+impl RootRole {
+    pub fn new(role: impl Into<String>, db: Option<String>) -> Self {
+        Self {
+            role: role.into(),
+            db,
+        }
+    }
+
+    /// Parses a comma separated list of roles, e.g. `root,readWrite@app`. Empty entries are skipped.
+    pub fn parse_list(value: &str) -> Vec<RootRole> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|role| !role.is_empty())
+            .map(RootRole::from)
+            .collect()
+    }
+
+    /// Formats roles as a comma separated list, the inverse of [`RootRole::parse_list`].
+    pub fn format_list(roles: &[RootRole]) -> String {
+        roles
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl From<&str> for RootRole {
+    fn from(s: &str) -> Self {
+        match s.split_once('@') {
+            Some((role, db)) => RootRole::new(role, Some(db.to_string())),
+            None => RootRole::new(s, None),
+        }
+    }
+}
+
+impl Display for RootRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.db {
+            Some(db) => write!(f, "{}@{}", self.role, db),
+            None => write!(f, "{}", self.role),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(RootRole::from("root"), RootRole::new("root", None));
+        assert_eq!(
+            RootRole::from("readWrite@app"),
+            RootRole::new("readWrite", Some("app".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(RootRole::new("root", None).to_string(), "root");
+        assert_eq!(
+            RootRole::new("readWrite", Some("app".to_string())).to_string(),
+            "readWrite@app"
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            RootRole::parse_list(" root, readWrite@app,,dbAdmin@app "),
+            vec![
+                RootRole::new("root", None),
+                RootRole::new("readWrite", Some("app".to_string())),
+                RootRole::new("dbAdmin", Some("app".to_string())),
+            ]
+        );
+        assert!(RootRole::parse_list("").is_empty());
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        let roles = vec![
+            RootRole::new("root", None),
+            RootRole::new("readWrite", Some("app".to_string())),
+        ];
+
+        let formatted = RootRole::format_list(&roles);
+
+        assert_eq!(formatted, "root,readWrite@app");
+        assert_eq!(RootRole::parse_list(&formatted), roles);
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+/// A subset of the `serverStatus` command output of a deployment.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServerStatus {
+    /// Time since the mongod process started.
+    pub uptime: Duration,
+    pub connections: ConnectionStats,
+    pub opcounters: OpCounters,
+}
+
+/// Connection counters reported by `serverStatus.connections`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionStats {
+    /// Number of currently open connections.
+    pub current: u64,
+    /// Number of connections that can still be opened.
+    pub available: u64,
+    /// Number of connections created since the server started.
+    pub total_created: u64,
+}
+
+/// Operation counters reported by `serverStatus.opcounters`, counted since the server started.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpCounters {
+    pub insert: u64,
+    pub query: u64,
+    pub update: u64,
+    pub delete: u64,
+    pub getmore: u64,
+    pub command: u64,
+}
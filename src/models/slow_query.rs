@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+
+/// A slow operation reported in the MongoDB server log.
+///
+/// MongoDB logs every operation slower than the `slowms` threshold as a structured
+/// JSON log line with the message `Slow query`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlowQuery {
+    /// When the operation was logged.
+    pub timestamp: Option<DateTime<Utc>>,
+    /// The namespace (`database.collection`) the operation ran against.
+    pub namespace: Option<String>,
+    /// The kind of operation, e.g. `command`, `update` or `remove`.
+    pub op_type: Option<String>,
+    /// How long the operation took, in milliseconds.
+    pub duration_ms: u64,
+    /// The query plan used, e.g. `COLLSCAN` or `IXSCAN { a: 1 }`.
+    pub plan_summary: Option<String>,
+}
+
+impl SlowQuery {
+    /// Parses a MongoDB structured log line, returns `None` when the line is not a slow query entry.
+    pub fn from_log_line(line: &str) -> Option<SlowQuery> {
+        let entry: serde_json::Value = serde_json::from_str(line.trim()).ok()?;
+        if entry.get("msg")?.as_str()? != "Slow query" {
+            return None;
+        }
+
+        let attr = entry.get("attr")?;
+        let attr_string = |key: &str| attr.get(key).and_then(|v| v.as_str()).map(String::from);
+
+        Some(SlowQuery {
+            timestamp: entry
+                .pointer("/t/$date")
+                .and_then(|v| v.as_str())
+                .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+                .map(|date| date.with_timezone(&Utc)),
+            namespace: attr_string("ns"),
+            op_type: attr_string("type"),
+            duration_ms: attr.get("durationMillis")?.as_u64()?,
+            plan_summary: attr_string("planSummary"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_query_from_log_line() {
+        let line = r#"{"t":{"$date":"2024-05-01T12:00:00.123+00:00"},"s":"I","c":"COMMAND","id":51803,"ctx":"conn12","msg":"Slow query","attr":{"type":"command","ns":"test.orders","command":{"find":"orders","filter":{"status":"A"}},"planSummary":"COLLSCAN","durationMillis":250}}"#;
+
+        let slow_query = SlowQuery::from_log_line(line).unwrap();
+
+        assert_eq!(
+            slow_query,
+            SlowQuery {
+                timestamp: Some(
+                    DateTime::parse_from_rfc3339("2024-05-01T12:00:00.123Z")
+                        .unwrap()
+                        .with_timezone(&Utc)
+                ),
+                namespace: Some("test.orders".to_string()),
+                op_type: Some("command".to_string()),
+                duration_ms: 250,
+                plan_summary: Some("COLLSCAN".to_string()),
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_slow_query_json_round_trip() {
+        let line = r#"{"t":{"$date":"2024-05-01T12:00:00.123+00:00"},"s":"I","c":"COMMAND","id":51803,"ctx":"conn12","msg":"Slow query","attr":{"type":"command","ns":"test.orders","planSummary":"COLLSCAN","durationMillis":250}}"#;
+        let slow_query = SlowQuery::from_log_line(line).unwrap();
+
+        let json = serde_json::to_string(&slow_query).unwrap();
+        let round_tripped: SlowQuery = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(round_tripped, slow_query);
+    }
+
+    #[test]
+    fn test_slow_query_from_log_line_other_message() {
+        let line = r#"{"t":{"$date":"2024-05-01T12:00:00.123+00:00"},"s":"I","c":"NETWORK","id":22943,"ctx":"listener","msg":"Connection accepted","attr":{"connectionCount":3}}"#;
+
+        assert_eq!(SlowQuery::from_log_line(line), None);
+    }
+
+    #[test]
+    fn test_slow_query_from_log_line_not_json() {
+        assert_eq!(SlowQuery::from_log_line("runner: starting mongod"), None);
+        assert_eq!(SlowQuery::from_log_line(""), None);
+    }
+
+    #[test]
+    fn test_slow_query_from_log_line_missing_duration() {
+        let line = r#"{"msg":"Slow query","attr":{"ns":"test.orders"}}"#;
+
+        assert_eq!(SlowQuery::from_log_line(line), None);
+    }
+}
@@ -0,0 +1,18 @@
+/// Options for stopping a deployment, see [`crate::Client::stop_deployment_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use atlas_local::models::StopDeploymentOptions;
+///
+/// let options = StopDeploymentOptions::builder().timeout_secs(2).build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, typed_builder::TypedBuilder)]
+#[builder(doc)]
+pub struct StopDeploymentOptions {
+    /// Seconds to wait for mongod to shut down before it is killed, Docker waits 10 seconds when not set.
+    ///
+    /// `-1` waits without a limit.
+    #[builder(default, setter(strip_option))]
+    pub timeout_secs: Option<i64>,
+}
@@ -0,0 +1,13 @@
+/// Telemetry settings of the Atlas Local image.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Telemetry {
+    /// Opt out of telemetry, sets `DO_NOT_TRACK`.
+    Disabled,
+    /// Send telemetry, optionally to a custom endpoint set through `TELEMETRY_BASE_URL`.
+    Enabled { base_url: Option<String> },
+}
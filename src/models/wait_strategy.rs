@@ -0,0 +1,17 @@
+/// How `create_deployment` decides that a started deployment is ready.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WaitStrategy {
+    /// Wait until the Docker health check reports the container as healthy.
+    #[default]
+    Healthy,
+    /// Wait until the bound host port accepts a TCP connection.
+    /// Faster than waiting for the health check, but mongod may still be initializing.
+    TcpConnect,
+    /// Do not wait, return as soon as the container is started.
+    None,
+}
@@ -22,6 +22,21 @@ pub struct WatchOptions {
     #[builder(default, setter(strip_option))]
     pub timeout_duration: Option<time::Duration>,
 
+    /// Instant at which to stop waiting, takes precedence over `timeout_duration` when set.
+    #[builder(default, setter(strip_option))]
+    pub deadline: Option<time::Instant>,
+
+    /// Time between two inspections of the container, one second when not set.
+    #[builder(default, setter(strip_option))]
+    pub poll_interval: Option<time::Duration>,
+
+    /// Maximum number of inspections before giving up on a deployment that is still starting.
+    ///
+    /// Only used by [`crate::Client::wait_for_healthy_deployment`], e.g. to bound a watch in tests
+    /// without relying on the clock.
+    #[builder(default, setter(strip_option))]
+    pub max_polls: Option<u32>,
+
     /// Indicates that the initial state of the deployment is allowed to be unhealthy.
     #[builder(default = false)]
     pub allow_unhealthy_initial_state: bool,
@@ -0,0 +1,277 @@
+use crate::{client::Document, models::ServerStatus};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MongoDbClientError {
+    #[error("Failed to connect to MongoDB: {message}")]
+    Connect { message: String },
+    #[error("Failed to query MongoDB: {message}")]
+    Query { message: String },
+}
+
+/// Queries a deployment directly over its connection string instead of running mongosh in its container.
+///
+/// With the `mongodb` feature this is implemented for [`bollard::Docker`] using the MongoDB driver,
+/// the queries do not go through Docker.
+pub trait MongoDbClient {
+    /// Gets the Atlas deployment ID stored in `admin.atlascli`, `None` when the collection has no ID.
+    fn get_deployment_id(
+        &self,
+        connection_string: &str,
+    ) -> impl Future<Output = Result<Option<String>, MongoDbClientError>> + Send;
+
+    /// Lists the names of the databases, used to check that the deployment can be reached and authenticated against.
+    fn list_database_names(
+        &self,
+        connection_string: &str,
+    ) -> impl Future<Output = Result<Vec<String>, MongoDbClientError>> + Send;
+
+    /// Runs the `serverStatus` command and reads the uptime, connection and operation counters.
+    fn server_status(
+        &self,
+        connection_string: &str,
+    ) -> impl Future<Output = Result<ServerStatus, MongoDbClientError>> + Send;
+
+    /// Runs an aggregation pipeline against a collection, documents are in relaxed Extended JSON.
+    fn aggregate(
+        &self,
+        connection_string: &str,
+        database: &str,
+        collection: &str,
+        pipeline: Vec<Document>,
+    ) -> impl Future<Output = Result<Vec<Document>, MongoDbClientError>> + Send;
+}
+
+#[cfg(feature = "mongodb")]
+impl MongoDbClient for bollard::Docker {
+    async fn get_deployment_id(
+        &self,
+        connection_string: &str,
+    ) -> Result<Option<String>, MongoDbClientError> {
+        let client = mongodb::Client::with_uri_str(connection_string)
+            .await
+            .map_err(|err| MongoDbClientError::Connect {
+                message: err.to_string(),
+            })?;
+
+        let document = client
+            .database("admin")
+            .collection::<mongodb::bson::Document>("atlascli")
+            .find_one(mongodb::bson::doc! {})
+            .await
+            .map_err(|err| MongoDbClientError::Query {
+                message: err.to_string(),
+            })?;
+
+        Ok(document.and_then(|document| document.get_str("uuid").ok().map(str::to_string)))
+    }
+
+    async fn list_database_names(
+        &self,
+        connection_string: &str,
+    ) -> Result<Vec<String>, MongoDbClientError> {
+        let client = mongodb::Client::with_uri_str(connection_string)
+            .await
+            .map_err(|err| MongoDbClientError::Connect {
+                message: err.to_string(),
+            })?;
+
+        client
+            .list_database_names()
+            .await
+            .map_err(|err| MongoDbClientError::Query {
+                message: err.to_string(),
+            })
+    }
+
+    async fn server_status(
+        &self,
+        connection_string: &str,
+    ) -> Result<ServerStatus, MongoDbClientError> {
+        let client = mongodb::Client::with_uri_str(connection_string)
+            .await
+            .map_err(|err| MongoDbClientError::Connect {
+                message: err.to_string(),
+            })?;
+
+        let document = client
+            .database("admin")
+            .run_command(mongodb::bson::doc! { "serverStatus": 1 })
+            .await
+            .map_err(|err| MongoDbClientError::Query {
+                message: err.to_string(),
+            })?;
+
+        server_status_from_document(&document)
+    }
+
+    async fn aggregate(
+        &self,
+        connection_string: &str,
+        database: &str,
+        collection: &str,
+        pipeline: Vec<Document>,
+    ) -> Result<Vec<Document>, MongoDbClientError> {
+        use futures_util::TryStreamExt;
+        use mongodb::bson::{self, Bson};
+
+        let invalid_stage = |err: bson::extjson::de::Error| MongoDbClientError::Query {
+            message: format!("Invalid pipeline stage: {err}"),
+        };
+        let pipeline = pipeline
+            .into_iter()
+            .map(|stage| bson::Document::try_from(stage).map_err(invalid_stage))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let client = mongodb::Client::with_uri_str(connection_string)
+            .await
+            .map_err(|err| MongoDbClientError::Connect {
+                message: err.to_string(),
+            })?;
+
+        let documents: Vec<bson::Document> = client
+            .database(database)
+            .collection::<bson::Document>(collection)
+            .aggregate(pipeline)
+            .await
+            .map_err(|err| MongoDbClientError::Query {
+                message: err.to_string(),
+            })?
+            .try_collect()
+            .await
+            .map_err(|err| MongoDbClientError::Query {
+                message: err.to_string(),
+            })?;
+
+        Ok(documents
+            .into_iter()
+            .filter_map(
+                |document| match Bson::Document(document).into_relaxed_extjson() {
+                    serde_json::Value::Object(document) => Some(document),
+                    _ => None,
+                },
+            )
+            .collect())
+    }
+}
+
+/// Reads the fields of [`ServerStatus`] from a `serverStatus` response.
+#[cfg(feature = "mongodb")]
+fn server_status_from_document(
+    document: &mongodb::bson::Document,
+) -> Result<ServerStatus, MongoDbClientError> {
+    use std::time::Duration;
+
+    use mongodb::bson::{Bson, Document};
+
+    use crate::models::{ConnectionStats, OpCounters};
+
+    let invalid_field = |field: &str| MongoDbClientError::Query {
+        message: format!("serverStatus returned no valid {field}"),
+    };
+    let section = |name: &str| document.get_document(name).map_err(|_| invalid_field(name));
+    // Counters are returned as an int or a long depending on their size
+    let counter = |section: &Document, name: &str, field: &str| {
+        match section.get(field) {
+            Some(Bson::Int32(value)) => u64::try_from(*value).ok(),
+            Some(Bson::Int64(value)) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| invalid_field(&format!("{name}.{field}")))
+    };
+
+    let uptime = match document.get("uptime") {
+        Some(Bson::Double(secs)) => Some(*secs),
+        Some(Bson::Int32(secs)) => Some(f64::from(*secs)),
+        _ => None,
+    }
+    .filter(|secs| secs.is_finite() && *secs >= 0.0)
+    .map(Duration::from_secs_f64)
+    .ok_or_else(|| invalid_field("uptime"))?;
+
+    let connections = section("connections")?;
+    let opcounters = section("opcounters")?;
+
+    Ok(ServerStatus {
+        uptime,
+        connections: ConnectionStats {
+            current: counter(connections, "connections", "current")?,
+            available: counter(connections, "connections", "available")?,
+            total_created: counter(connections, "connections", "totalCreated")?,
+        },
+        opcounters: OpCounters {
+            insert: counter(opcounters, "opcounters", "insert")?,
+            query: counter(opcounters, "opcounters", "query")?,
+            update: counter(opcounters, "opcounters", "update")?,
+            delete: counter(opcounters, "opcounters", "delete")?,
+            getmore: counter(opcounters, "opcounters", "getmore")?,
+            command: counter(opcounters, "opcounters", "command")?,
+        },
+    })
+}
+
+#[cfg(all(test, feature = "mongodb"))]
+mod tests {
+    use std::time::Duration;
+
+    use mongodb::bson::doc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{ConnectionStats, OpCounters};
+
+    #[test]
+    fn test_server_status_from_document() {
+        let document = doc! {
+            "uptime": 3600.5,
+            "connections": { "current": 5, "available": 838855, "totalCreated": 42_i64 },
+            "opcounters": {
+                "insert": 10_i64,
+                "query": 20_i64,
+                "update": 3_i64,
+                "delete": 1_i64,
+                "getmore": 7_i64,
+                "command": 120_i64,
+            },
+        };
+
+        assert_eq!(
+            server_status_from_document(&document).unwrap(),
+            ServerStatus {
+                uptime: Duration::from_secs_f64(3600.5),
+                connections: ConnectionStats {
+                    current: 5,
+                    available: 838855,
+                    total_created: 42,
+                },
+                opcounters: OpCounters {
+                    insert: 10,
+                    query: 20,
+                    update: 3,
+                    delete: 1,
+                    getmore: 7,
+                    command: 120,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_server_status_from_document_missing_section() {
+        let document = doc! { "uptime": 3600.5, "connections": { "current": 5 } };
+
+        assert!(matches!(
+            server_status_from_document(&document).unwrap_err(),
+            MongoDbClientError::Query { .. }
+        ));
+    }
+
+    #[test]
+    fn test_server_status_from_document_invalid_uptime() {
+        let document = doc! { "uptime": -1.0 };
+
+        assert!(matches!(
+            server_status_from_document(&document).unwrap_err(),
+            MongoDbClientError::Query { .. }
+        ));
+    }
+}